@@ -1,6 +1,9 @@
 import {
+  AccountMeta,
   Context,
   PublicKey,
+  Signer,
+  SignerMeta,
   TransactionBuilder,
 } from '@metaplex-foundation/umi';
 import { ASSET_PROGRAM_ID } from './generated';
@@ -9,11 +12,17 @@ import {
   ApproveInstructionArgs,
   approve as baseApprove,
 } from './generated/instructions/approve';
+import { getTrailingAccounts } from './trailingAccounts';
 
 export function approve(
   context: Pick<Context, 'identity' | 'programs'>,
   input: ApproveInstructionAccounts &
-    ApproveInstructionArgs & { proxy?: PublicKey }
+    ApproveInstructionArgs & {
+      payer?: Signer;
+      group?: PublicKey;
+      proxy?: PublicKey;
+      ruleSet?: PublicKey | PublicKey[];
+    }
 ): TransactionBuilder {
   if (input.proxy) {
     const proxied = context.programs.clone();
@@ -31,5 +40,39 @@ export function approve(
     });
   }
 
-  return ix;
+  // the payer, system program and group are optional accounts, using the
+  // program id as a placeholder for a missing account
+  if (input.payer || input.group) {
+    const placeholder: AccountMeta = {
+      pubkey: ASSET_PROGRAM_ID,
+      isWritable: false,
+      isSigner: false,
+    };
+    const accounts: (AccountMeta | SignerMeta)[] = input.payer
+      ? [
+          { signer: input.payer, isWritable: true },
+          {
+            pubkey: context.programs.getPublicKey(
+              'systemProgram',
+              '11111111111111111111111111111111'
+            ),
+            isWritable: false,
+            isSigner: false,
+          },
+        ]
+      : [placeholder, placeholder];
+
+    if (input.group) {
+      accounts.push({
+        pubkey: input.group,
+        isWritable: false,
+        isSigner: false,
+      });
+    }
+
+    ix = ix.addRemainingAccounts(accounts);
+  }
+
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
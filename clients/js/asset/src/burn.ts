@@ -8,10 +8,15 @@ import {
   BurnInstructionAccounts,
   burn as baseBurn,
 } from './generated/instructions/burn';
+import { getTrailingAccounts } from './trailingAccounts';
 
 export function burn(
   context: Pick<Context, 'programs'>,
-  input: BurnInstructionAccounts & { proxy?: PublicKey }
+  input: BurnInstructionAccounts & {
+    proxy?: PublicKey;
+    parents?: PublicKey[];
+    ruleSet?: PublicKey | PublicKey[];
+  }
 ): TransactionBuilder {
   if (input.proxy) {
     const proxied = context.programs.clone();
@@ -29,5 +34,17 @@ export function burn(
     });
   }
 
-  return ix;
+  // the parent chain of a nested asset, starting with the parent of the asset
+  if (input.parents) {
+    ix = ix.addRemainingAccounts(
+      input.parents.map((pubkey) => ({
+        pubkey,
+        isWritable: false,
+        isSigner: false,
+      }))
+    );
+  }
+
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
@@ -0,0 +1,36 @@
+import {
+  Serializer,
+  array,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  Constraint,
+  OperatorType,
+  getConstraintSerializer,
+  wrapSerializerInConstraintHeader,
+} from '.';
+
+export type AtLeast = {
+  type: 'AtLeast';
+  n: bigint;
+  constraints: Constraint[];
+};
+
+export const atLeast = (
+  n: number | bigint,
+  constraints: Constraint[]
+): AtLeast => ({
+  type: 'AtLeast',
+  n: BigInt(n),
+  constraints,
+});
+
+export const getAtLeastSerializer = (): Serializer<AtLeast> =>
+  wrapSerializerInConstraintHeader(
+    OperatorType.AtLeast,
+    struct([
+      ['n', u64()],
+      ['constraints', array(getConstraintSerializer(), { size: 'remainder' })],
+    ])
+  );
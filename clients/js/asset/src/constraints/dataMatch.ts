@@ -0,0 +1,54 @@
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  Account,
+  OperatorType,
+  getAccountSerializer,
+  wrapSerializerInConstraintHeader,
+} from '.';
+
+export type DataMatch = {
+  type: 'DataMatch';
+  account: Account;
+  offset: number;
+  bytes: Uint8Array;
+};
+
+export const dataMatch = (
+  account: Account,
+  offset: number,
+  data: Uint8Array
+): DataMatch => ({
+  type: 'DataMatch',
+  account,
+  offset,
+  bytes: data,
+});
+
+// The expected bytes are padded to a multiple of 8 bytes to keep
+// the alignment of any constraint that follows.
+const getPadding = (length: number): Uint8Array =>
+  new Uint8Array((8 - (length % 8)) % 8);
+
+export const getDataMatchSerializer = (): Serializer<DataMatch> =>
+  wrapSerializerInConstraintHeader(
+    OperatorType.DataMatch,
+    mapSerializer(
+      struct<Omit<DataMatch, 'type'> & { padding: Uint8Array }>([
+        ['account', getAccountSerializer()],
+        ['offset', u32()],
+        ['bytes', bytes({ size: u32() })],
+        ['padding', bytes()],
+      ]),
+      (value: Omit<DataMatch, 'type'>) => ({
+        ...value,
+        padding: getPadding(value.bytes.length),
+      }),
+      ({ padding, ...value }) => value
+    )
+  );
@@ -0,0 +1,27 @@
+import {
+  PublicKey,
+  PublicKeyInput,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { OperatorType, wrapSerializerInConstraintHeader } from '.';
+
+export type External = {
+  type: 'External';
+  address: PublicKey;
+};
+
+export const external = (address: PublicKeyInput): External => ({
+  type: 'External',
+  address: toPublicKey(address),
+});
+
+export const getExternalSerializer = (): Serializer<External> =>
+  wrapSerializerInConstraintHeader(
+    OperatorType.External,
+    struct([['address', publicKeySerializer()]])
+  );
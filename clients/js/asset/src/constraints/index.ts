@@ -9,24 +9,45 @@ import {
   u64,
 } from '@metaplex-foundation/umi/serializers';
 import { And, getAndSerializer } from './and';
+import { AtLeast, getAtLeastSerializer } from './atLeast';
+import { DataMatch, getDataMatchSerializer } from './dataMatch';
+import { External, getExternalSerializer } from './external';
 import { Not, getNotSerializer } from './not';
 import { Or, getOrSerializer } from './or';
 import { OwnedBy, getOwnedBySerializer } from './ownedBy';
 import { PubkeyMatch, getPubkeyMatchSerializer } from './pubkeyMatch';
 import { Empty, getEmptySerializer } from './empty';
+import { TimeWindow, getTimeWindowSerializer } from './timeWindow';
+import { ValueMatch, getValueMatchSerializer } from './valueMatch';
 
 export * from './and';
+export * from './atLeast';
+export * from './dataMatch';
 export * from './empty';
+export * from './external';
 export * from './not';
 export * from './or';
 export * from './ownedBy';
 export * from './pubkeyMatch';
+export * from './timeWindow';
+export * from './valueMatch';
 
 // -------------------//
 // Constraint         //
 // -------------------//
 
-export type Constraint = And | Not | Or | OwnedBy | PubkeyMatch | Empty;
+export type Constraint =
+  | And
+  | Not
+  | Or
+  | OwnedBy
+  | PubkeyMatch
+  | Empty
+  | TimeWindow
+  | AtLeast
+  | DataMatch
+  | ValueMatch
+  | External;
 
 export const getConstraintSerializer = (): Serializer<Constraint> => ({
   description: 'Constraint',
@@ -61,6 +82,16 @@ export const getConstraintSerializerFromType = <T extends Constraint>(
         return getPubkeyMatchSerializer();
       case 'Empty':
         return getEmptySerializer();
+      case 'TimeWindow':
+        return getTimeWindowSerializer();
+      case 'AtLeast':
+        return getAtLeastSerializer();
+      case 'DataMatch':
+        return getDataMatchSerializer();
+      case 'ValueMatch':
+        return getValueMatchSerializer();
+      case 'External':
+        return getExternalSerializer();
       default:
         throw new Error(`Unknown operator type: ${type}`);
     }
@@ -77,6 +108,11 @@ export enum OperatorType {
   OwnedBy,
   PubkeyMatch,
   Empty,
+  TimeWindow,
+  AtLeast,
+  DataMatch,
+  ValueMatch,
+  External,
 }
 
 export type OperatorTypeArgs = OperatorType;
@@ -150,6 +186,16 @@ export const getOperatorTypeAsString = (
       return 'PubkeyMatch';
     case OperatorType.Empty:
       return 'Empty';
+    case OperatorType.TimeWindow:
+      return 'TimeWindow';
+    case OperatorType.AtLeast:
+      return 'AtLeast';
+    case OperatorType.DataMatch:
+      return 'DataMatch';
+    case OperatorType.ValueMatch:
+      return 'ValueMatch';
+    case OperatorType.External:
+      return 'External';
     default:
       throw new Error(`Unknown operator type: ${type}`);
   }
@@ -163,9 +209,10 @@ enum AccountType {
   Asset,
   Authority,
   Recipient,
+  Caller,
 }
 
-export type Account = 'Asset' | 'Authority' | 'Recipient';
+export type Account = 'Asset' | 'Authority' | 'Recipient' | 'Caller';
 
 export function getAccountSerializer(): Serializer<Account> {
   return mapSerializer(
@@ -0,0 +1,30 @@
+import { Serializer, i64, struct } from '@metaplex-foundation/umi/serializers';
+import { OperatorType, wrapSerializerInConstraintHeader } from '.';
+
+export type TimeWindow = {
+  type: 'TimeWindow';
+  start: bigint;
+  end: bigint;
+};
+
+/**
+ * Creates a constraint that passes when the current unix timestamp is within the
+ * window; use `0` to leave either side of the window open.
+ */
+export const timeWindow = (
+  start: number | bigint,
+  end: number | bigint = 0
+): TimeWindow => ({
+  type: 'TimeWindow',
+  start: BigInt(start),
+  end: BigInt(end),
+});
+
+export const getTimeWindowSerializer = (): Serializer<TimeWindow> =>
+  wrapSerializerInConstraintHeader(
+    OperatorType.TimeWindow,
+    struct([
+      ['start', i64()],
+      ['end', i64()],
+    ])
+  );
@@ -0,0 +1,139 @@
+import {
+  Serializer,
+  bool,
+  mergeBytes,
+  string,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  Account,
+  OperatorType,
+  getAccountSerializer,
+  wrapSerializerInConstraintHeader,
+} from '.';
+import { Type } from '../generated';
+
+export type ValueSource = 'Attributes' | 'Properties';
+
+export type ValueMatch = {
+  type: 'ValueMatch';
+  account: Account;
+  source: ValueSource;
+  name: string;
+  // when `null`, the value on the asset must match the key of the `account`.
+  value: string | bigint | boolean | null;
+};
+
+export const valueMatch = (
+  source: ValueSource,
+  name: string,
+  value: string | number | bigint | boolean
+): ValueMatch => ({
+  type: 'ValueMatch',
+  account: 'Asset',
+  source,
+  name,
+  value: typeof value === 'number' ? BigInt(value) : value,
+});
+
+export const valueMatchAccount = (
+  source: ValueSource,
+  name: string,
+  account: Account
+): ValueMatch => ({
+  type: 'ValueMatch',
+  account,
+  source,
+  name,
+  value: null,
+});
+
+enum MatchMode {
+  Value,
+  Account,
+}
+
+const getValueSerializer = (type: Type): Serializer<any> => {
+  switch (type) {
+    case Type.Text:
+      return string({ size: u8() });
+    case Type.Number:
+      return u64();
+    default:
+      return bool();
+  }
+};
+
+const getValueType = (value: string | bigint | boolean): Type => {
+  switch (typeof value) {
+    case 'string':
+      return Type.Text;
+    case 'boolean':
+      return Type.Boolean;
+    default:
+      return Type.Number;
+  }
+};
+
+export const getValueMatchSerializer = (): Serializer<ValueMatch> =>
+  wrapSerializerInConstraintHeader(OperatorType.ValueMatch, {
+    description: 'ValueMatch',
+    fixedSize: null,
+    maxSize: null,
+    serialize: (constraint: Omit<ValueMatch, 'type'>) => {
+      const { value } = constraint;
+      const parts = [
+        getAccountSerializer().serialize(constraint.account),
+        u8().serialize(constraint.source === 'Attributes' ? 0 : 1),
+        u8().serialize(value === null ? MatchMode.Account : MatchMode.Value),
+        string({ size: u8() }).serialize(constraint.name),
+      ];
+
+      if (value !== null) {
+        const type = getValueType(value);
+        parts.push(u8().serialize(type));
+        parts.push(getValueSerializer(type).serialize(value));
+      }
+
+      // the data is padded to a multiple of 8 bytes to keep
+      // the alignment of any constraint that follows.
+      const length = parts.reduce((total, part) => total + part.length, 0);
+      parts.push(new Uint8Array((8 - (length % 8)) % 8));
+
+      return mergeBytes(parts);
+    },
+    deserialize: (buffer: Uint8Array, offset = 0) => {
+      const [account, sourceOffset] = getAccountSerializer().deserialize(
+        buffer,
+        offset
+      );
+      const [source, modeOffset] = u8().deserialize(buffer, sourceOffset);
+      const [mode, nameOffset] = u8().deserialize(buffer, modeOffset);
+      const [name, valueOffset] = string({ size: u8() }).deserialize(
+        buffer,
+        nameOffset
+      );
+
+      let value: string | bigint | boolean | null = null;
+      let end = valueOffset;
+
+      if (mode === MatchMode.Value) {
+        const [type, typeOffset] = u8().deserialize(buffer, valueOffset);
+        [value, end] = getValueSerializer(type as Type).deserialize(
+          buffer,
+          typeOffset
+        );
+      }
+
+      return [
+        {
+          account,
+          source: source === 0 ? 'Attributes' : 'Properties',
+          name,
+          value,
+        },
+        end + ((8 - ((end - offset) % 8)) % 8),
+      ];
+    },
+  });
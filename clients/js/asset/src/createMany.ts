@@ -0,0 +1,27 @@
+import {
+  Context,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  CreateManyInstructionAccounts,
+  CreateManyInstructionArgs,
+  createMany as baseCreateMany,
+} from './generated/instructions/createMany';
+
+export function createMany(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: CreateManyInstructionAccounts &
+    Omit<CreateManyInstructionArgs, 'assets'> & {
+      assets: { asset: Signer; name: string; owner: PublicKey }[];
+    }
+): TransactionBuilder {
+  // asset accounts are passed in the same order as the assets input
+  return baseCreateMany(context, {
+    ...input,
+    assets: input.assets.map(({ name, owner }) => ({ name, owner })),
+  }).addRemainingAccounts(
+    input.assets.map(({ asset }) => ({ signer: asset, isWritable: true }))
+  );
+}
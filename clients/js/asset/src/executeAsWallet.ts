@@ -0,0 +1,56 @@
+import {
+  Context,
+  Instruction,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import {
+  publicKey as publicKeySerializer,
+  string,
+} from '@metaplex-foundation/umi/serializers';
+import { ASSET_PROGRAM_ID } from './generated';
+import {
+  executeAsWallet as baseExecuteAsWallet,
+  ExecuteAsWalletInstructionAccounts,
+} from './generated/instructions/executeAsWallet';
+
+export function findAssetWalletPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: { asset: PublicKey }
+): Pda {
+  const programId = context.programs.getPublicKey('asset', ASSET_PROGRAM_ID);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('wallet'),
+    publicKeySerializer().serialize(seeds.asset),
+  ]);
+}
+
+export function executeAsWallet(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: Omit<ExecuteAsWalletInstructionAccounts, 'wallet' | 'program'> & {
+    wallet?: PublicKey | Pda;
+    instruction: Instruction;
+  }
+): TransactionBuilder {
+  const wallet = publicKey(
+    input.wallet ??
+      findAssetWalletPda(context, { asset: publicKey(input.asset, false) }),
+    false
+  );
+
+  // the wallet signs through the asset program, so it is not a signer of the
+  // transaction
+  return baseExecuteAsWallet(context, {
+    ...input,
+    wallet,
+    program: input.instruction.programId,
+    data: input.instruction.data,
+  }).addRemainingAccounts(
+    input.instruction.keys.map((key) => ({
+      ...key,
+      isSigner: key.pubkey === wallet ? false : key.isSigner,
+    }))
+  );
+}
@@ -0,0 +1,7 @@
+import { TypedExtension } from '.';
+import { ExtensionType } from '../generated';
+
+export const delegates = (slots = 0): TypedExtension => ({
+  type: ExtensionType.Delegates,
+  values: Array.from({ length: slots }, () => ({ address: null, roles: [] })),
+});
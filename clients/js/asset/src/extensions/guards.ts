@@ -0,0 +1,49 @@
+import {
+  Serializer,
+  array,
+  scalarEnum,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { Constraint, getConstraintSerializer } from '../constraints';
+import { ExtensionType } from '../generated';
+import { TypedExtension } from '.';
+
+export enum Action {
+  Burn,
+  Lock,
+  Unlock,
+  Approve,
+  Update,
+  Transfer,
+}
+
+export type Guard = {
+  action: Action;
+  constraint: Constraint;
+};
+
+export type Guards = {
+  values: Guard[];
+};
+
+export const guards = (values: Guards['values']): TypedExtension => ({
+  type: ExtensionType.Guards,
+  values,
+});
+
+export const getGuardSerializer = (): Serializer<Guard> =>
+  struct<Guard>(
+    [
+      ['action', scalarEnum<Action>(Action, { size: u64() })],
+      ['constraint', getConstraintSerializer()],
+    ],
+    { description: 'Guard' }
+  );
+
+export function getGuardsSerializer(): Serializer<Guards> {
+  return struct<Guards>(
+    [['values', array(getGuardSerializer(), { size: 'remainder' })]],
+    { description: 'Guards' }
+  );
+}
@@ -2,25 +2,48 @@ import { Serializer } from '@metaplex-foundation/umi/serializers';
 import { Asset } from '..';
 import {
   Attributes,
+  Authorities,
   Blob,
   Bucket,
   Creators,
+  DelegateExpiry,
+  Delegates,
+  Edition,
   ExtensionType,
   Grouping,
   Links,
   Manager,
+  MasterEdition,
   Metadata,
+  PendingAuthority,
+  PrimarySale,
   Proxy,
+  Rental,
+  RoyaltyPayment,
+  TransferHook,
+  UpdateDelegate,
   getAttributesSerializer,
+  getAuthoritiesSerializer,
   getBlobSerializer,
   getBucketSerializer,
   getCreatorsSerializer,
+  getDelegateExpirySerializer,
+  getDelegatesSerializer,
+  getEditionSerializer,
   getGroupingSerializer,
   getLinksSerializer,
   getManagerSerializer,
+  getMasterEditionSerializer,
   getMetadataSerializer,
+  getPendingAuthoritySerializer,
+  getPrimarySaleSerializer,
   getProxySerializer,
+  getRentalSerializer,
+  getRoyaltyPaymentSerializer,
+  getTransferHookSerializer,
+  getUpdateDelegateSerializer,
 } from '../generated';
+import { Guards, getGuardsSerializer } from './guards';
 import { Royalties, getRoyaltiesSerializer } from './royalties';
 import { Properties, getPropertiesSerializer } from './properties';
 
@@ -28,12 +51,18 @@ export * from './attributes';
 export * from './blob';
 export * from './bucket';
 export * from './creators';
+export * from './delegates';
 export * from './grouping';
+export * from './guards';
 export * from './links';
 export * from './manager';
+export * from './masterEdition';
 export * from './metadata';
+export * from './primarySale';
 export * from './properties';
 export * from './royalties';
+export * from './transferHook';
+export * from './updateDelegate';
 
 export type TypedExtension =
   | ({ type: ExtensionType.Attributes } & Attributes)
@@ -46,7 +75,19 @@ export type TypedExtension =
   | ({ type: ExtensionType.Manager } & Manager)
   | ({ type: ExtensionType.Proxy } & Proxy)
   | ({ type: ExtensionType.Properties } & Properties)
-  | ({ type: ExtensionType.Bucket } & Bucket);
+  | ({ type: ExtensionType.Bucket } & Bucket)
+  | ({ type: ExtensionType.Edition } & Edition)
+  | ({ type: ExtensionType.MasterEdition } & MasterEdition)
+  | ({ type: ExtensionType.DelegateExpiry } & DelegateExpiry)
+  | ({ type: ExtensionType.Delegates } & Delegates)
+  | ({ type: ExtensionType.UpdateDelegate } & UpdateDelegate)
+  | ({ type: ExtensionType.Authorities } & Authorities)
+  | ({ type: ExtensionType.TransferHook } & TransferHook)
+  | ({ type: ExtensionType.Guards } & Guards)
+  | ({ type: ExtensionType.Rental } & Rental)
+  | ({ type: ExtensionType.PendingAuthority } & PendingAuthority)
+  | ({ type: ExtensionType.PrimarySale } & PrimarySale)
+  | ({ type: ExtensionType.RoyaltyPayment } & RoyaltyPayment);
 
 export const getExtensionSerializerFromType = <T extends TypedExtension>(
   type: ExtensionType
@@ -75,6 +116,30 @@ export const getExtensionSerializerFromType = <T extends TypedExtension>(
         return getPropertiesSerializer();
       case ExtensionType.Bucket:
         return getBucketSerializer();
+      case ExtensionType.Edition:
+        return getEditionSerializer();
+      case ExtensionType.MasterEdition:
+        return getMasterEditionSerializer();
+      case ExtensionType.DelegateExpiry:
+        return getDelegateExpirySerializer();
+      case ExtensionType.Delegates:
+        return getDelegatesSerializer();
+      case ExtensionType.UpdateDelegate:
+        return getUpdateDelegateSerializer();
+      case ExtensionType.Authorities:
+        return getAuthoritiesSerializer();
+      case ExtensionType.TransferHook:
+        return getTransferHookSerializer();
+      case ExtensionType.Guards:
+        return getGuardsSerializer();
+      case ExtensionType.Rental:
+        return getRentalSerializer();
+      case ExtensionType.PendingAuthority:
+        return getPendingAuthoritySerializer();
+      case ExtensionType.PrimarySale:
+        return getPrimarySaleSerializer();
+      case ExtensionType.RoyaltyPayment:
+        return getRoyaltyPaymentSerializer();
       default:
         throw new Error(`Unknown extension type: ${type}`);
     }
@@ -0,0 +1,10 @@
+import { TypedExtension } from '.';
+import { ExtensionType, MasterEdition } from '../generated';
+
+export const masterEdition = (
+  maxSupply: MasterEdition['maxSupply'] | number = 0n
+): TypedExtension => ({
+  type: ExtensionType.MasterEdition,
+  supply: BigInt(0),
+  maxSupply: BigInt(maxSupply),
+});
@@ -0,0 +1,7 @@
+import { TypedExtension } from '.';
+import { ExtensionType } from '../generated';
+
+export const primarySale = (happened = false): TypedExtension => ({
+  type: ExtensionType.PrimarySale,
+  happened: BigInt(happened ? 1 : 0),
+});
@@ -1,28 +1,121 @@
-import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import {
+  Serializer,
+  scalarEnum,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
 import { Constraint, empty, getConstraintSerializer } from '../constraints';
 import { ExtensionType } from '../generated';
 import { TypedExtension } from '.';
 
+export enum RoyaltiesMode {
+  Constraint,
+  Payment,
+}
+
 export type Royalties = {
   basisPoints: bigint | number;
   constraint: Constraint;
+  /** Defaults to `RoyaltiesMode.Constraint` on royalties without a mode. */
+  mode?: RoyaltiesMode;
+  /** Minimum amount (in lamports) paid to the creators in `Payment` mode. */
+  amount?: bigint | number;
+  /**
+   * Basis points of the primary sale; `basisPoints` is the secondary rate. The
+   * primary sale is tracked by the `PrimarySale` extension of each asset.
+   */
+  primaryBasisPoints?: bigint | number;
 };
 
 export const royalties = (
   basisPoints: Royalties['basisPoints'],
-  constraint: Royalties['constraint'] = empty()
+  constraint: Royalties['constraint'] = empty(),
+  mode: RoyaltiesMode = RoyaltiesMode.Constraint,
+  amount: Royalties['amount'] = 0,
+  primaryBasisPoints?: Royalties['primaryBasisPoints']
 ): TypedExtension => ({
   type: ExtensionType.Royalties,
   basisPoints,
   constraint,
+  mode,
+  amount,
+  primaryBasisPoints,
 });
 
 export function getRoyaltiesSerializer(): Serializer<Royalties> {
-  return struct<Royalties>(
-    [
-      ['basisPoints', u64()],
-      ['constraint', getConstraintSerializer()],
-    ],
-    { description: 'Royalties' }
-  ) as Serializer<Royalties>;
+  const royaltiesSerializer = struct<
+    Pick<Royalties, 'basisPoints' | 'constraint'>
+  >([
+    ['basisPoints', u64()],
+    ['constraint', getConstraintSerializer()],
+  ]);
+  const modeSerializer = struct<Required<Pick<Royalties, 'mode' | 'amount'>>>([
+    ['mode', scalarEnum<RoyaltiesMode>(RoyaltiesMode, { size: u64() })],
+    ['amount', u64()],
+  ]);
+  const primarySerializer = struct<
+    Required<Pick<Royalties, 'primaryBasisPoints'>>
+  >([['primaryBasisPoints', u64()]]);
+
+  return {
+    description: 'Royalties',
+    fixedSize: null,
+    maxSize: null,
+    serialize: (value: Royalties) => {
+      const royaltiesBytes = royaltiesSerializer.serialize(value);
+      const modeBytes = modeSerializer.serialize({
+        mode: value.mode ?? RoyaltiesMode.Constraint,
+        amount: value.amount ?? 0,
+      });
+      // the primary rate is optional
+      const primaryBytes =
+        value.primaryBasisPoints === undefined
+          ? new Uint8Array()
+          : primarySerializer.serialize({
+              primaryBasisPoints: value.primaryBasisPoints,
+            });
+      const bytes = new Uint8Array(
+        royaltiesBytes.length + modeBytes.length + primaryBytes.length
+      );
+      bytes.set(royaltiesBytes);
+      bytes.set(modeBytes, royaltiesBytes.length);
+      bytes.set(primaryBytes, royaltiesBytes.length + modeBytes.length);
+      return bytes;
+    },
+    deserialize: (buffer: Uint8Array, offset = 0) => {
+      const [value, modeOffset] = royaltiesSerializer.deserialize(
+        buffer,
+        offset
+      );
+
+      // backwards compatibility: royalties created before the mode was added
+      // do not have the mode and amount bytes
+      if (modeOffset >= buffer.length) {
+        return [
+          {
+            ...value,
+            mode: RoyaltiesMode.Constraint,
+            amount: BigInt(0),
+          },
+          modeOffset,
+        ];
+      }
+
+      const [mode, primaryOffset] = modeSerializer.deserialize(
+        buffer,
+        modeOffset
+      );
+
+      // royalties without a primary rate do not have the primary rate bytes
+      if (primaryOffset >= buffer.length) {
+        return [{ ...value, ...mode }, primaryOffset];
+      }
+
+      const [primary, finalOffset] = primarySerializer.deserialize(
+        buffer,
+        primaryOffset
+      );
+      return [{ ...value, ...mode, ...primary }, finalOffset];
+    },
+  };
 }
@@ -0,0 +1,8 @@
+import { PublicKey } from '@metaplex-foundation/umi';
+import { TypedExtension } from '.';
+import { ExtensionType } from '../generated';
+
+export const transferHook = (program: PublicKey): TypedExtension => ({
+  type: ExtensionType.TransferHook,
+  program,
+});
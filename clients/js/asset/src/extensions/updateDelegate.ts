@@ -0,0 +1,12 @@
+import { PublicKey } from '@metaplex-foundation/umi';
+import { TypedExtension } from '.';
+import { ExtensionType } from '../generated';
+
+export const updateDelegate = (
+  delegate: PublicKey,
+  extensionTypes: ExtensionType[]
+): TypedExtension => ({
+  type: ExtensionType.UpdateDelegate,
+  delegate,
+  extensionTypes,
+});
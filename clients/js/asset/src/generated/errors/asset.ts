@@ -301,6 +301,45 @@ export class ExtensionLengthInvalidError extends ProgramError {
 codeToErrorMap.set(0x15, ExtensionLengthInvalidError);
 nameToErrorMap.set('ExtensionLengthInvalid', ExtensionLengthInvalidError);
 
+/** DelegateExpired: Delegate expired */
+export class DelegateExpiredError extends ProgramError {
+  override readonly name: string = 'DelegateExpired';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super('Delegate expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x16, DelegateExpiredError);
+nameToErrorMap.set('DelegateExpired', DelegateExpiredError);
+
+/** ImmutableExtension: Extension is immutable */
+export class ImmutableExtensionError extends ProgramError {
+  override readonly name: string = 'ImmutableExtension';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super('Extension is immutable', program, cause);
+  }
+}
+codeToErrorMap.set(0x17, ImmutableExtensionError);
+nameToErrorMap.set('ImmutableExtension', ImmutableExtensionError);
+
+/** RentalNotExpired: Rental not expired */
+export class RentalNotExpiredError extends ProgramError {
+  override readonly name: string = 'RentalNotExpired';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super('Rental not expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x18, RentalNotExpiredError);
+nameToErrorMap.set('RentalNotExpired', RentalNotExpiredError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
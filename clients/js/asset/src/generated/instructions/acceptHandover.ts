@@ -0,0 +1,112 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AcceptHandoverInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The proposed authority of the asset */
+  newAuthority?: Signer;
+};
+
+// Data.
+export type AcceptHandoverInstructionData = { discriminator: number };
+
+export type AcceptHandoverInstructionDataArgs = {};
+
+export function getAcceptHandoverInstructionDataSerializer(): Serializer<
+  AcceptHandoverInstructionDataArgs,
+  AcceptHandoverInstructionData
+> {
+  return mapSerializer<
+    AcceptHandoverInstructionDataArgs,
+    any,
+    AcceptHandoverInstructionData
+  >(
+    struct<AcceptHandoverInstructionData>([['discriminator', u8()]], {
+      description: 'AcceptHandoverInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 30 })
+  ) as Serializer<
+    AcceptHandoverInstructionDataArgs,
+    AcceptHandoverInstructionData
+  >;
+}
+
+// Instruction.
+export function acceptHandover(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: AcceptHandoverInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    newAuthority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.newAuthority.value) {
+    resolvedAccounts.newAuthority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAcceptHandoverInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
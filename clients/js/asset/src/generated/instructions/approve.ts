@@ -8,15 +8,20 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  i64,
   mapSerializer,
+  option,
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
@@ -45,9 +50,13 @@ export type ApproveInstructionAccounts = {
 export type ApproveInstructionData = {
   discriminator: number;
   delegateInput: DelegateInput;
+  expiry: Option<bigint>;
 };
 
-export type ApproveInstructionDataArgs = { delegateInput: DelegateInputArgs };
+export type ApproveInstructionDataArgs = {
+  delegateInput: DelegateInputArgs;
+  expiry?: OptionOrNullable<number | bigint>;
+};
 
 export function getApproveInstructionDataSerializer(): Serializer<
   ApproveInstructionDataArgs,
@@ -58,10 +67,11 @@ export function getApproveInstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['delegateInput', getDelegateInputSerializer()],
+        ['expiry', option(i64())],
       ],
       { description: 'ApproveInstructionData' }
     ),
-    (value) => ({ ...value, discriminator: 3 })
+    (value) => ({ ...value, discriminator: 3, expiry: value.expiry ?? none() })
   ) as Serializer<ApproveInstructionDataArgs, ApproveInstructionData>;
 }
 
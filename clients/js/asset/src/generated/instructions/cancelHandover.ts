@@ -0,0 +1,112 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelHandoverInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The authority of the asset */
+  authority?: Signer;
+};
+
+// Data.
+export type CancelHandoverInstructionData = { discriminator: number };
+
+export type CancelHandoverInstructionDataArgs = {};
+
+export function getCancelHandoverInstructionDataSerializer(): Serializer<
+  CancelHandoverInstructionDataArgs,
+  CancelHandoverInstructionData
+> {
+  return mapSerializer<
+    CancelHandoverInstructionDataArgs,
+    any,
+    CancelHandoverInstructionData
+  >(
+    struct<CancelHandoverInstructionData>([['discriminator', u8()]], {
+      description: 'CancelHandoverInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 31 })
+  ) as Serializer<
+    CancelHandoverInstructionDataArgs,
+    CancelHandoverInstructionData
+  >;
+}
+
+// Instruction.
+export function cancelHandover(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: CancelHandoverInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCancelHandoverInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
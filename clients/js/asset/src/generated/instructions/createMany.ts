@@ -0,0 +1,177 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AssetInput,
+  AssetInputArgs,
+  Standard,
+  StandardArgs,
+  getAssetInputSerializer,
+  getStandardSerializer,
+} from '../types';
+
+// Accounts.
+export type CreateManyInstructionAccounts = {
+  /** The authority of the assets */
+  authority?: PublicKey | Pda | Signer;
+  /** Asset account of the group */
+  group?: PublicKey | Pda;
+  /** Group authority for creating assets into a group */
+  groupAuthority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateManyInstructionData = {
+  discriminator: number;
+  standard: Standard;
+  mutable: boolean;
+  assets: Array<AssetInput>;
+};
+
+export type CreateManyInstructionDataArgs = {
+  standard?: StandardArgs;
+  mutable?: boolean;
+  assets: Array<AssetInputArgs>;
+};
+
+export function getCreateManyInstructionDataSerializer(): Serializer<
+  CreateManyInstructionDataArgs,
+  CreateManyInstructionData
+> {
+  return mapSerializer<
+    CreateManyInstructionDataArgs,
+    any,
+    CreateManyInstructionData
+  >(
+    struct<CreateManyInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['standard', getStandardSerializer()],
+        ['mutable', bool()],
+        ['assets', array(getAssetInputSerializer())],
+      ],
+      { description: 'CreateManyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 22,
+      standard: value.standard ?? Standard.NonFungible,
+      mutable: value.mutable ?? true,
+    })
+  ) as Serializer<CreateManyInstructionDataArgs, CreateManyInstructionData>;
+}
+
+// Args.
+export type CreateManyInstructionArgs = CreateManyInstructionDataArgs;
+
+// Instruction.
+export function createMany(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: CreateManyInstructionAccounts & CreateManyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    group: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    groupAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.groupAuthority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateManyInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    if (resolvedAccounts.payer.value) {
+      resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+        'systemProgram',
+        '11111111111111111111111111111111'
+      );
+      resolvedAccounts.systemProgram.isWritable = false;
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateManyInstructionDataSerializer().serialize(
+    resolvedArgs as CreateManyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,158 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  RuleSetInput,
+  RuleSetInputArgs,
+  getRuleSetInputSerializer,
+} from '../types';
+
+// Accounts.
+export type CreateRuleSetInstructionAccounts = {
+  /** Rule set account */
+  ruleSet: Signer;
+  /** The authority of the rule set */
+  authority?: PublicKey | Pda | Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateRuleSetInstructionData = {
+  discriminator: number;
+  ruleSetInput: RuleSetInput;
+};
+
+export type CreateRuleSetInstructionDataArgs = {
+  ruleSetInput: RuleSetInputArgs;
+};
+
+export function getCreateRuleSetInstructionDataSerializer(): Serializer<
+  CreateRuleSetInstructionDataArgs,
+  CreateRuleSetInstructionData
+> {
+  return mapSerializer<
+    CreateRuleSetInstructionDataArgs,
+    any,
+    CreateRuleSetInstructionData
+  >(
+    struct<CreateRuleSetInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['ruleSetInput', getRuleSetInputSerializer()],
+      ],
+      { description: 'CreateRuleSetInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 23 })
+  ) as Serializer<
+    CreateRuleSetInstructionDataArgs,
+    CreateRuleSetInstructionData
+  >;
+}
+
+// Args.
+export type CreateRuleSetInstructionArgs = CreateRuleSetInstructionDataArgs;
+
+// Instruction.
+export function createRuleSet(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: CreateRuleSetInstructionAccounts & CreateRuleSetInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    ruleSet: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.ruleSet ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateRuleSetInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateRuleSetInstructionDataSerializer().serialize(
+    resolvedArgs as CreateRuleSetInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
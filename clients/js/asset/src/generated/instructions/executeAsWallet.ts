@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ExecuteAsWalletInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The owner of the asset */
+  owner?: Signer;
+  /** The wallet of the asset */
+  wallet: PublicKey | Pda;
+  /** The program to invoke */
+  program: PublicKey | Pda;
+  /** The asset defining the group, required when the asset is part of a group */
+  group?: PublicKey | Pda;
+};
+
+// Data.
+export type ExecuteAsWalletInstructionData = {
+  discriminator: number;
+  data: Uint8Array;
+};
+
+export type ExecuteAsWalletInstructionDataArgs = { data: Uint8Array };
+
+export function getExecuteAsWalletInstructionDataSerializer(): Serializer<
+  ExecuteAsWalletInstructionDataArgs,
+  ExecuteAsWalletInstructionData
+> {
+  return mapSerializer<
+    ExecuteAsWalletInstructionDataArgs,
+    any,
+    ExecuteAsWalletInstructionData
+  >(
+    struct<ExecuteAsWalletInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['data', bytes({ size: u32() })],
+      ],
+      { description: 'ExecuteAsWalletInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 28 })
+  ) as Serializer<
+    ExecuteAsWalletInstructionDataArgs,
+    ExecuteAsWalletInstructionData
+  >;
+}
+
+// Args.
+export type ExecuteAsWalletInstructionArgs = ExecuteAsWalletInstructionDataArgs;
+
+// Instruction.
+export function executeAsWallet(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: ExecuteAsWalletInstructionAccounts & ExecuteAsWalletInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    wallet: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.wallet ?? null,
+    },
+    program: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+    group: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.group ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ExecuteAsWalletInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.owner.value) {
+    resolvedAccounts.owner.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getExecuteAsWalletInstructionDataSerializer().serialize(
+    resolvedArgs as ExecuteAsWalletInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
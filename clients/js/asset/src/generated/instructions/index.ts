@@ -6,4 +6,13 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './acceptHandover';
+export * from './cancelHandover';
 export * from './close';
+export * from './createRuleSet';
+export * from './freezeGroup';
+export * from './proposeHandover';
+export * from './recall';
+export * from './reclaim';
+export * from './thawGroup';
+export * from './updateRuleSet';
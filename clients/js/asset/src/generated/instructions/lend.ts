@@ -0,0 +1,155 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type LendInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The owner of the asset */
+  owner?: Signer;
+  /** The account receiving the asset */
+  renter: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The asset defining the group, if applicable */
+  group?: PublicKey | Pda;
+};
+
+// Data.
+export type LendInstructionData = { discriminator: number; expiry: bigint };
+
+export type LendInstructionDataArgs = { expiry: number | bigint };
+
+export function getLendInstructionDataSerializer(): Serializer<
+  LendInstructionDataArgs,
+  LendInstructionData
+> {
+  return mapSerializer<LendInstructionDataArgs, any, LendInstructionData>(
+    struct<LendInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['expiry', i64()],
+      ],
+      { description: 'LendInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 26 })
+  ) as Serializer<LendInstructionDataArgs, LendInstructionData>;
+}
+
+// Args.
+export type LendInstructionArgs = LendInstructionDataArgs;
+
+// Instruction.
+export function lend(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: LendInstructionAccounts & LendInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    renter: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.renter ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    group: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.group ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: LendInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.owner.value) {
+    resolvedAccounts.owner.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    if (resolvedAccounts.payer.value) {
+      resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+        'systemProgram',
+        '11111111111111111111111111111111'
+      );
+      resolvedAccounts.systemProgram.isWritable = false;
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getLendInstructionDataSerializer().serialize(
+    resolvedArgs as LendInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
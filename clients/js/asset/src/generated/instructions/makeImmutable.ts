@@ -0,0 +1,134 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ExtensionType,
+  ExtensionTypeArgs,
+  getExtensionTypeSerializer,
+} from '../types';
+
+// Accounts.
+export type MakeImmutableInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The authority of the extension (or the asset account when uninitialized) */
+  authority?: Signer;
+};
+
+// Data.
+export type MakeImmutableInstructionData = {
+  discriminator: number;
+  extensionType: ExtensionType;
+};
+
+export type MakeImmutableInstructionDataArgs = {
+  extensionType: ExtensionTypeArgs;
+};
+
+export function getMakeImmutableInstructionDataSerializer(): Serializer<
+  MakeImmutableInstructionDataArgs,
+  MakeImmutableInstructionData
+> {
+  return mapSerializer<
+    MakeImmutableInstructionDataArgs,
+    any,
+    MakeImmutableInstructionData
+  >(
+    struct<MakeImmutableInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['extensionType', getExtensionTypeSerializer()],
+      ],
+      { description: 'MakeImmutableInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 20 })
+  ) as Serializer<
+    MakeImmutableInstructionDataArgs,
+    MakeImmutableInstructionData
+  >;
+}
+
+// Args.
+export type MakeImmutableInstructionArgs = MakeImmutableInstructionDataArgs;
+
+// Instruction.
+export function makeImmutable(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: MakeImmutableInstructionAccounts & MakeImmutableInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MakeImmutableInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMakeImmutableInstructionDataSerializer().serialize(
+    resolvedArgs as MakeImmutableInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PayRoyaltiesInstructionAccounts = {
+  /** The account paying the royalties */
+  payer?: Signer;
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The asset defining the group, if applicable */
+  group?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type PayRoyaltiesInstructionData = {
+  discriminator: number;
+  price: bigint;
+};
+
+export type PayRoyaltiesInstructionDataArgs = { price: number | bigint };
+
+export function getPayRoyaltiesInstructionDataSerializer(): Serializer<
+  PayRoyaltiesInstructionDataArgs,
+  PayRoyaltiesInstructionData
+> {
+  return mapSerializer<
+    PayRoyaltiesInstructionDataArgs,
+    any,
+    PayRoyaltiesInstructionData
+  >(
+    struct<PayRoyaltiesInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['price', u64()],
+      ],
+      { description: 'PayRoyaltiesInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 25 })
+  ) as Serializer<PayRoyaltiesInstructionDataArgs, PayRoyaltiesInstructionData>;
+}
+
+// Args.
+export type PayRoyaltiesInstructionArgs = PayRoyaltiesInstructionDataArgs;
+
+// Instruction.
+export function payRoyalties(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: PayRoyaltiesInstructionAccounts & PayRoyaltiesInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    payer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    group: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.group ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PayRoyaltiesInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPayRoyaltiesInstructionDataSerializer().serialize(
+    resolvedArgs as PayRoyaltiesInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
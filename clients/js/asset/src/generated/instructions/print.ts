@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PrintInstructionAccounts = {
+  /** Master asset account */
+  master: PublicKey | Pda;
+  /** Asset account of the print */
+  asset: Signer;
+  /** The authority of the master asset */
+  authority?: Signer;
+  /** The owner of the print */
+  owner?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type PrintInstructionData = { discriminator: number };
+
+export type PrintInstructionDataArgs = {};
+
+export function getPrintInstructionDataSerializer(): Serializer<
+  PrintInstructionDataArgs,
+  PrintInstructionData
+> {
+  return mapSerializer<PrintInstructionDataArgs, any, PrintInstructionData>(
+    struct<PrintInstructionData>([['discriminator', u8()]], {
+      description: 'PrintInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 18 })
+  ) as Serializer<PrintInstructionDataArgs, PrintInstructionData>;
+}
+
+// Instruction.
+export function print(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: PrintInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    master: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.master ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    owner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.owner.value) {
+    resolvedAccounts.owner.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPrintInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
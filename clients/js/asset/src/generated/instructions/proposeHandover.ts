@@ -0,0 +1,142 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ProposeHandoverInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The authority of the asset */
+  authority?: Signer;
+  /** The proposed authority of the asset */
+  newAuthority: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ProposeHandoverInstructionData = { discriminator: number };
+
+export type ProposeHandoverInstructionDataArgs = {};
+
+export function getProposeHandoverInstructionDataSerializer(): Serializer<
+  ProposeHandoverInstructionDataArgs,
+  ProposeHandoverInstructionData
+> {
+  return mapSerializer<
+    ProposeHandoverInstructionDataArgs,
+    any,
+    ProposeHandoverInstructionData
+  >(
+    struct<ProposeHandoverInstructionData>([['discriminator', u8()]], {
+      description: 'ProposeHandoverInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 29 })
+  ) as Serializer<
+    ProposeHandoverInstructionDataArgs,
+    ProposeHandoverInstructionData
+  >;
+}
+
+// Instruction.
+export function proposeHandover(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: ProposeHandoverInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    if (resolvedAccounts.payer.value) {
+      resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+        'systemProgram',
+        '11111111111111111111111111111111'
+      );
+      resolvedAccounts.systemProgram.isWritable = false;
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getProposeHandoverInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,112 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RecallInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The manager delegate of the asset */
+  manager?: Signer;
+  /** The account receiving the asset */
+  recipient: PublicKey | Pda;
+};
+
+// Data.
+export type RecallInstructionData = { discriminator: number };
+
+export type RecallInstructionDataArgs = {};
+
+export function getRecallInstructionDataSerializer(): Serializer<
+  RecallInstructionDataArgs,
+  RecallInstructionData
+> {
+  return mapSerializer<RecallInstructionDataArgs, any, RecallInstructionData>(
+    struct<RecallInstructionData>([['discriminator', u8()]], {
+      description: 'RecallInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 32 })
+  ) as Serializer<RecallInstructionDataArgs, RecallInstructionData>;
+}
+
+// Instruction.
+export function recall(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: RecallInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    manager: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.manager ?? null,
+    },
+    recipient: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.recipient ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.manager.value) {
+    resolvedAccounts.manager.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRecallInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
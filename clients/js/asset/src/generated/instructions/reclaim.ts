@@ -0,0 +1,92 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReclaimInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+};
+
+// Data.
+export type ReclaimInstructionData = { discriminator: number };
+
+export type ReclaimInstructionDataArgs = {};
+
+export function getReclaimInstructionDataSerializer(): Serializer<
+  ReclaimInstructionDataArgs,
+  ReclaimInstructionData
+> {
+  return mapSerializer<ReclaimInstructionDataArgs, any, ReclaimInstructionData>(
+    struct<ReclaimInstructionData>([['discriminator', u8()]], {
+      description: 'ReclaimInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 27 })
+  ) as Serializer<ReclaimInstructionDataArgs, ReclaimInstructionData>;
+}
+
+// Instruction.
+export function reclaim(
+  context: Pick<Context, 'programs'>,
+  input: ReclaimInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReclaimInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
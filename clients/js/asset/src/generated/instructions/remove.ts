@@ -35,7 +35,7 @@ import {
 export type RemoveInstructionAccounts = {
   /** Asset account */
   asset: PublicKey | Pda;
-  /** The authority of the asset */
+  /** The authority of the asset or extension */
   authority?: Signer;
   /** The asset defining the group, if applicable */
   group?: PublicKey | Pda;
@@ -37,6 +37,8 @@ export type RevokeInstructionAccounts = {
   asset: PublicKey | Pda;
   /** Owner of the asset or current delegate */
   signer?: Signer;
+  /** The delegate to revoke */
+  delegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -89,6 +91,11 @@ export function revoke(
       isWritable: false as boolean,
       value: input.signer ?? null,
     },
+    delegate: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.delegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
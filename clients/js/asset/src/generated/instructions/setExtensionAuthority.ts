@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ExtensionType,
+  ExtensionTypeArgs,
+  getExtensionTypeSerializer,
+} from '../types';
+
+// Accounts.
+export type SetExtensionAuthorityInstructionAccounts = {
+  /** Asset account */
+  asset: PublicKey | Pda;
+  /** The current authority of the extension */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetExtensionAuthorityInstructionData = {
+  discriminator: number;
+  extensionType: ExtensionType;
+  newAuthority: Option<PublicKey>;
+};
+
+export type SetExtensionAuthorityInstructionDataArgs = {
+  extensionType: ExtensionTypeArgs;
+  newAuthority?: OptionOrNullable<PublicKey>;
+};
+
+export function getSetExtensionAuthorityInstructionDataSerializer(): Serializer<
+  SetExtensionAuthorityInstructionDataArgs,
+  SetExtensionAuthorityInstructionData
+> {
+  return mapSerializer<
+    SetExtensionAuthorityInstructionDataArgs,
+    any,
+    SetExtensionAuthorityInstructionData
+  >(
+    struct<SetExtensionAuthorityInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['extensionType', getExtensionTypeSerializer()],
+        ['newAuthority', option(publicKeySerializer())],
+      ],
+      { description: 'SetExtensionAuthorityInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 19,
+      newAuthority: value.newAuthority ?? none(),
+    })
+  ) as Serializer<
+    SetExtensionAuthorityInstructionDataArgs,
+    SetExtensionAuthorityInstructionData
+  >;
+}
+
+// Args.
+export type SetExtensionAuthorityInstructionArgs = SetExtensionAuthorityInstructionDataArgs;
+
+// Instruction.
+export function setExtensionAuthority(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetExtensionAuthorityInstructionAccounts & SetExtensionAuthorityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetExtensionAuthorityInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    if (resolvedAccounts.payer.value) {
+      resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+        'systemProgram',
+        '11111111111111111111111111111111'
+      );
+      resolvedAccounts.systemProgram.isWritable = false;
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetExtensionAuthorityInstructionDataSerializer().serialize(
+    resolvedArgs as SetExtensionAuthorityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,104 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ThawGroupInstructionAccounts = {
+  /** Asset account of the group */
+  group: PublicKey | Pda;
+  /** The authority of the group */
+  authority: Signer;
+};
+
+// Data.
+export type ThawGroupInstructionData = { discriminator: number };
+
+export type ThawGroupInstructionDataArgs = {};
+
+export function getThawGroupInstructionDataSerializer(): Serializer<
+  ThawGroupInstructionDataArgs,
+  ThawGroupInstructionData
+> {
+  return mapSerializer<
+    ThawGroupInstructionDataArgs,
+    any,
+    ThawGroupInstructionData
+  >(
+    struct<ThawGroupInstructionData>([['discriminator', u8()]], {
+      description: 'ThawGroupInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 34 })
+  ) as Serializer<ThawGroupInstructionDataArgs, ThawGroupInstructionData>;
+}
+
+// Instruction.
+export function thawGroup(
+  context: Pick<Context, 'programs'>,
+  input: ThawGroupInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getThawGroupInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
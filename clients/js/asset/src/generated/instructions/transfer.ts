@@ -34,7 +34,7 @@ export type TransferInstructionAccounts = {
   signer?: Signer;
   /** The recipient of the asset */
   recipient: PublicKey | Pda;
-  /** The asset defining the group, if applicable */
+  /** The asset defining the group, required when the asset is part of a group */
   group?: PublicKey | Pda;
 };
 
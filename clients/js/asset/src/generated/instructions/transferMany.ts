@@ -0,0 +1,109 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferManyInstructionAccounts = {
+  /** Current owner of the assets or transfer delegate */
+  signer?: Signer;
+  /** The recipient of the assets */
+  recipient: PublicKey | Pda;
+};
+
+// Data.
+export type TransferManyInstructionData = { discriminator: number };
+
+export type TransferManyInstructionDataArgs = {};
+
+export function getTransferManyInstructionDataSerializer(): Serializer<
+  TransferManyInstructionDataArgs,
+  TransferManyInstructionData
+> {
+  return mapSerializer<
+    TransferManyInstructionDataArgs,
+    any,
+    TransferManyInstructionData
+  >(
+    struct<TransferManyInstructionData>([['discriminator', u8()]], {
+      description: 'TransferManyInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 21 })
+  ) as Serializer<TransferManyInstructionDataArgs, TransferManyInstructionData>;
+}
+
+// Instruction.
+export function transferMany(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: TransferManyInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    signer: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.signer ?? null,
+    },
+    recipient: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipient ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.signer.value) {
+    resolvedAccounts.signer.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTransferManyInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -41,7 +41,7 @@ import {
 export type UpdateInstructionAccounts = {
   /** Asset account */
   asset: PublicKey | Pda;
-  /** The authority of the asset */
+  /** The authority or update delegate of the asset */
   authority?: Signer;
   /** Extension buffer (uninitialized asset) account */
   buffer?: PublicKey | Pda;
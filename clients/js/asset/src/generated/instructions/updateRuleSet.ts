@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  RuleSetInput,
+  RuleSetInputArgs,
+  getRuleSetInputSerializer,
+} from '../types';
+
+// Accounts.
+export type UpdateRuleSetInstructionAccounts = {
+  /** Rule set account */
+  ruleSet: PublicKey | Pda;
+  /** The authority of the rule set */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateRuleSetInstructionData = {
+  discriminator: number;
+  ruleSetInput: RuleSetInput;
+};
+
+export type UpdateRuleSetInstructionDataArgs = {
+  ruleSetInput: RuleSetInputArgs;
+};
+
+export function getUpdateRuleSetInstructionDataSerializer(): Serializer<
+  UpdateRuleSetInstructionDataArgs,
+  UpdateRuleSetInstructionData
+> {
+  return mapSerializer<
+    UpdateRuleSetInstructionDataArgs,
+    any,
+    UpdateRuleSetInstructionData
+  >(
+    struct<UpdateRuleSetInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['ruleSetInput', getRuleSetInputSerializer()],
+      ],
+      { description: 'UpdateRuleSetInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 24 })
+  ) as Serializer<
+    UpdateRuleSetInstructionDataArgs,
+    UpdateRuleSetInstructionData
+  >;
+}
+
+// Args.
+export type UpdateRuleSetInstructionArgs = UpdateRuleSetInstructionDataArgs;
+
+// Instruction.
+export function updateRuleSet(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: UpdateRuleSetInstructionAccounts & UpdateRuleSetInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'asset',
+    'AssetGtQBTSgm5s91d1RAQod5JmaZiJDxqsgtqrZud73'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    ruleSet: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.ruleSet ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateRuleSetInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    if (resolvedAccounts.payer.value) {
+      resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+        'systemProgram',
+        '11111111111111111111111111111111'
+      );
+      resolvedAccounts.systemProgram.isWritable = false;
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateRuleSetInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateRuleSetInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
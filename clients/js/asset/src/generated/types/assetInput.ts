@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AssetInput = { name: string; owner: PublicKey };
+
+export type AssetInputArgs = AssetInput;
+
+export function getAssetInputSerializer(): Serializer<
+  AssetInputArgs,
+  AssetInput
+> {
+  return struct<AssetInput>(
+    [
+      ['name', string()],
+      ['owner', publicKeySerializer()],
+    ],
+    { description: 'AssetInput' }
+  ) as Serializer<AssetInputArgs, AssetInput>;
+}
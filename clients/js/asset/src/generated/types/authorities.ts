@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Authorities = { values: Array<PublicKey> };
+
+export type AuthoritiesArgs = Authorities;
+
+export function getAuthoritiesSerializer(): Serializer<
+  AuthoritiesArgs,
+  Authorities
+> {
+  return struct<Authorities>(
+    [['values', array(publicKeySerializer(), { size: 'remainder' })]],
+    { description: 'Authorities' }
+  ) as Serializer<AuthoritiesArgs, Authorities>;
+}
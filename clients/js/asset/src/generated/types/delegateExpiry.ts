@@ -0,0 +1,28 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Expiry, ExpiryArgs, getExpirySerializer } from '.';
+
+export type DelegateExpiry = { values: Array<Expiry> };
+
+export type DelegateExpiryArgs = { values: Array<ExpiryArgs> };
+
+export function getDelegateExpirySerializer(): Serializer<
+  DelegateExpiryArgs,
+  DelegateExpiry
+> {
+  return struct<DelegateExpiry>(
+    [['values', array(getExpirySerializer(), { size: 'remainder' })]],
+    { description: 'DelegateExpiry' }
+  ) as Serializer<DelegateExpiryArgs, DelegateExpiry>;
+}
@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Delegate, DelegateArgs, getDelegateSerializer } from '.';
+
+export type Delegates = { values: Array<Delegate> };
+
+export type DelegatesArgs = { values: Array<DelegateArgs> };
+
+export function getDelegatesSerializer(): Serializer<DelegatesArgs, Delegates> {
+  return struct<Delegates>(
+    [['values', array(getDelegateSerializer(), { size: 'remainder' })]],
+    { description: 'Delegates' }
+  ) as Serializer<DelegatesArgs, Delegates>;
+}
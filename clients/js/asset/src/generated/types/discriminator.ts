@@ -11,6 +11,7 @@ import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
 export enum Discriminator {
   Uninitialized,
   Asset,
+  RuleSet,
 }
 
 export type DiscriminatorArgs = Discriminator;
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Edition = { master: PublicKey; number: bigint };
+
+export type EditionArgs = { master: PublicKey; number: number | bigint };
+
+export function getEditionSerializer(): Serializer<EditionArgs, Edition> {
+  return struct<Edition>(
+    [
+      ['master', publicKeySerializer()],
+      ['number', u64()],
+    ],
+    { description: 'Edition' }
+  ) as Serializer<EditionArgs, Edition>;
+}
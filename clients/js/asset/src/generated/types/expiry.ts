@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Expiry = { delegate: PublicKey; timestamp: bigint };
+
+export type ExpiryArgs = { delegate: PublicKey; timestamp: number | bigint };
+
+export function getExpirySerializer(): Serializer<ExpiryArgs, Expiry> {
+  return struct<Expiry>(
+    [
+      ['delegate', publicKeySerializer()],
+      ['timestamp', i64()],
+    ],
+    { description: 'Expiry' }
+  ) as Serializer<ExpiryArgs, Expiry>;
+}
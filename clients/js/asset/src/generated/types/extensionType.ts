@@ -21,6 +21,18 @@ export enum ExtensionType {
   Proxy,
   Properties,
   Bucket,
+  Edition,
+  MasterEdition,
+  DelegateExpiry,
+  Delegates,
+  UpdateDelegate,
+  Authorities,
+  TransferHook,
+  Guards,
+  Rental,
+  PendingAuthority,
+  PrimarySale,
+  RoyaltyPayment,
 }
 
 export type ExtensionTypeArgs = ExtensionType;
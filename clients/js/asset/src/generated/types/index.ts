@@ -6,15 +6,21 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './assetInput';
 export * from './attributes';
+export * from './authorities';
 export * from './blob';
 export * from './bucket';
 export * from './creator';
 export * from './creators';
 export * from './delegate';
+export * from './delegateExpiry';
 export * from './delegateInput';
 export * from './delegateRole';
+export * from './delegates';
 export * from './discriminator';
+export * from './edition';
+export * from './expiry';
 export * from './extensionHeader';
 export * from './extensionInput';
 export * from './extensionType';
@@ -22,10 +28,18 @@ export * from './grouping';
 export * from './link';
 export * from './links';
 export * from './manager';
+export * from './masterEdition';
 export * from './metadata';
+export * from './pendingAuthority';
+export * from './primarySale';
 export * from './proxy';
+export * from './rental';
+export * from './royaltyPayment';
+export * from './ruleSetInput';
 export * from './standard';
 export * from './state';
 export * from './strategy';
 export * from './trait';
+export * from './transferHook';
 export * from './type';
+export * from './updateDelegate';
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type MasterEdition = { supply: bigint; maxSupply: bigint };
+
+export type MasterEditionArgs = {
+  supply: number | bigint;
+  maxSupply: number | bigint;
+};
+
+export function getMasterEditionSerializer(): Serializer<
+  MasterEditionArgs,
+  MasterEdition
+> {
+  return struct<MasterEdition>(
+    [
+      ['supply', u64()],
+      ['maxSupply', u64()],
+    ],
+    { description: 'MasterEdition' }
+  ) as Serializer<MasterEditionArgs, MasterEdition>;
+}
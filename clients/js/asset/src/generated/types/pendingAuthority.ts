@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PendingAuthority = { authority: PublicKey };
+
+export type PendingAuthorityArgs = PendingAuthority;
+
+export function getPendingAuthoritySerializer(): Serializer<
+  PendingAuthorityArgs,
+  PendingAuthority
+> {
+  return struct<PendingAuthority>([['authority', publicKeySerializer()]], {
+    description: 'PendingAuthority',
+  }) as Serializer<PendingAuthorityArgs, PendingAuthority>;
+}
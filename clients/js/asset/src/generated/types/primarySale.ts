@@ -0,0 +1,22 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type PrimarySale = { happened: bigint };
+
+export type PrimarySaleArgs = { happened: number | bigint };
+
+export function getPrimarySaleSerializer(): Serializer<
+  PrimarySaleArgs,
+  PrimarySale
+> {
+  return struct<PrimarySale>([['happened', u64()]], {
+    description: 'PrimarySale',
+  }) as Serializer<PrimarySaleArgs, PrimarySale>;
+}
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Rental = { lender: PublicKey; expiry: bigint };
+
+export type RentalArgs = { lender: PublicKey; expiry: number | bigint };
+
+export function getRentalSerializer(): Serializer<RentalArgs, Rental> {
+  return struct<Rental>(
+    [
+      ['lender', publicKeySerializer()],
+      ['expiry', i64()],
+    ],
+    { description: 'Rental' }
+  ) as Serializer<RentalArgs, Rental>;
+}
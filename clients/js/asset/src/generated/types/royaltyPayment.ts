@@ -0,0 +1,22 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type RoyaltyPayment = { amount: bigint };
+
+export type RoyaltyPaymentArgs = { amount: number | bigint };
+
+export function getRoyaltyPaymentSerializer(): Serializer<
+  RoyaltyPaymentArgs,
+  RoyaltyPayment
+> {
+  return struct<RoyaltyPayment>([['amount', u64()]], {
+    description: 'RoyaltyPayment',
+  }) as Serializer<RoyaltyPaymentArgs, RoyaltyPayment>;
+}
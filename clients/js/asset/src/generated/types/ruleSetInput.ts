@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RuleSetInput = { constraint: Uint8Array };
+
+export type RuleSetInputArgs = RuleSetInput;
+
+export function getRuleSetInputSerializer(): Serializer<
+  RuleSetInputArgs,
+  RuleSetInput
+> {
+  return struct<RuleSetInput>([['constraint', bytes({ size: u32() })]], {
+    description: 'RuleSetInput',
+  }) as Serializer<RuleSetInputArgs, RuleSetInput>;
+}
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ExtensionType,
+  ExtensionTypeArgs,
+  getExtensionTypeSerializer,
+} from '.';
+
+export type UpdateDelegate = {
+  delegate: PublicKey;
+  extensionTypes: Array<ExtensionType>;
+};
+
+export type UpdateDelegateArgs = {
+  delegate: PublicKey;
+  extensionTypes: Array<ExtensionTypeArgs>;
+};
+
+export function getUpdateDelegateSerializer(): Serializer<
+  UpdateDelegateArgs,
+  UpdateDelegate
+> {
+  return struct<UpdateDelegate>(
+    [
+      ['delegate', publicKeySerializer()],
+      ['extensionTypes', array(getExtensionTypeSerializer(), { size: 'remainder' })],
+    ],
+    { description: 'UpdateDelegate' }
+  ) as Serializer<UpdateDelegateArgs, UpdateDelegate>;
+}
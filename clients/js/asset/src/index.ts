@@ -33,19 +33,28 @@ export * from './approve';
 export * from './burn';
 export * from './constraints';
 export * from './create';
+export * from './createMany';
+export * from './executeAsWallet';
 export * from './extensions';
 export * from './generated';
 export * from './group';
 export * from './handover';
 export * from './hooked';
 export * from './initialize';
+export * from './lend';
 export * from './lock';
+export * from './makeImmutable';
 export * from './mint';
+export * from './payRoyalties';
 export * from './plugin';
+export * from './print';
 export * from './remove';
 export * from './resize';
 export * from './revoke';
+export * from './setExtensionAuthority';
 export * from './transfer';
+export * from './trailingAccounts';
+export * from './transferMany';
 export * from './ungroup';
 export * from './unlock';
 export * from './unverify';
@@ -65,6 +74,10 @@ export {
 
 export const SYSTEM_PROGRAM_ID = publicKey('11111111111111111111111111111111');
 
+export const SYSVAR_INSTRUCTIONS_ID = publicKey(
+  'Sysvar1nstructions1111111111111111111111111'
+);
+
 export function getAssetGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
   const programId = context.programs.getPublicKey(
     'asset',
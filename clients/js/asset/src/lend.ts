@@ -0,0 +1,24 @@
+import {
+  Context,
+  PublicKey,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  LendInstructionAccounts,
+  LendInstructionArgs,
+  lend as baseLend,
+} from './generated/instructions/lend';
+import { getTrailingAccounts } from './trailingAccounts';
+
+export function lend(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: LendInstructionAccounts &
+    LendInstructionArgs & {
+      ruleSet?: PublicKey | PublicKey[];
+    }
+): TransactionBuilder {
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return baseLend(context, input).addRemainingAccounts(
+    getTrailingAccounts(input.ruleSet)
+  );
+}
@@ -8,10 +8,14 @@ import {
   LockInstructionAccounts,
   lock as baseLock,
 } from './generated/instructions/lock';
+import { getTrailingAccounts } from './trailingAccounts';
 
 export function lock(
   context: Pick<Context, 'identity' | 'programs'>,
-  input: LockInstructionAccounts & { proxy?: PublicKey }
+  input: LockInstructionAccounts & {
+    proxy?: PublicKey;
+    ruleSet?: PublicKey | PublicKey[];
+  }
 ): TransactionBuilder {
   if (input.proxy) {
     const proxied = context.programs.clone();
@@ -29,5 +33,6 @@ export function lock(
     });
   }
 
-  return ix;
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
@@ -0,0 +1,27 @@
+import {
+  Context,
+  PublicKey,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  payRoyalties as basePayRoyalties,
+  PayRoyaltiesInstructionAccounts,
+  PayRoyaltiesInstructionArgs,
+} from './generated/instructions/payRoyalties';
+
+export function payRoyalties(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: PayRoyaltiesInstructionAccounts &
+    PayRoyaltiesInstructionArgs & {
+      creators: PublicKey[];
+    }
+): TransactionBuilder {
+  // creators must follow the same order as the creators extension
+  return basePayRoyalties(context, input).addRemainingAccounts(
+    input.creators.map((pubkey) => ({
+      pubkey,
+      isWritable: true,
+      isSigner: false,
+    }))
+  );
+}
@@ -0,0 +1,33 @@
+import {
+  Context,
+  PublicKey,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { ASSET_PROGRAM_ID } from './generated';
+import {
+  PrintInstructionAccounts,
+  print as basePrint,
+} from './generated/instructions/print';
+
+export function print(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: PrintInstructionAccounts & { proxy?: PublicKey }
+): TransactionBuilder {
+  if (input.proxy) {
+    const proxied = context.programs.clone();
+    proxied.bind('asset', input.proxy);
+    context = { ...context, programs: proxied };
+  }
+
+  let ix = basePrint(context, input);
+
+  if (input.proxy) {
+    ix = ix.addRemainingAccounts({
+      pubkey: ASSET_PROGRAM_ID,
+      isWritable: false,
+      isSigner: false,
+    });
+  }
+
+  return ix;
+}
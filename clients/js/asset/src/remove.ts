@@ -9,11 +9,15 @@ import {
   RemoveInstructionArgs,
   remove as baseRemove,
 } from './generated/instructions/remove';
+import { getTrailingAccounts } from './trailingAccounts';
 
 export function remove(
   context: Pick<Context, 'identity' | 'programs'>,
   input: RemoveInstructionAccounts &
-    RemoveInstructionArgs & { proxy?: PublicKey }
+    RemoveInstructionArgs & {
+      proxy?: PublicKey;
+      ruleSet?: PublicKey | PublicKey[];
+    }
 ): TransactionBuilder {
   if (input.proxy) {
     const proxied = context.programs.clone();
@@ -31,5 +35,6 @@ export function remove(
     });
   }
 
-  return ix;
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
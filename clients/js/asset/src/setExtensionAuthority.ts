@@ -0,0 +1,35 @@
+import {
+  Context,
+  PublicKey,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { ASSET_PROGRAM_ID } from './generated';
+import {
+  SetExtensionAuthorityInstructionAccounts,
+  SetExtensionAuthorityInstructionArgs,
+  setExtensionAuthority as baseSetExtensionAuthority,
+} from './generated/instructions/setExtensionAuthority';
+
+export function setExtensionAuthority(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetExtensionAuthorityInstructionAccounts &
+    SetExtensionAuthorityInstructionArgs & { proxy?: PublicKey }
+): TransactionBuilder {
+  if (input.proxy) {
+    const proxied = context.programs.clone();
+    proxied.bind('asset', input.proxy);
+    context = { ...context, programs: proxied };
+  }
+
+  let ix = baseSetExtensionAuthority(context, input);
+
+  if (input.proxy) {
+    ix = ix.addRemainingAccounts({
+      pubkey: ASSET_PROGRAM_ID,
+      isWritable: false,
+      isSigner: false,
+    });
+  }
+
+  return ix;
+}
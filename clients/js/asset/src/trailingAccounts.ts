@@ -0,0 +1,25 @@
+import { AccountMeta, PublicKey } from '@metaplex-foundation/umi';
+import { SYSVAR_INSTRUCTIONS_ID } from '.';
+
+/**
+ * Returns the optional accounts appended to the end of an instruction.
+ *
+ * The instructions sysvar is always included, so constraints on the calling
+ * program can be evaluated; rule set accounts are only included when they are
+ * referenced by `External` constraints.
+ */
+export function getTrailingAccounts(
+  ruleSet?: PublicKey | PublicKey[]
+): AccountMeta[] {
+  const accounts: AccountMeta[] = (
+    Array.isArray(ruleSet) ? ruleSet : ruleSet ? [ruleSet] : []
+  ).map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }));
+
+  accounts.push({
+    pubkey: SYSVAR_INSTRUCTIONS_ID,
+    isWritable: false,
+    isSigner: false,
+  });
+
+  return accounts;
+}
@@ -1,4 +1,5 @@
 import {
+  AccountMeta,
   Context,
   PublicKey,
   TransactionBuilder,
@@ -8,10 +9,23 @@ import {
   TransferInstructionAccounts,
 } from './generated/instructions/transfer';
 import { ASSET_PROGRAM_ID } from './generated';
+import { getTrailingAccounts } from './trailingAccounts';
 
+/**
+ * Transfers an asset to a new owner.
+ *
+ * The `group` account is required for every member of a group (not only when the
+ * group has royalties), since the transfer fails when the group is locked or frozen.
+ */
 export function transfer(
   context: Pick<Context, 'identity' | 'programs'>,
-  input: TransferInstructionAccounts & { proxy?: PublicKey }
+  input: TransferInstructionAccounts & {
+    proxy?: PublicKey;
+    parents?: PublicKey[];
+    transferHook?: PublicKey;
+    extraAccounts?: AccountMeta[];
+    ruleSet?: PublicKey | PublicKey[];
+  }
 ): TransactionBuilder {
   if (input.proxy) {
     const proxied = context.programs.clone();
@@ -29,5 +43,26 @@ export function transfer(
     });
   }
 
-  return ix;
+  // the parent chain of a nested asset, starting with the parent of the asset
+  if (input.parents) {
+    ix = ix.addRemainingAccounts(
+      input.parents.map((pubkey) => ({
+        pubkey,
+        isWritable: false,
+        isSigner: false,
+      }))
+    );
+  }
+
+  // the transfer hook program is followed by any extra accounts it requires
+  if (input.transferHook) {
+    ix = ix.addRemainingAccounts([
+      { pubkey: input.transferHook, isWritable: false, isSigner: false },
+      ...(input.extraAccounts ?? []),
+    ]);
+  }
+
+  // the rule set (if any) and instructions sysvar are the last accounts, used to
+  // evaluate constraints and find royalty payments in the same transaction
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
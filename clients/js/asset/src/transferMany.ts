@@ -0,0 +1,36 @@
+import {
+  Context,
+  PublicKey,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  transferMany as baseTransferMany,
+  TransferManyInstructionAccounts,
+} from './generated/instructions/transferMany';
+import { getTrailingAccounts } from './trailingAccounts';
+
+export function transferMany(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: TransferManyInstructionAccounts & {
+    assets: PublicKey[];
+    groups?: PublicKey[];
+    ruleSet?: PublicKey | PublicKey[];
+  }
+): TransactionBuilder {
+  // assets are followed by the (read-only) group accounts required to check
+  // royalties and locks; a group that is also transferred is only listed once,
+  // and the rule set (if any) and instructions sysvar are the last accounts
+  return baseTransferMany(context, input).addRemainingAccounts([
+    ...input.assets.map((pubkey) => ({
+      pubkey,
+      isWritable: true,
+      isSigner: false,
+    })),
+    ...(input.groups ?? []).map((pubkey) => ({
+      pubkey,
+      isWritable: false,
+      isSigner: false,
+    })),
+    ...getTrailingAccounts(input.ruleSet),
+  ]);
+}
@@ -8,10 +8,14 @@ import {
   UnlockInstructionAccounts,
   unlock as baseUnlock,
 } from './generated/instructions/unlock';
+import { getTrailingAccounts } from './trailingAccounts';
 
 export function unlock(
   context: Pick<Context, 'identity' | 'programs'>,
-  input: UnlockInstructionAccounts & { proxy?: PublicKey }
+  input: UnlockInstructionAccounts & {
+    proxy?: PublicKey;
+    ruleSet?: PublicKey | PublicKey[];
+  }
 ): TransactionBuilder {
   if (input.proxy) {
     const proxied = context.programs.clone();
@@ -29,5 +33,6 @@ export function unlock(
     });
   }
 
-  return ix;
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
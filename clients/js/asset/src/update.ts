@@ -12,6 +12,7 @@ import {
   UpdateInstructionArgs,
   update as baseUpdate,
 } from './generated/instructions/update';
+import { getTrailingAccounts } from './trailingAccounts';
 
 export function update(
   context: Pick<
@@ -21,7 +22,7 @@ export function update(
   input: UpdateInstructionAccounts &
     Omit<UpdateInstructionArgs, 'extension'> & {
       extension?: TypedExtension;
-    } & { proxy?: PublicKey }
+    } & { proxy?: PublicKey; ruleSet?: PublicKey | PublicKey[] }
 ): TransactionBuilder {
   let extension: OptionOrNullable<ExtensionInputArgs> = none();
 
@@ -55,5 +56,6 @@ export function update(
     });
   }
 
-  return ix;
+  // the rule set (if any) and instructions sysvar are the last accounts
+  return ix.addRemainingAccounts(getTrailingAccounts(input.ruleSet));
 }
@@ -1,12 +1,17 @@
-import { generateSigner } from '@metaplex-foundation/umi';
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
   Asset,
   DelegateRole,
+  ExtensionType,
   approve,
   create,
   delegateInput,
+  delegates,
   fetchAsset,
+  mint,
+  revoke,
+  transfer,
 } from '../src';
 import { createUmi } from './_setup';
 
@@ -160,3 +165,168 @@ test('it sets all roles when passing in the All variant', async (t) => {
     },
   });
 });
+
+test('it can set a delegate with an expiry', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset.
+  await create(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  // When we approve a delegate with an expiry.
+  const authority = generateSigner(umi).publicKey;
+  const expiry = BigInt(Math.floor(Date.now() / 1000) + 3600);
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: authority,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Transfer] }),
+    expiry,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then the delegate is set with its expiry.
+  const account = await fetchAsset(umi, asset.publicKey);
+  t.like(account, <Asset>{
+    delegate: {
+      address: authority,
+      roles: [DelegateRole.Transfer],
+    },
+    extensions: [
+      {
+        type: ExtensionType.DelegateExpiry,
+        values: [{ delegate: authority, timestamp: expiry }],
+      },
+    ],
+  });
+});
+
+test('it cannot use an expired delegate', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset.
+  await create(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  // And we approve a delegate that has already expired.
+  const delegate = generateSigner(umi);
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: delegate.publicKey,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Transfer] }),
+    expiry: 1,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When the delegate tries to transfer the asset.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: delegate,
+    recipient: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, {
+    message: /Invalid owner or transfer delegate/,
+  });
+});
+
+test('it keeps a separate expiry for each delegate', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a delegates extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [delegates()],
+  }).sendAndConfirm(umi);
+
+  // When we approve two delegates with different expiries.
+  const first = generateSigner(umi).publicKey;
+  const second = generateSigner(umi).publicKey;
+  const now = Math.floor(Date.now() / 1000);
+
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: first,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Transfer] }),
+    expiry: now + 3600,
+    payer: umi.identity,
+  })
+    .add(
+      approve(umi, {
+        asset: asset.publicKey,
+        owner,
+        delegate: second,
+        delegateInput: delegateInput('Some', { roles: [DelegateRole.Lock] }),
+        expiry: now + 7200,
+        payer: umi.identity,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then each delegate has its own expiry.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Delegates,
+        values: [{ address: second, roles: [DelegateRole.Lock] }],
+      },
+      {
+        type: ExtensionType.DelegateExpiry,
+        values: [
+          { delegate: first, timestamp: BigInt(now + 3600) },
+          { delegate: second, timestamp: BigInt(now + 7200) },
+        ],
+      },
+    ],
+  });
+
+  // When we revoke the first delegate.
+  await revoke(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    delegate: first,
+    delegateInput: delegateInput('All'),
+  }).sendAndConfirm(umi);
+
+  // Then its expiry is cleared.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Delegates,
+      },
+      {
+        type: ExtensionType.DelegateExpiry,
+        values: [
+          {
+            delegate: publicKey('11111111111111111111111111111111'),
+            timestamp: 0n,
+          },
+          { delegate: second, timestamp: BigInt(now + 7200) },
+        ],
+      },
+    ],
+  });
+});
@@ -0,0 +1,114 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  Discriminator,
+  ExtensionType,
+  Standard,
+  createMany,
+  fetchAsset,
+  grouping,
+  mint,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can create multiple assets', async (t) => {
+  // Given a Umi instance and three new signers.
+  const umi = await createUmi();
+  const assets = [
+    { asset: generateSigner(umi), owner: generateSigner(umi).publicKey },
+    { asset: generateSigner(umi), owner: generateSigner(umi).publicKey },
+    { asset: generateSigner(umi), owner: generateSigner(umi).publicKey },
+  ];
+
+  // When we create all assets at once.
+  await createMany(umi, {
+    payer: umi.identity,
+    assets: assets.map(({ asset, owner }, index) => ({
+      asset,
+      owner,
+      name: `Digital Asset #${index}`,
+    })),
+  }).sendAndConfirm(umi);
+
+  // Then all assets were created with the correct data.
+  for (const [index, { asset, owner }] of assets.entries()) {
+    t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+      discriminator: Discriminator.Asset,
+      standard: Standard.NonFungible,
+      owner,
+      authority: umi.identity.publicKey,
+      name: `Digital Asset #${index}`,
+    });
+  }
+});
+
+test('it can create multiple assets in a group', async (t) => {
+  // Given a Umi instance and a group asset.
+  const umi = await createUmi();
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // When we create two assets in the group.
+  const assets = [generateSigner(umi), generateSigner(umi)];
+  await createMany(umi, {
+    group: groupAsset.publicKey,
+    payer: umi.identity,
+    assets: assets.map((asset) => ({
+      asset,
+      owner: umi.identity.publicKey,
+      name: 'Digital Asset',
+    })),
+  }).sendAndConfirm(umi);
+
+  // Then the group is set on the assets.
+  for (const asset of assets) {
+    t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+      group: groupAsset.publicKey,
+    });
+  }
+
+  // And the group size has increased.
+  t.like(await fetchAsset(umi, groupAsset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Grouping,
+        size: 2n,
+        maxSize: 10n,
+      },
+    ],
+  });
+});
+
+test('it cannot create assets exceeding the group max size', async (t) => {
+  // Given a Umi instance and a group asset with max size 2.
+  const umi = await createUmi();
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(2)],
+  }).sendAndConfirm(umi);
+
+  // When we try to create three assets in the group.
+  const promise = createMany(umi, {
+    group: groupAsset.publicKey,
+    payer: umi.identity,
+    assets: [generateSigner(umi), generateSigner(umi), generateSigner(umi)].map(
+      (asset) => ({
+        asset,
+        owner: umi.identity.publicKey,
+        name: 'Digital Asset',
+      })
+    ),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Maximum group size reached/ });
+});
@@ -0,0 +1,237 @@
+import {
+  Context,
+  Instruction,
+  PublicKey,
+  Signer,
+  SolAmount,
+  TransactionBuilder,
+  createNoopSigner,
+  generateSigner,
+  publicKey,
+  sol,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { struct, u32, u64 } from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  Asset,
+  executeAsWallet,
+  fetchAsset,
+  findAssetWalletPda,
+  freezeGroup,
+  group,
+  grouping,
+  mint,
+  transfer,
+} from '../src';
+import { createUmi } from './_setup';
+
+const transferSolInstruction = (
+  source: PublicKey,
+  destination: PublicKey,
+  amount: SolAmount
+): Instruction => ({
+  programId: publicKey('11111111111111111111111111111111'),
+  keys: [
+    { pubkey: source, isSigner: true, isWritable: true },
+    { pubkey: destination, isSigner: false, isWritable: true },
+  ],
+  data: struct<{ discriminator: number; lamports: bigint }>([
+    ['discriminator', u32()],
+    ['lamports', u64()],
+  ]).serialize({ discriminator: 2, lamports: amount.basisPoints }),
+});
+
+const transferSol = (
+  context: Pick<Context, 'payer'>,
+  destination: PublicKey,
+  amount: SolAmount
+): TransactionBuilder =>
+  transactionBuilder([
+    {
+      instruction: transferSolInstruction(
+        context.payer.publicKey,
+        destination,
+        amount
+      ),
+      signers: [context.payer as Signer],
+      bytesCreatedOnChain: 0,
+    },
+  ]);
+
+test('it can transfer SOL from the wallet of an asset', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And an asset with a funded wallet.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  const [wallet] = findAssetWalletPda(umi, { asset: asset.publicKey });
+  await transferSol(umi, wallet, sol(1)).sendAndConfirm(umi);
+
+  // When the owner transfers SOL from the wallet.
+  const recipient = generateSigner(umi).publicKey;
+  await executeAsWallet(umi, {
+    asset: asset.publicKey,
+    owner,
+    instruction: transferSolInstruction(wallet, recipient, sol(0.1)),
+  }).sendAndConfirm(umi);
+
+  // Then the SOL was transferred.
+  t.deepEqual(await umi.rpc.getBalance(wallet), sol(0.9));
+  t.deepEqual(await umi.rpc.getBalance(recipient), sol(0.1));
+});
+
+test('it can transfer an asset held by the wallet of an asset', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And an asset.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Character',
+  }).sendAndConfirm(umi);
+
+  // And an item held by the wallet of the asset.
+  const [wallet] = findAssetWalletPda(umi, { asset: asset.publicKey });
+  const item = generateSigner(umi);
+  await mint(umi, {
+    asset: item,
+    owner: wallet,
+    payer: umi.identity,
+    name: 'Equipment',
+  }).sendAndConfirm(umi);
+
+  // When the owner transfers the item out of the wallet.
+  const recipient = generateSigner(umi).publicKey;
+  const [instruction] = transfer(umi, {
+    asset: item.publicKey,
+    signer: createNoopSigner(wallet),
+    recipient,
+  }).getInstructions();
+
+  await executeAsWallet(umi, {
+    asset: asset.publicKey,
+    owner,
+    instruction,
+  }).sendAndConfirm(umi);
+
+  // Then the item was transferred.
+  t.like(await fetchAsset(umi, item.publicKey), <Asset>{
+    owner: recipient,
+  });
+});
+
+test('it cannot use the wallet of an asset as a non-owner', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And an asset with a funded wallet.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  const [wallet] = findAssetWalletPda(umi, { asset: asset.publicKey });
+  await transferSol(umi, wallet, sol(1)).sendAndConfirm(umi);
+
+  // When a non-owner tries to transfer SOL from the wallet.
+  const promise = executeAsWallet(umi, {
+    asset: asset.publicKey,
+    owner: generateSigner(umi),
+    instruction: transferSolInstruction(
+      wallet,
+      generateSigner(umi).publicKey,
+      sol(0.1)
+    ),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid asset owner/ });
+
+  t.deepEqual(await umi.rpc.getBalance(wallet), sol(1));
+});
+
+test('it cannot use the wallet of a member of a frozen group', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And a group asset.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And an asset in the group with a funded wallet.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  await group(umi, {
+    group: groupAsset.publicKey,
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  const [wallet] = findAssetWalletPda(umi, { asset: asset.publicKey });
+  await transferSol(umi, wallet, sol(1)).sendAndConfirm(umi);
+
+  // And the group authority freezes the group.
+  await freezeGroup(umi, {
+    group: groupAsset.publicKey,
+    authority: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When the owner tries to transfer SOL from the wallet.
+  let promise = executeAsWallet(umi, {
+    asset: asset.publicKey,
+    owner,
+    group: groupAsset.publicKey,
+    instruction: transferSolInstruction(
+      wallet,
+      generateSigner(umi).publicKey,
+      sol(0.1)
+    ),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // When the owner tries again without the group account.
+  promise = executeAsWallet(umi, {
+    asset: asset.publicKey,
+    owner,
+    instruction: transferSolInstruction(
+      wallet,
+      generateSigner(umi).publicKey,
+      sol(0.1)
+    ),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /insufficient account keys/ });
+
+  t.deepEqual(await umi.rpc.getBalance(wallet), sol(1));
+});
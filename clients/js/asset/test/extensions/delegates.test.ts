@@ -0,0 +1,316 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  DelegateRole,
+  ExtensionType,
+  State,
+  approve,
+  delegateInput,
+  delegates,
+  fetchAsset,
+  lock,
+  mint,
+  revoke,
+  transfer,
+} from '../../src';
+import { createUmi } from '../_setup';
+
+test('it can approve multiple delegates', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a delegates extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [delegates(1)],
+  }).sendAndConfirm(umi);
+
+  // When we approve three delegates with different roles.
+  const staking = generateSigner(umi).publicKey;
+  const marketplace = generateSigner(umi).publicKey;
+  const burner = generateSigner(umi).publicKey;
+
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: staking,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Lock] }),
+  })
+    .add(
+      approve(umi, {
+        asset: asset.publicKey,
+        owner,
+        delegate: marketplace,
+        delegateInput: delegateInput('Some', {
+          roles: [DelegateRole.Transfer],
+        }),
+      })
+    )
+    .add(
+      approve(umi, {
+        asset: asset.publicKey,
+        owner,
+        delegate: burner,
+        delegateInput: delegateInput('Some', { roles: [DelegateRole.Burn] }),
+        payer: umi.identity,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the first delegate is set on the asset and the others are on the list.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    delegate: {
+      address: staking,
+      roles: [DelegateRole.Lock],
+    },
+    extensions: [
+      {
+        type: ExtensionType.Delegates,
+        values: [
+          { address: marketplace, roles: [DelegateRole.Transfer] },
+          { address: burner, roles: [DelegateRole.Burn] },
+        ],
+      },
+    ],
+  });
+});
+
+test('each delegate can use its own role', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a delegates extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [delegates(1)],
+  }).sendAndConfirm(umi);
+
+  // And we approve a lock and a transfer delegate.
+  const staking = generateSigner(umi);
+  const marketplace = generateSigner(umi);
+
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: staking.publicKey,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Lock] }),
+  })
+    .add(
+      approve(umi, {
+        asset: asset.publicKey,
+        owner,
+        delegate: marketplace.publicKey,
+        delegateInput: delegateInput('Some', {
+          roles: [DelegateRole.Transfer],
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the transfer delegate tries to lock the asset.
+  const promise = lock(umi, {
+    asset: asset.publicKey,
+    signer: marketplace,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Delegate role not active/ });
+
+  // When the lock delegate locks the asset.
+  await lock(umi, {
+    asset: asset.publicKey,
+    signer: staking,
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    state: State.Locked,
+  });
+
+  // And the transfer delegate tries to transfer the locked asset.
+  const recipient = generateSigner(umi).publicKey;
+  const transferPromise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: marketplace,
+    recipient,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(transferPromise, { message: /Asset is locked/ });
+});
+
+test('a transfer delegate on the list can transfer an asset', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a delegates extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [delegates(1)],
+  }).sendAndConfirm(umi);
+
+  // And we approve a lock and a transfer delegate.
+  const staking = generateSigner(umi).publicKey;
+  const marketplace = generateSigner(umi);
+
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: staking,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Lock] }),
+  })
+    .add(
+      approve(umi, {
+        asset: asset.publicKey,
+        owner,
+        delegate: marketplace.publicKey,
+        delegateInput: delegateInput('Some', {
+          roles: [DelegateRole.Transfer],
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the transfer delegate transfers the asset.
+  const recipient = generateSigner(umi).publicKey;
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: marketplace,
+    recipient,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner and all delegates are cleared.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient,
+    delegate: null,
+    extensions: [
+      {
+        type: ExtensionType.Delegates,
+        values: [{ address: null, roles: [] }],
+      },
+    ],
+  });
+});
+
+test('an owner can revoke a delegate from the list', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a delegates extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [delegates(1)],
+  }).sendAndConfirm(umi);
+
+  // And we approve two delegates.
+  const staking = generateSigner(umi).publicKey;
+  const marketplace = generateSigner(umi).publicKey;
+
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: staking,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Lock] }),
+  })
+    .add(
+      approve(umi, {
+        asset: asset.publicKey,
+        owner,
+        delegate: marketplace,
+        delegateInput: delegateInput('Some', {
+          roles: [DelegateRole.Transfer],
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the owner revokes the delegate on the list.
+  await revoke(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    delegate: marketplace,
+    delegateInput: delegateInput('All'),
+  }).sendAndConfirm(umi);
+
+  // Then the delegate is removed from the list.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    delegate: {
+      address: staking,
+      roles: [DelegateRole.Lock],
+    },
+    extensions: [
+      {
+        type: ExtensionType.Delegates,
+        values: [{ address: null, roles: [] }],
+      },
+    ],
+  });
+});
+
+test('it creates the delegates list when the asset delegate is in use', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset without a delegates extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  // And we approve a delegate.
+  const staking = generateSigner(umi).publicKey;
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: staking,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Lock] }),
+  }).sendAndConfirm(umi);
+
+  // When we approve a second delegate with a payer.
+  const marketplace = generateSigner(umi).publicKey;
+  await approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: marketplace,
+    delegateInput: delegateInput('Some', { roles: [DelegateRole.Transfer] }),
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then the first delegate is kept and the second is added to a new list.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    delegate: {
+      address: staking,
+      roles: [DelegateRole.Lock],
+    },
+    extensions: [
+      {
+        type: ExtensionType.Delegates,
+        values: [{ address: marketplace, roles: [DelegateRole.Transfer] }],
+      },
+    ],
+  });
+});
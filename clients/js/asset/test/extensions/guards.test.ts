@@ -0,0 +1,203 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Action,
+  Asset,
+  ExtensionType,
+  State,
+  burn,
+  createRuleSet,
+  external,
+  fetchAsset,
+  getConstraintSerializer,
+  guards,
+  lock,
+  mint,
+  pubkeyMatch,
+  transfer,
+} from '../../src';
+import { createUmi } from '../_setup';
+
+test('it can create an asset with guards', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const authority = generateSigner(umi).publicKey;
+
+  // When we create a new asset with a guards extension.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      guards([
+        {
+          action: Action.Burn,
+          constraint: pubkeyMatch('Authority', [authority]),
+        },
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then the guards are set on the asset.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Guards,
+        values: [
+          {
+            action: Action.Burn,
+            constraint: {
+              type: 'PubkeyMatch',
+              account: 'Authority',
+              pubkeys: [authority],
+            },
+          },
+        ],
+      },
+    ],
+  });
+});
+
+test('it cannot burn an asset that does not satisfy its burn guard', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And an asset that can only be burned by a different authority.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      guards([
+        {
+          action: Action.Burn,
+          constraint: pubkeyMatch('Authority', [
+            generateSigner(umi).publicKey,
+          ]),
+        },
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When the owner tries to burn the asset.
+  const promise = burn(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // And the owner can still lock the asset, which is not guarded.
+  await lock(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+  }).sendAndConfirm(umi);
+
+  t.truthy(await fetchAsset(umi, asset.publicKey));
+});
+
+test('it evaluates the transfer guard on wallet-to-wallet transfers', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+  const allowed = generateSigner(umi).publicKey;
+
+  // And an asset that can only be transferred to an allowed wallet.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      guards([
+        {
+          action: Action.Transfer,
+          constraint: pubkeyMatch('Recipient', [allowed]),
+        },
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset to a different wallet.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // When we transfer the asset to the allowed wallet.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: allowed,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: allowed,
+  });
+});
+
+test('it evaluates a lock guard referencing a rule set', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And a rule set that only allows the owner as the authority.
+  const ruleSet = generateSigner(umi);
+  await createRuleSet(umi, {
+    ruleSet,
+    ruleSetInput: {
+      constraint: getConstraintSerializer().serialize(
+        pubkeyMatch('Authority', [owner.publicKey])
+      ),
+    },
+  }).sendAndConfirm(umi);
+
+  // And an asset with a lock guard referencing the rule set.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      guards([
+        {
+          action: Action.Lock,
+          constraint: external(ruleSet.publicKey),
+        },
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to lock the asset without the rule set account.
+  const promise = lock(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /insufficient account keys/ });
+
+  // When we lock the asset with the rule set account.
+  await lock(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    ruleSet: ruleSet.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset is locked.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    state: State.Locked,
+  });
+});
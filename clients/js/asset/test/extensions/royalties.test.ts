@@ -1,6 +1,7 @@
 import { generateSigner, publicKey } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
+  ASSET_PROGRAM_ID,
   Asset,
   Constraint,
   Discriminator,
@@ -8,12 +9,20 @@ import {
   Standard,
   State,
   and,
+  atLeast,
+  attributes,
+  dataMatch,
   fetchAsset,
   mint,
   not,
   or,
   ownedBy,
+  properties,
   pubkeyMatch,
+  timeWindow,
+  transfer,
+  valueMatch,
+  valueMatchAccount,
 } from '../../src';
 import { royalties } from '../../src/extensions/royalties';
 import { createUmi } from '../_setup';
@@ -319,3 +328,334 @@ test('it can mint a new asset with a nested royalties extension', async (t) => {
     ],
   });
 });
+
+test('it can mint a new asset with a royalties extension with a TimeWindow constraint', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  const basisPoints = BigInt(500);
+
+  // When we create a new asset that can only be transferred after a reveal date.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [royalties(basisPoints, timeWindow(1_700_000_000))],
+  }).sendAndConfirm(umi);
+
+  // Then an asset was created with the correct data.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Royalties,
+        basisPoints,
+        constraint: {
+          type: 'TimeWindow',
+          start: 1_700_000_000n,
+          end: 0n,
+        },
+      },
+    ],
+  });
+});
+
+test('it can restrict transfers to a caller program with a royalties extension', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const marketplace = generateSigner(umi).publicKey;
+
+  // And a recipient account that is not a wallet.
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset that can only be transferred through the marketplace program.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [royalties(500n, pubkeyMatch('Caller', [marketplace]))],
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset directly.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // And an asset that can be transferred directly.
+  const direct = generateSigner(umi);
+  await mint(umi, {
+    asset: direct,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, pubkeyMatch('Caller', [ASSET_PROGRAM_ID, marketplace])),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset directly.
+  await transfer(umi, {
+    asset: direct.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, direct.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+});
+
+test('it can transfer an asset with a royalties extension with an AtLeast constraint', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And a recipient account that is not a wallet.
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset with a constraint where two of the three conditions must pass.
+  const atLeastConstraint = atLeast(2, [
+    pubkeyMatch('Recipient', [generateSigner(umi).publicKey]),
+    ownedBy('Recipient', [ASSET_PROGRAM_ID]),
+    pubkeyMatch('Caller', [ASSET_PROGRAM_ID]),
+  ]);
+
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [royalties(500n, atLeastConstraint)],
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Royalties,
+        basisPoints: 500n,
+        constraint: atLeastConstraint,
+      },
+    ],
+  });
+
+  // When we transfer the asset.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+});
+
+test('it can gate transfers on the recipient data with a DataMatch constraint', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a recipient account that is an asset.
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset that can only be transferred to accounts with a different discriminator.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(
+        500n,
+        dataMatch('Recipient', 0, new Uint8Array([Discriminator.Uninitialized]))
+      ),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // And an asset that can only be transferred to asset accounts.
+  const matching = generateSigner(umi);
+  await mint(umi, {
+    asset: matching,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(
+        500n,
+        dataMatch('Recipient', 0, new Uint8Array([Discriminator.Asset]))
+      ),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset.
+  await transfer(umi, {
+    asset: matching.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, matching.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+});
+
+test('it can gate transfers on a property value with a ValueMatch constraint', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a recipient account that is an asset.
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And a staked asset that can only be transferred when not staked.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      properties([{ name: 'staked', value: true }]),
+      royalties(500n, valueMatch('Properties', 'staked', false)),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // And an asset that is not staked.
+  const unstaked = generateSigner(umi);
+  await mint(umi, {
+    asset: unstaked,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      properties([{ name: 'staked', value: false }]),
+      royalties(500n, valueMatch('Properties', 'staked', false)),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset.
+  await transfer(umi, {
+    asset: unstaked.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, unstaked.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+});
+
+test('it can restrict the recipient to a pubkey stored in an attribute', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And two recipient accounts that are assets.
+  const beneficiary = generateSigner(umi);
+  await mint(umi, {
+    asset: beneficiary,
+    payer: umi.identity,
+    name: 'Beneficiary',
+  }).sendAndConfirm(umi);
+
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset that can only be transferred to its beneficiary.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      attributes([{ name: 'beneficiary', value: beneficiary.publicKey }]),
+      royalties(
+        500n,
+        valueMatchAccount('Attributes', 'beneficiary', 'Recipient')
+      ),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset to a different recipient.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // When we transfer the asset to the beneficiary.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: beneficiary.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: beneficiary.publicKey,
+  });
+});
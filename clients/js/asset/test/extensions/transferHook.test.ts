@@ -0,0 +1,110 @@
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  ExtensionType,
+  fetchAsset,
+  mint,
+  transfer,
+  transferHook,
+} from '../../src';
+import { createUmi } from '../_setup';
+
+// Program that does not implement the transfer hook interface.
+const TOKEN_METADATA_PROGRAM_ID = publicKey(
+  'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+);
+
+test('it can create an asset with a transfer hook', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const program = generateSigner(umi).publicKey;
+
+  // When we create a new asset with a transfer hook extension.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [transferHook(program)],
+  }).sendAndConfirm(umi);
+
+  // Then the transfer hook is set on the asset.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.TransferHook,
+        program,
+      },
+    ],
+  });
+});
+
+test('it cannot transfer an asset without the transfer hook program', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a transfer hook extension.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [transferHook(TOKEN_METADATA_PROGRAM_ID)],
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset without the hook program.
+  const recipient = generateSigner(umi).publicKey;
+  let promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /insufficient account keys/ });
+
+  // When we try to transfer the asset with a different hook program.
+  promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient,
+    transferHook: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /incorrect program id/ });
+});
+
+test('a failing transfer hook reverts the transfer', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset with a transfer hook that always fails.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [transferHook(TOKEN_METADATA_PROGRAM_ID)],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+    transferHook: TOKEN_METADATA_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error and the owner is unchanged.
+  await t.throwsAsync(promise);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: owner.publicKey,
+  });
+});
@@ -0,0 +1,107 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  ExtensionType,
+  attributes,
+  creators,
+  fetchAsset,
+  mint,
+  update,
+  updateDelegate,
+} from '../../src';
+import { createUmi } from '../_setup';
+
+test('an update delegate can update a granted extension', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const delegate = generateSigner(umi);
+
+  // And we create a new asset with an update delegate for attributes.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      attributes([{ name: 'Level', value: '1' }]),
+      updateDelegate(delegate.publicKey, [
+        ExtensionType.Attributes,
+        ExtensionType.Properties,
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When the delegate updates the attributes.
+  await update(umi, {
+    asset: asset.publicKey,
+    authority: delegate,
+    extension: attributes([{ name: 'Level', value: '2' }]),
+  }).sendAndConfirm(umi);
+
+  // Then the attributes are updated.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Attributes,
+        values: [{ name: 'Level', value: '2' }],
+      },
+      {
+        type: ExtensionType.UpdateDelegate,
+        delegate: delegate.publicKey,
+        extensionTypes: [ExtensionType.Attributes, ExtensionType.Properties],
+      },
+    ],
+  });
+});
+
+test('an update delegate cannot update other extensions', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const delegate = generateSigner(umi);
+
+  // And we create a new asset with an update delegate for attributes.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      updateDelegate(delegate.publicKey, [ExtensionType.Attributes]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When the delegate tries to add a creators extension.
+  let promise = update(umi, {
+    asset: asset.publicKey,
+    authority: delegate,
+    extension: creators([{ address: delegate.publicKey, share: 100 }]),
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+
+  // When the delegate tries to update the name of the asset.
+  promise = update(umi, {
+    asset: asset.publicKey,
+    authority: delegate,
+    name: 'Delegated Asset',
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+
+  // When the delegate tries to grant itself more extension types.
+  promise = update(umi, {
+    asset: asset.publicKey,
+    authority: delegate,
+    extension: updateDelegate(delegate.publicKey, [
+      ExtensionType.Attributes,
+      ExtensionType.Royalties,
+    ]),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+});
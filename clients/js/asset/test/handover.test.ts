@@ -1,6 +1,15 @@
 import { generateSigner } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { Asset, create, fetchAsset, handover } from '../src';
+import {
+  Asset,
+  ExtensionType,
+  acceptHandover,
+  cancelHandover,
+  create,
+  fetchAsset,
+  handover,
+  proposeHandover,
+} from '../src';
 import { createUmi } from './_setup';
 
 test('it can handover an asset to a new authority', async (t) => {
@@ -67,3 +76,124 @@ test('it cannot handover an asset with the wrong authority', async (t) => {
   // Then we expect an error.
   await t.throwsAsync(promise, { message: /Invalid authority/ });
 });
+
+test('it can handover an asset in two steps', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  // And we create a new asset.
+  await create(umi, {
+    asset,
+    owner: umi.identity.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    authority,
+  }).sendAndConfirm(umi);
+
+  // When we propose a new authority.
+  const newAuthority = generateSigner(umi);
+  await proposeHandover(umi, {
+    asset: asset.publicKey,
+    authority,
+    newAuthority: newAuthority.publicKey,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then the authority is pending.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    authority: authority.publicKey,
+    extensions: [
+      {
+        type: ExtensionType.PendingAuthority,
+        authority: newAuthority.publicKey,
+      },
+    ],
+  });
+
+  // And when the new authority accepts the handover.
+  await acceptHandover(umi, {
+    asset: asset.publicKey,
+    newAuthority,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has the new authority.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    authority: newAuthority.publicKey,
+  });
+});
+
+test('it cannot accept a handover as a different authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  // And an asset with a pending handover.
+  await create(umi, {
+    asset,
+    owner: umi.identity.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    authority,
+  }).sendAndConfirm(umi);
+
+  await proposeHandover(umi, {
+    asset: asset.publicKey,
+    authority,
+    newAuthority: generateSigner(umi).publicKey,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When a different signer tries to accept the handover.
+  const promise = acceptHandover(umi, {
+    asset: asset.publicKey,
+    newAuthority: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+});
+
+test('it cannot accept a cancelled handover', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  // And an asset with a pending handover.
+  await create(umi, {
+    asset,
+    owner: umi.identity.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    authority,
+  }).sendAndConfirm(umi);
+
+  const newAuthority = generateSigner(umi);
+  await proposeHandover(umi, {
+    asset: asset.publicKey,
+    authority,
+    newAuthority: newAuthority.publicKey,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When we cancel the handover.
+  await cancelHandover(umi, {
+    asset: asset.publicKey,
+    authority,
+  }).sendAndConfirm(umi);
+
+  // Then the new authority cannot accept it.
+  const promise = acceptHandover(umi, {
+    asset: asset.publicKey,
+    newAuthority,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Extension not found/ });
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    authority: authority.publicKey,
+  });
+});
@@ -0,0 +1,236 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  ExtensionType,
+  State,
+  creators,
+  fetchAsset,
+  lend,
+  mint,
+  pubkeyMatch,
+  reclaim,
+  royalties,
+  transfer,
+  transferHook,
+  unlock,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can lend an asset to a renter', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+  const renter = generateSigner(umi);
+
+  // And we create a new asset.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  // When we lend the asset to the renter for an hour.
+  const expiry = BigInt(Math.floor(Date.now() / 1000) + 3600);
+  await lend(umi, {
+    asset: asset.publicKey,
+    owner,
+    renter: renter.publicKey,
+    payer: umi.identity,
+    expiry,
+  }).sendAndConfirm(umi);
+
+  // Then the renter holds the locked asset.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: renter.publicKey,
+    state: State.Locked,
+    extensions: [
+      {
+        type: ExtensionType.Rental,
+        lender: owner.publicKey,
+        expiry,
+      },
+    ],
+  });
+
+  // And the renter cannot transfer the asset.
+  let promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: renter,
+    recipient: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // And the renter cannot unlock the asset.
+  promise = unlock(umi, {
+    asset: asset.publicKey,
+    signer: renter,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // And the asset cannot be reclaimed before the rental expires.
+  promise = reclaim(umi, {
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Rental not expired/ });
+});
+
+test('it can reclaim an asset after the rental expires', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+  const renter = generateSigner(umi);
+
+  // And we create a new asset.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  // And we lend the asset to the renter for a couple of seconds.
+  await lend(umi, {
+    asset: asset.publicKey,
+    owner,
+    renter: renter.publicKey,
+    payer: umi.identity,
+    expiry: BigInt(Math.floor(Date.now() / 1000) + 2),
+  }).sendAndConfirm(umi);
+
+  // When anyone reclaims the asset after the rental expires.
+  await new Promise((resolve) => {
+    setTimeout(resolve, 5000);
+  });
+
+  await reclaim(umi, {
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset is returned to the lender and unlocked.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: owner.publicKey,
+    state: State.Unlocked,
+  });
+
+  // And the lender can transfer the asset.
+  const recipient = generateSigner(umi).publicKey;
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient,
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient,
+  });
+});
+
+test('it cannot lend an asset to a renter rejected by its royalties', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And a renter account that is an asset (not a wallet).
+  const renter = generateSigner(umi);
+  await mint(umi, {
+    asset: renter,
+    payer: umi.identity,
+    name: 'Renter',
+  }).sendAndConfirm(umi);
+
+  // And an asset whose royalties constraint does not allow the renter.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(
+        500n,
+        pubkeyMatch('Recipient', [generateSigner(umi).publicKey])
+      ),
+      creators([{ address: umi.identity.publicKey, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to lend the asset to the renter.
+  const promise = lend(umi, {
+    asset: asset.publicKey,
+    owner,
+    renter: renter.publicKey,
+    payer: umi.identity,
+    expiry: BigInt(Math.floor(Date.now() / 1000) + 3600),
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // And the asset was not lent.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: owner.publicKey,
+    state: State.Unlocked,
+  });
+});
+
+test('it cannot lend an asset with a transfer hook', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And an asset with a transfer hook.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [transferHook(generateSigner(umi).publicKey)],
+  }).sendAndConfirm(umi);
+
+  // When we try to lend the asset.
+  const promise = lend(umi, {
+    asset: asset.publicKey,
+    owner,
+    renter: generateSigner(umi).publicKey,
+    payer: umi.identity,
+    expiry: BigInt(Math.floor(Date.now() / 1000) + 3600),
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise, { message: /Extension data invalid/ });
+});
+
+test('it cannot lend an asset to itself', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a new asset.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  // When we try to lend the asset to itself.
+  const promise = lend(umi, {
+    asset: asset.publicKey,
+    owner,
+    renter: asset.publicKey,
+    payer: umi.identity,
+    expiry: BigInt(Math.floor(Date.now() / 1000) + 3600),
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise, { message: /invalid program argument/ });
+});
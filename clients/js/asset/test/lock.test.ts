@@ -5,10 +5,15 @@ import {
   DelegateRole,
   State,
   approve,
+  burn,
   create,
   delegateInput,
   fetchAsset,
+  freezeGroup,
+  group,
+  grouping,
   lock,
+  mint,
   transfer,
 } from '../src';
 import { createUmi } from './_setup';
@@ -190,3 +195,172 @@ test('it cannot lock as an owner if delegate set', async (t) => {
   // Then we get an error.
   await t.throwsAsync(promise, { message: /Invalid delegate/ });
 });
+
+test('it can freeze a group as the group authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const groupOwner = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And we create a group asset owned by a different owner than its authority.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    owner: groupOwner.publicKey,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And an asset in the group.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  await group(umi, {
+    group: groupAsset.publicKey,
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When the authority tries to lock the group asset.
+  let promise = lock(umi, {
+    asset: groupAsset.publicKey,
+    signer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error, since the authority is not the owner.
+  await t.throwsAsync(promise);
+
+  // When the authority freezes the group.
+  await freezeGroup(umi, {
+    group: groupAsset.publicKey,
+    authority: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then the group is frozen without changing its state.
+  t.like(await fetchAsset(umi, groupAsset.publicKey), <Asset>{
+    state: State.Unlocked,
+  });
+
+  // And its members cannot be transferred.
+  promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+});
+
+test('it cannot freeze a group as a non-authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const groupOwner = generateSigner(umi);
+
+  // And a group asset owned by a different owner than its authority.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    owner: groupOwner.publicKey,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // When the owner tries to freeze the group.
+  const promise = freezeGroup(umi, {
+    group: groupAsset.publicKey,
+    authority: groupOwner,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+});
+
+test('it cannot transfer, burn or approve a member of a locked group', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And we create a group asset.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And an asset in the group.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  await group(umi, {
+    group: groupAsset.publicKey,
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // And we lock the group.
+  await lock(umi, {
+    asset: groupAsset.publicKey,
+    signer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset.
+  let promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // When we try to transfer the asset without the group account.
+  promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise);
+
+  // When we try to burn the asset.
+  promise = burn(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // When we try to approve a delegate.
+  promise = approve(umi, {
+    asset: asset.publicKey,
+    owner,
+    delegate: generateSigner(umi).publicKey,
+    delegateInput: delegateInput('All'),
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we get an error.
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // And the asset was not transferred.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: owner.publicKey,
+  });
+});
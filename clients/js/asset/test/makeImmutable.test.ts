@@ -0,0 +1,98 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  ExtensionType,
+  attributes,
+  fetchAsset,
+  links,
+  makeImmutable,
+  mint,
+  remove,
+  update,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can make an extension immutable', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+
+  // And we create a new asset with links and attributes extensions.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      links([{ name: 'website', uri: 'https://nifty-oss.org' }]),
+      attributes([{ name: 'Level', value: '1' }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we make the links extension immutable.
+  await makeImmutable(umi, {
+    asset: asset.publicKey,
+    extensionType: ExtensionType.Links,
+  }).sendAndConfirm(umi);
+
+  // Then we cannot update the links extension.
+  let promise = update(umi, {
+    asset: asset.publicKey,
+    extension: links([{ name: 'website', uri: 'https://other.xyz' }]),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Extension is immutable/ });
+
+  // And we cannot remove the links extension.
+  promise = remove(umi, {
+    asset: asset.publicKey,
+    extensionType: ExtensionType.Links,
+    recipient: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Extension is immutable/ });
+
+  // And we can still update the attributes extension.
+  await update(umi, {
+    asset: asset.publicKey,
+    extension: attributes([{ name: 'Level', value: '2' }]),
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    mutable: true,
+    extensions: [
+      {
+        type: ExtensionType.Links,
+        values: [{ name: 'website', uri: 'https://nifty-oss.org' }],
+      },
+      {
+        type: ExtensionType.Attributes,
+        values: [{ name: 'Level', value: '2' }],
+      },
+    ],
+  });
+});
+
+test('it cannot make an extension immutable with the wrong authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+
+  // And we create a new asset with a links extension.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [links([{ name: 'website', uri: 'https://nifty-oss.org' }])],
+  }).sendAndConfirm(umi);
+
+  // When we try to make the links extension immutable with a different authority.
+  const promise = makeImmutable(umi, {
+    asset: asset.publicKey,
+    authority: generateSigner(umi),
+    extensionType: ExtensionType.Links,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+});
@@ -0,0 +1,179 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import { Asset, burn, fetchAsset, mint, transfer } from '../src';
+import { createUmi } from './_setup';
+
+test('it can transfer a nested asset as the owner of the parent', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a parent asset owned by the owner.
+  const parent = generateSigner(umi);
+  await mint(umi, {
+    asset: parent,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Bundle',
+  }).sendAndConfirm(umi);
+
+  // And a child asset owned by the parent asset.
+  const child = generateSigner(umi);
+  await mint(umi, {
+    asset: child,
+    owner: parent.publicKey,
+    payer: umi.identity,
+    name: 'Equipment',
+  }).sendAndConfirm(umi);
+
+  // When we transfer the parent asset.
+  const recipient = generateSigner(umi);
+  await transfer(umi, {
+    asset: parent.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the child asset is still owned by the parent.
+  t.like(await fetchAsset(umi, child.publicKey), <Asset>{
+    owner: parent.publicKey,
+  });
+
+  // And the new owner of the parent can transfer the child asset.
+  await transfer(umi, {
+    asset: child.publicKey,
+    signer: recipient,
+    recipient: owner.publicKey,
+    parents: [parent.publicKey],
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, child.publicKey), <Asset>{
+    owner: owner.publicKey,
+  });
+});
+
+test('it cannot transfer a nested asset with the parent keypair', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a parent asset owned by the owner.
+  const parent = generateSigner(umi);
+  await mint(umi, {
+    asset: parent,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Bundle',
+  }).sendAndConfirm(umi);
+
+  // And a child asset owned by the parent asset.
+  const child = generateSigner(umi);
+  await mint(umi, {
+    asset: child,
+    owner: parent.publicKey,
+    payer: umi.identity,
+    name: 'Equipment',
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the child asset signing with the parent keypair.
+  let promise = transfer(umi, {
+    asset: child.publicKey,
+    signer: parent,
+    recipient: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, {
+    message: /Invalid owner or transfer delegate/,
+  });
+
+  // And the owner of the parent cannot transfer the child asset without the
+  // parent chain.
+  promise = transfer(umi, {
+    asset: child.publicKey,
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, {
+    message: /Invalid owner or transfer delegate/,
+  });
+});
+
+test('it cannot nest an asset into one of its children', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a parent asset with a child asset.
+  const parent = generateSigner(umi);
+  await mint(umi, {
+    asset: parent,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Bundle',
+  }).sendAndConfirm(umi);
+
+  const child = generateSigner(umi);
+  await mint(umi, {
+    asset: child,
+    owner: parent.publicKey,
+    payer: umi.identity,
+    name: 'Equipment',
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the parent asset to its child.
+  const promise = transfer(umi, {
+    asset: parent.publicKey,
+    signer: owner,
+    recipient: child.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /invalid program argument/ });
+
+  t.like(await fetchAsset(umi, parent.publicKey), <Asset>{
+    owner: owner.publicKey,
+  });
+});
+
+test('it can burn a nested asset as the owner of the top-level parent', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a chain of nested assets: grandparent > parent > child.
+  const grandparent = generateSigner(umi);
+  await mint(umi, {
+    asset: grandparent,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Inventory',
+  }).sendAndConfirm(umi);
+
+  const parent = generateSigner(umi);
+  await mint(umi, {
+    asset: parent,
+    owner: grandparent.publicKey,
+    payer: umi.identity,
+    name: 'Bundle',
+  }).sendAndConfirm(umi);
+
+  const child = generateSigner(umi);
+  await mint(umi, {
+    asset: child,
+    owner: parent.publicKey,
+    payer: umi.identity,
+    name: 'Equipment',
+  }).sendAndConfirm(umi);
+
+  // When the owner of the grandparent burns the child asset.
+  await burn(umi, {
+    asset: child.publicKey,
+    signer: owner,
+    parents: [parent.publicKey, grandparent.publicKey],
+  }).sendAndConfirm(umi);
+
+  // Then the child asset is closed.
+  t.false(await umi.rpc.accountExists(child.publicKey));
+});
@@ -0,0 +1,589 @@
+import {
+  PublicKey,
+  Signer,
+  SolAmount,
+  TransactionBuilder,
+  generateSigner,
+  lamports,
+  publicKey,
+  sol,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { struct, u32, u64 } from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  Asset,
+  RoyaltiesMode,
+  creators,
+  empty,
+  fetchAsset,
+  group,
+  grouping,
+  mint,
+  payRoyalties,
+  primarySale,
+  pubkeyMatch,
+  royalties,
+  transfer,
+  transferMany,
+} from '../src';
+import { createUmi } from './_setup';
+
+const transferSol = (
+  source: Signer,
+  destination: PublicKey,
+  amount: SolAmount
+): TransactionBuilder =>
+  transactionBuilder([
+    {
+      instruction: {
+        programId: publicKey('11111111111111111111111111111111'),
+        keys: [
+          { pubkey: source.publicKey, isSigner: true, isWritable: true },
+          { pubkey: destination, isSigner: false, isWritable: true },
+        ],
+        data: struct<{ discriminator: number; lamports: bigint }>([
+          ['discriminator', u32()],
+          ['lamports', u64()],
+        ]).serialize({ discriminator: 2, lamports: amount.basisPoints }),
+      },
+      signers: [source],
+      bytesCreatedOnChain: 0,
+    },
+  ]);
+
+test('it can pay royalties to the creators of an asset', async (t) => {
+  // Given a Umi instance and two creators.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const first = generateSigner(umi).publicKey;
+  const second = generateSigner(umi).publicKey;
+
+  // And an asset with 5% royalties split between the creators.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n),
+      creators([
+        { address: first, share: 70 },
+        { address: second, share: 30 },
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we pay the royalties of a 1 SOL sale.
+  await payRoyalties(umi, {
+    asset: asset.publicKey,
+    price: sol(1).basisPoints,
+    creators: [first, second],
+  }).sendAndConfirm(umi);
+
+  // Then the creators received their share of the royalties.
+  t.deepEqual(await umi.rpc.getBalance(first), sol(0.035));
+  t.deepEqual(await umi.rpc.getBalance(second), sol(0.015));
+
+  // And the payment was recorded on the asset.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [{}, {}, { amount: sol(0.05).basisPoints }],
+  });
+});
+
+test('it pays the remainder of the royalties to the first creator', async (t) => {
+  // Given a Umi instance and three creators.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const first = generateSigner(umi).publicKey;
+  const second = generateSigner(umi).publicKey;
+  const third = generateSigner(umi).publicKey;
+
+  // And an asset with 1% royalties split between the creators.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(100n),
+      creators([
+        { address: first, share: 34 },
+        { address: second, share: 33 },
+        { address: third, share: 33 },
+      ]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we pay the royalties of a sale whose shares are not exact.
+  await payRoyalties(umi, {
+    asset: asset.publicKey,
+    price: lamports(10_000).basisPoints,
+    creators: [first, second, third],
+  }).sendAndConfirm(umi);
+
+  // Then the first creator received the remainder of the royalties.
+  t.deepEqual(await umi.rpc.getBalance(first), lamports(34));
+  t.deepEqual(await umi.rpc.getBalance(second), lamports(33));
+  t.deepEqual(await umi.rpc.getBalance(third), lamports(33));
+});
+
+test('it consumes the royalty payment on the next transfer', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+
+  // And a recipient account that is an asset (not a wallet).
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset requiring a royalty payment of at least 0.05 SOL.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, empty(), RoyaltiesMode.Payment, sol(0.05).basisPoints),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we pay the royalties of a 1 SOL sale in a separate transaction.
+  await payRoyalties(umi, {
+    asset: asset.publicKey,
+    price: sol(1).basisPoints,
+    creators: [creator],
+  }).sendAndConfirm(umi);
+
+  // And transfer the asset.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner and the payment was consumed.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient.publicKey,
+    extensions: [{}, {}, { amount: 0n }],
+  });
+});
+
+test('it enforces the royalties constraint when royalties are paid in the same transaction', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+
+  // And a recipient account that is an asset (not a wallet).
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset whose royalties constraint does not allow the recipient.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(
+        500n,
+        pubkeyMatch('Recipient', [generateSigner(umi).publicKey])
+      ),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we pay the royalties before transferring the asset.
+  const promise = payRoyalties(umi, {
+    asset: asset.publicKey,
+    price: sol(1).basisPoints,
+    creators: [creator],
+  })
+    .add(
+      transfer(umi, {
+        asset: asset.publicKey,
+        signer: owner,
+        recipient: recipient.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error, since the payment does not replace the constraint.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // And the asset was not transferred.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: owner.publicKey,
+  });
+});
+
+test('it can transfer an asset with a minimum royalty payment to the creators', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+
+  // And a recipient account that is an asset (not a wallet).
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And an asset requiring a royalty payment of at least 0.1 SOL.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, empty(), RoyaltiesMode.Payment, sol(0.1).basisPoints),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      { mode: RoyaltiesMode.Payment, amount: sol(0.1).basisPoints },
+      {},
+    ],
+  });
+
+  // When we transfer the asset paying less than the minimum amount.
+  await umi.rpc.airdrop(owner.publicKey, sol(1));
+  let promise = transferSol(owner, creator, sol(0.05))
+    .add(
+      transfer(umi, {
+        asset: asset.publicKey,
+        signer: owner,
+        recipient: recipient.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // When the minimum amount is paid by an account that is not part of the transfer.
+  promise = transferSol(umi.identity, creator, sol(0.1))
+    .add(
+      transfer(umi, {
+        asset: asset.publicKey,
+        signer: owner,
+        recipient: recipient.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // When we pay the minimum amount to the creator before transferring the asset.
+  await transferSol(owner, creator, sol(0.1))
+    .add(
+      transfer(umi, {
+        asset: asset.publicKey,
+        signer: owner,
+        recipient: recipient.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+  t.deepEqual(await umi.rpc.getBalance(creator), sol(0.1));
+});
+
+test('it pays the primary rate until the primary sale of an asset', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const buyer = generateSigner(umi);
+  const creator = generateSigner(umi);
+
+  // And an asset with 10% royalties on the primary sale and 5% afterwards.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, empty(), RoyaltiesMode.Constraint, 0, 1000n),
+      creators([{ address: creator.publicKey, share: 100 }]),
+      primarySale(),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset to the creator.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: creator.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the primary sale has not happened.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: creator.publicKey,
+    extensions: [{ primaryBasisPoints: 1000n }, {}, { happened: 0n }],
+  });
+
+  // When we pay the royalties of a 1 SOL primary sale and transfer the asset.
+  await payRoyalties(umi, {
+    asset: asset.publicKey,
+    price: sol(1).basisPoints,
+    creators: [creator.publicKey],
+  })
+    .add(
+      transfer(umi, {
+        asset: asset.publicKey,
+        signer: creator,
+        recipient: buyer.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the creator received the primary rate and the primary sale happened.
+  t.deepEqual(await umi.rpc.getBalance(creator.publicKey), sol(0.1));
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: buyer.publicKey,
+    extensions: [{}, {}, { happened: 1n }],
+  });
+
+  // When we pay the royalties of a 1 SOL secondary sale.
+  await payRoyalties(umi, {
+    asset: asset.publicKey,
+    price: sol(1).basisPoints,
+    creators: [creator.publicKey],
+  }).sendAndConfirm(umi);
+
+  // Then the creator received the secondary rate.
+  t.deepEqual(await umi.rpc.getBalance(creator.publicKey), sol(0.15));
+});
+
+test('it applies the primary rate of group royalties to each asset', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+
+  // And a recipient account that is an asset (not a wallet).
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And a group with royalties exempt from the constraint on the primary sale.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [
+      grouping(10),
+      royalties(
+        500n,
+        pubkeyMatch('Recipient', [generateSigner(umi).publicKey]),
+        RoyaltiesMode.Constraint,
+        0,
+        0n
+      ),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // And two assets in the group, only one of them in its primary sale.
+  const primary = generateSigner(umi);
+  const secondary = generateSigner(umi);
+
+  await mint(umi, {
+    asset: primary,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [primarySale()],
+  }).sendAndConfirm(umi);
+
+  await mint(umi, {
+    asset: secondary,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [primarySale(true)],
+  }).sendAndConfirm(umi);
+
+  for (const asset of [primary, secondary]) {
+    await group(umi, {
+      group: groupAsset.publicKey,
+      asset: asset.publicKey,
+    }).sendAndConfirm(umi);
+  }
+
+  // When we transfer the asset in its primary sale.
+  await transfer(umi, {
+    asset: primary.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset was transferred and its primary sale happened.
+  t.like(await fetchAsset(umi, primary.publicKey), <Asset>{
+    owner: recipient.publicKey,
+    extensions: [{ happened: 1n }],
+  });
+
+  // When we transfer the asset past its primary sale.
+  const promise = transfer(umi, {
+    asset: secondary.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the group royalties constraint is enforced.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+});
+
+test('it requires a royalty payment on wallet-to-wallet transfers', async (t) => {
+  // Given a Umi instance and two wallets.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const buyer = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+
+  // And an asset requiring a royalty payment of at least 0.1 SOL.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, empty(), RoyaltiesMode.Payment, sol(0.1).basisPoints),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset to the wallet without paying the royalties.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: buyer.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+});
+
+test('it cannot use the same royalty payment for multiple transfers', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+  await umi.rpc.airdrop(owner.publicKey, sol(1));
+
+  // And a recipient account that is an asset (not a wallet).
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And two assets requiring a royalty payment of at least 0.1 SOL.
+  const first = generateSigner(umi);
+  await mint(umi, {
+    asset: first,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, empty(), RoyaltiesMode.Payment, sol(0.1).basisPoints),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  const second = generateSigner(umi);
+  await mint(umi, {
+    asset: second,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(500n, empty(), RoyaltiesMode.Payment, sol(0.1).basisPoints),
+      creators([{ address: creator, share: 100 }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we pay the minimum amount once and transfer both assets.
+  const promise = transferSol(owner, creator, sol(0.1))
+    .add(
+      transfer(umi, {
+        asset: first.publicKey,
+        signer: owner,
+        recipient: recipient.publicKey,
+      })
+    )
+    .add(
+      transfer(umi, {
+        asset: second.publicKey,
+        signer: owner,
+        recipient: recipient.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // When we transfer both assets in a single instruction with a single payment.
+  const multiple = transferSol(owner, creator, sol(0.1))
+    .add(
+      transferMany(umi, {
+        signer: owner,
+        recipient: recipient.publicKey,
+        assets: [first.publicKey, second.publicKey],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(multiple, { message: /Assertion Failure/ });
+
+  // When we pay the royalties of both assets.
+  await transferSol(owner, creator, sol(0.2))
+    .add(
+      transferMany(umi, {
+        signer: owner,
+        recipient: recipient.publicKey,
+        assets: [first.publicKey, second.publicKey],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then both assets were transferred.
+  t.like(await fetchAsset(umi, first.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+  t.like(await fetchAsset(umi, second.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+});
@@ -0,0 +1,200 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  Discriminator,
+  ExtensionType,
+  Standard,
+  creators,
+  fetchAsset,
+  masterEdition,
+  mint,
+  print,
+  royalties,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can print an edition of a master asset', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And we create a master asset.
+  const master = generateSigner(umi);
+  await mint(umi, {
+    asset: master,
+    payer: umi.identity,
+    name: 'Master',
+    extensions: [masterEdition(100)],
+  }).sendAndConfirm(umi);
+
+  // When we print an edition.
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+  await print(umi, {
+    master: master.publicKey,
+    asset,
+    owner: owner.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the print references the master and carries its edition number.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    discriminator: Discriminator.Asset,
+    standard: Standard.NonFungible,
+    owner: owner.publicKey,
+    authority: umi.identity.publicKey,
+    name: 'Master',
+    extensions: [
+      {
+        type: ExtensionType.Edition,
+        master: master.publicKey,
+        number: 1n,
+      },
+    ],
+  });
+
+  // And the master supply has increased.
+  t.like(await fetchAsset(umi, master.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.MasterEdition,
+        supply: 1n,
+        maxSupply: 100n,
+      },
+    ],
+  });
+});
+
+test('it cannot exceed max edition supply', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And we create a master asset with a max supply of 1.
+  const master = generateSigner(umi);
+  await mint(umi, {
+    asset: master,
+    payer: umi.identity,
+    name: 'Master',
+    extensions: [masterEdition(1)],
+  }).sendAndConfirm(umi);
+
+  // And we print the only edition.
+  await print(umi, {
+    master: master.publicKey,
+    asset: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  // When we try to print a second edition.
+  const promise = print(umi, {
+    master: master.publicKey,
+    asset: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Maximum edition supply reached/ });
+
+  // And the master supply is the same.
+  t.like(await fetchAsset(umi, master.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.MasterEdition,
+        supply: 1n,
+        maxSupply: 1n,
+      },
+    ],
+  });
+});
+
+test('it cannot print from a non-master asset', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And a "normal" asset.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Asset',
+  }).sendAndConfirm(umi);
+
+  // When we try to print from the asset.
+  const promise = print(umi, {
+    master: asset.publicKey,
+    asset: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Extension not found/ });
+});
+
+test('it copies the creators and royalties of the master to the print', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And we create a master asset with creators and royalties.
+  const master = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+  await mint(umi, {
+    asset: master,
+    payer: umi.identity,
+    name: 'Master',
+    extensions: [
+      masterEdition(100),
+      creators([{ address: creator, share: 100 }]),
+      royalties(500),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we print an edition.
+  const asset = generateSigner(umi);
+  await print(umi, {
+    master: master.publicKey,
+    asset,
+    owner: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the print carries the creators and royalties of the master.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Edition,
+        master: master.publicKey,
+        number: 1n,
+      },
+      {
+        type: ExtensionType.Creators,
+        values: [{ address: creator, share: 100 }],
+      },
+      {
+        type: ExtensionType.Royalties,
+        basisPoints: 500n,
+      },
+    ],
+  });
+});
+
+test('it cannot print from a soulbound master', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And we create a soulbound master asset.
+  const master = generateSigner(umi);
+  await mint(umi, {
+    asset: master,
+    payer: umi.identity,
+    name: 'Master',
+    standard: Standard.Soulbound,
+    extensions: [masterEdition(100)],
+  }).sendAndConfirm(umi);
+
+  // When we try to print an edition.
+  const promise = print(umi, {
+    master: master.publicKey,
+    asset: generateSigner(umi),
+    owner: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error, since prints are non-fungible assets.
+  await t.throwsAsync(promise, {
+    message: /Extension data invalid/,
+  });
+});
@@ -0,0 +1,135 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  DelegateRole,
+  Standard,
+  State,
+  fetchAsset,
+  lock,
+  manager,
+  mint,
+  recall,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can recall a locked managed asset as the manager', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+  const issuer = generateSigner(umi);
+
+  // And a managed asset.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Managed Asset',
+    standard: Standard.Managed,
+    extensions: [manager(issuer.publicKey, DelegateRole.Transfer)],
+  }).sendAndConfirm(umi);
+
+  // And the owner locks the asset.
+  await lock(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+  }).sendAndConfirm(umi);
+
+  // When the manager recalls the asset.
+  await recall(umi, {
+    asset: asset.publicKey,
+    manager: issuer,
+    recipient: issuer.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset is owned by the issuer and unlocked.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: issuer.publicKey,
+    state: State.Unlocked,
+  });
+});
+
+test('it can recall a soulbound asset as the manager', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+  const issuer = generateSigner(umi);
+
+  // And a soulbound asset with a manager (without delegate roles).
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Credential',
+    standard: Standard.Soulbound,
+    extensions: [manager(issuer.publicKey, [])],
+  }).sendAndConfirm(umi);
+
+  // When the manager recalls the asset.
+  await recall(umi, {
+    asset: asset.publicKey,
+    manager: issuer,
+    recipient: issuer.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset is owned by the issuer.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: issuer.publicKey,
+    standard: Standard.Soulbound,
+  });
+});
+
+test('it cannot recall an asset as a non-manager', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And a managed asset.
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Managed Asset',
+    standard: Standard.Managed,
+    extensions: [
+      manager(generateSigner(umi).publicKey, DelegateRole.Transfer),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When a different signer tries to recall the asset.
+  const fake = generateSigner(umi);
+  const promise = recall(umi, {
+    asset: asset.publicKey,
+    manager: fake,
+    recipient: fake.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid delegate/ });
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: owner.publicKey,
+  });
+});
+
+test('it cannot create a soulbound asset with a manager with delegate roles', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+  const issuer = generateSigner(umi);
+
+  // When we try to create a soulbound asset with a manager that can burn it.
+  const promise = mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Credential',
+    standard: Standard.Soulbound,
+    extensions: [manager(issuer.publicKey, DelegateRole.Burn)],
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error, since the manager can only recall the asset.
+  await t.throwsAsync(promise, { message: /Extension data invalid/ });
+});
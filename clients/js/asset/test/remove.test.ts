@@ -12,7 +12,10 @@ import {
   grouping,
   links,
   manager,
+  masterEdition,
   metadata,
+  mint,
+  print,
   remove,
   royalties,
 } from '../src';
@@ -416,3 +419,70 @@ test('it cannot remove a non-existing extension', async (t) => {
     message: /Extension not found/,
   });
 });
+
+test('it cannot remove the master edition extension from a printed master', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And we create a master asset with one print.
+  const master = generateSigner(umi);
+  await mint(umi, {
+    asset: master,
+    payer: umi.identity,
+    name: 'Master',
+    extensions: [masterEdition(100)],
+  }).sendAndConfirm(umi);
+
+  await print(umi, {
+    master: master.publicKey,
+    asset: generateSigner(umi),
+    owner: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When we try to remove the master edition extension.
+  const promise = remove(umi, {
+    asset: master.publicKey,
+    authority: umi.identity,
+    recipient: umi.identity.publicKey,
+    extensionType: ExtensionType.MasterEdition,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, {
+    message: /Extension data invalid/,
+  });
+});
+
+test('it cannot remove the edition extension from a print', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // And we create a master asset with one print.
+  const master = generateSigner(umi);
+  await mint(umi, {
+    asset: master,
+    payer: umi.identity,
+    name: 'Master',
+    extensions: [masterEdition(100)],
+  }).sendAndConfirm(umi);
+
+  const asset = generateSigner(umi);
+  await print(umi, {
+    master: master.publicKey,
+    asset,
+    owner: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When we try to remove the edition extension from the print.
+  const promise = remove(umi, {
+    asset: asset.publicKey,
+    authority: umi.identity,
+    recipient: umi.identity.publicKey,
+    extensionType: ExtensionType.Edition,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, {
+    message: /Extension data invalid/,
+  });
+});
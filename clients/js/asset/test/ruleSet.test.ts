@@ -0,0 +1,226 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import { publicKey as publicKeySerializer } from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  ASSET_PROGRAM_ID,
+  Asset,
+  Discriminator,
+  and,
+  createRuleSet,
+  external,
+  fetchAsset,
+  getConstraintSerializer,
+  mint,
+  not,
+  pubkeyMatch,
+  royalties,
+  transfer,
+  updateRuleSet,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can create a rule set', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const ruleSet = generateSigner(umi);
+  const constraint = getConstraintSerializer().serialize(
+    pubkeyMatch('Recipient', [generateSigner(umi).publicKey])
+  );
+
+  // When we create a new rule set.
+  await createRuleSet(umi, {
+    ruleSet,
+    ruleSetInput: { constraint },
+  }).sendAndConfirm(umi);
+
+  // Then the rule set account holds the authority and the constraint.
+  const account = await umi.rpc.getAccount(ruleSet.publicKey);
+  t.true(account.exists);
+  if (account.exists) {
+    t.is(account.owner, ASSET_PROGRAM_ID);
+    t.is(account.data[0], Discriminator.RuleSet);
+    t.is(
+      publicKeySerializer().deserialize(account.data, 8)[0],
+      umi.identity.publicKey
+    );
+    t.deepEqual(account.data.slice(40), constraint);
+  }
+});
+
+test('it cannot create a rule set with a nested external constraint', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const ruleSet = generateSigner(umi);
+
+  // When we try to create a rule set referencing another rule set in a
+  // nested constraint.
+  const promise = createRuleSet(umi, {
+    ruleSet,
+    ruleSetInput: {
+      constraint: getConstraintSerializer().serialize(
+        and([
+          pubkeyMatch('Recipient', [generateSigner(umi).publicKey]),
+          not(external(generateSigner(umi).publicKey)),
+        ])
+      ),
+    },
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /invalid instruction data/ });
+});
+
+test('it can share a rule set between assets', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And two recipient accounts that are assets.
+  const allowed = generateSigner(umi);
+  await mint(umi, {
+    asset: allowed,
+    payer: umi.identity,
+    name: 'Allowed',
+  }).sendAndConfirm(umi);
+
+  const other = generateSigner(umi);
+  await mint(umi, {
+    asset: other,
+    payer: umi.identity,
+    name: 'Other',
+  }).sendAndConfirm(umi);
+
+  // And a rule set that only allows transfers to the first recipient.
+  const ruleSet = generateSigner(umi);
+  await createRuleSet(umi, {
+    ruleSet,
+    ruleSetInput: {
+      constraint: getConstraintSerializer().serialize(
+        pubkeyMatch('Recipient', [allowed.publicKey])
+      ),
+    },
+  }).sendAndConfirm(umi);
+
+  // And an asset with royalties referencing the rule set.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [royalties(500n, external(ruleSet.publicKey))],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset without the rule set account.
+  let promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: allowed.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /insufficient account keys/ });
+
+  // When we transfer the asset to the other recipient.
+  promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: other.publicKey,
+    ruleSet: ruleSet.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Assertion Failure/ });
+
+  // When the rule set authority updates the rule set to allow the other recipient.
+  await updateRuleSet(umi, {
+    ruleSet: ruleSet.publicKey,
+    payer: umi.identity,
+    ruleSetInput: {
+      constraint: getConstraintSerializer().serialize(
+        pubkeyMatch('Recipient', [allowed.publicKey, other.publicKey])
+      ),
+    },
+  }).sendAndConfirm(umi);
+
+  // And we transfer the asset to the other recipient.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: other.publicKey,
+    ruleSet: ruleSet.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: other.publicKey,
+  });
+});
+
+test('it can reference multiple rule sets', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And a recipient account that is an asset.
+  const recipient = generateSigner(umi);
+  await mint(umi, {
+    asset: recipient,
+    payer: umi.identity,
+    name: 'Recipient',
+  }).sendAndConfirm(umi);
+
+  // And two rule sets that allow transfers to the recipient.
+  const first = generateSigner(umi);
+  const second = generateSigner(umi);
+
+  for (const ruleSet of [first, second]) {
+    await createRuleSet(umi, {
+      ruleSet,
+      ruleSetInput: {
+        constraint: getConstraintSerializer().serialize(
+          pubkeyMatch('Recipient', [recipient.publicKey])
+        ),
+      },
+    }).sendAndConfirm(umi);
+  }
+
+  // And an asset with royalties referencing both rule sets.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      royalties(
+        500n,
+        and([external(first.publicKey), external(second.publicKey)])
+      ),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset with only one of the rule sets.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+    ruleSet: first.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /insufficient account keys/ });
+
+  // When we transfer the asset with both rule sets (in any order).
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient: recipient.publicKey,
+    ruleSet: [second.publicKey, first.publicKey],
+  }).sendAndConfirm(umi);
+
+  // Then the asset has a new owner.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient.publicKey,
+  });
+});
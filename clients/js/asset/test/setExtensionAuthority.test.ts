@@ -0,0 +1,141 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  ExtensionType,
+  attributes,
+  fetchAsset,
+  links,
+  mint,
+  remove,
+  setExtensionAuthority,
+  update,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can set the authority of an extension', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+
+  // And we create a new asset with links and attributes extensions.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [
+      links([{ name: 'website', uri: 'https://nifty-oss.org' }]),
+      attributes([{ name: 'Level', value: '1' }]),
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we set a different authority for the links extension.
+  const studio = generateSigner(umi);
+  await setExtensionAuthority(umi, {
+    asset: asset.publicKey,
+    extensionType: ExtensionType.Links,
+    newAuthority: studio.publicKey,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then the authority is added to the authorities table.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    authority: umi.identity.publicKey,
+    extensions: [
+      { type: ExtensionType.Links },
+      { type: ExtensionType.Attributes },
+      {
+        type: ExtensionType.Authorities,
+        values: [studio.publicKey],
+      },
+    ],
+  });
+
+  // And the new authority can update the links extension.
+  await update(umi, {
+    asset: asset.publicKey,
+    authority: studio,
+    extension: links([{ name: 'studio', uri: 'https://studio.xyz' }]),
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // And the asset authority can still update the attributes extension.
+  await update(umi, {
+    asset: asset.publicKey,
+    extension: attributes([{ name: 'Level', value: '2' }]),
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [
+      {
+        type: ExtensionType.Links,
+        values: [{ name: 'studio', uri: 'https://studio.xyz' }],
+      },
+      {
+        type: ExtensionType.Attributes,
+        values: [{ name: 'Level', value: '2' }],
+      },
+    ],
+  });
+});
+
+test('the asset authority cannot update an extension with its own authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const asset = generateSigner(umi);
+
+  // And we create a new asset with a links extension.
+  await mint(umi, {
+    asset,
+    payer: umi.identity,
+    name: 'Digital Asset',
+    extensions: [links([{ name: 'website', uri: 'https://nifty-oss.org' }])],
+  }).sendAndConfirm(umi);
+
+  // And we set a different authority for the links extension.
+  const studio = generateSigner(umi);
+  await setExtensionAuthority(umi, {
+    asset: asset.publicKey,
+    extensionType: ExtensionType.Links,
+    newAuthority: studio.publicKey,
+    payer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When the asset authority tries to update the links extension.
+  let promise = update(umi, {
+    asset: asset.publicKey,
+    extension: links([{ name: 'website', uri: 'https://other.xyz' }]),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+
+  // When the asset authority tries to remove the links extension.
+  promise = remove(umi, {
+    asset: asset.publicKey,
+    extensionType: ExtensionType.Links,
+    recipient: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Invalid authority/ });
+
+  // When the studio hands the extension back to the asset authority.
+  await setExtensionAuthority(umi, {
+    asset: asset.publicKey,
+    authority: studio,
+    extensionType: ExtensionType.Links,
+    newAuthority: null,
+  }).sendAndConfirm(umi);
+
+  // Then the asset authority can remove the extension.
+  await remove(umi, {
+    asset: asset.publicKey,
+    extensionType: ExtensionType.Links,
+    recipient: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    extensions: [{ type: ExtensionType.Authorities }],
+  });
+});
@@ -8,6 +8,8 @@ import {
   create,
   delegateInput,
   fetchAsset,
+  group,
+  grouping,
   mint,
   manager,
   transfer,
@@ -356,3 +358,56 @@ test('it cannot transfer an asset with the wrong manager delegate', async (t) =>
     message: /Invalid owner or transfer delegate/,
   });
 });
+
+test('it requires the group account to transfer a group member', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const recipient = generateSigner(umi).publicKey;
+
+  // And a group asset without royalties.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And an asset in the group.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  await group(umi, {
+    group: groupAsset.publicKey,
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the asset without the group account.
+  const promise = transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient,
+  }).sendAndConfirm(umi);
+
+  // Then we get an error, since the group lock must be checked.
+  await t.throwsAsync(promise, { message: /insufficient account keys/ });
+
+  // When we transfer the asset with the group account.
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset was transferred.
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient,
+  });
+});
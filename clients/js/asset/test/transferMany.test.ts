@@ -0,0 +1,188 @@
+import { generateSigner } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  Asset,
+  fetchAsset,
+  group,
+  grouping,
+  lock,
+  mint,
+  transferMany,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can transfer multiple assets', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And we create three assets.
+  const assets = [
+    generateSigner(umi),
+    generateSigner(umi),
+    generateSigner(umi),
+  ];
+
+  for (const asset of assets) {
+    await mint(umi, {
+      asset,
+      owner: owner.publicKey,
+      payer: umi.identity,
+      name: 'Digital Asset',
+    }).sendAndConfirm(umi);
+  }
+
+  // When we transfer all assets at once.
+  const recipient = generateSigner(umi).publicKey;
+  await transferMany(umi, {
+    signer: owner,
+    recipient,
+    assets: assets.map((asset) => asset.publicKey),
+  }).sendAndConfirm(umi);
+
+  // Then all assets have a new owner.
+  for (const asset of assets) {
+    t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+      owner: recipient,
+    });
+  }
+});
+
+test('it can transfer multiple assets of a group', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And we create a group asset.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And two assets in the group.
+  const assets = [generateSigner(umi), generateSigner(umi)];
+
+  for (const asset of assets) {
+    await mint(umi, {
+      asset,
+      owner: owner.publicKey,
+      payer: umi.identity,
+      name: 'Digital Asset',
+    }).sendAndConfirm(umi);
+
+    await group(umi, {
+      group: groupAsset.publicKey,
+      asset: asset.publicKey,
+    }).sendAndConfirm(umi);
+  }
+
+  // When we transfer the assets.
+  const recipient = generateSigner(umi).publicKey;
+  await transferMany(umi, {
+    signer: owner,
+    recipient,
+    assets: assets.map((asset) => asset.publicKey),
+    groups: [groupAsset.publicKey],
+  }).sendAndConfirm(umi);
+
+  // Then the assets have a new owner.
+  for (const asset of assets) {
+    t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+      owner: recipient,
+    });
+  }
+
+  // And the group is not transferred.
+  t.like(await fetchAsset(umi, groupAsset.publicKey), <Asset>{
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it can transfer a group together with its assets', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And we create a group asset.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And an asset in the group.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  await group(umi, {
+    group: groupAsset.publicKey,
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When we transfer both the group and the asset.
+  const recipient = generateSigner(umi).publicKey;
+  await transferMany(umi, {
+    signer: owner,
+    recipient,
+    assets: [groupAsset.publicKey, asset.publicKey],
+  }).sendAndConfirm(umi);
+
+  // Then both assets have a new owner.
+  for (const account of [groupAsset, asset]) {
+    t.like(await fetchAsset(umi, account.publicKey), <Asset>{
+      owner: recipient,
+    });
+  }
+});
+
+test('it cannot transfer multiple assets if one is locked', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  // And we create two assets.
+  const assets = [generateSigner(umi), generateSigner(umi)];
+
+  for (const asset of assets) {
+    await mint(umi, {
+      asset,
+      owner: owner.publicKey,
+      payer: umi.identity,
+      name: 'Digital Asset',
+    }).sendAndConfirm(umi);
+  }
+
+  // And we lock one of the assets.
+  await lock(umi, {
+    asset: assets[1].publicKey,
+    signer: owner,
+  }).sendAndConfirm(umi);
+
+  // When we try to transfer the assets.
+  const promise = transferMany(umi, {
+    signer: owner,
+    recipient: generateSigner(umi).publicKey,
+    assets: assets.map((asset) => asset.publicKey),
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /Asset is locked/ });
+
+  // And no asset was transferred.
+  for (const asset of assets) {
+    t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+      owner: owner.publicKey,
+    });
+  }
+});
@@ -8,7 +8,13 @@ import {
   create,
   delegateInput,
   fetchAsset,
+  freezeGroup,
+  group,
+  grouping,
   lock,
+  mint,
+  thawGroup,
+  transfer,
   unlock,
 } from '../src';
 import { createUmi } from './_setup';
@@ -242,3 +248,95 @@ test('it can unlock an asset that is unlocked', async (t) => {
     state: State.Unlocked,
   });
 });
+
+test('the group authority can thaw a group it froze', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const groupOwner = generateSigner(umi);
+  const owner = generateSigner(umi);
+
+  // And a group asset owned by a different owner than its authority.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    owner: groupOwner.publicKey,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And an asset in the group.
+  const asset = generateSigner(umi);
+  await mint(umi, {
+    asset,
+    owner: owner.publicKey,
+    payer: umi.identity,
+    name: 'Digital Asset',
+  }).sendAndConfirm(umi);
+
+  await group(umi, {
+    group: groupAsset.publicKey,
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  // And the authority froze the group.
+  await freezeGroup(umi, {
+    group: groupAsset.publicKey,
+    authority: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // When the authority thaws the group.
+  await thawGroup(umi, {
+    group: groupAsset.publicKey,
+    authority: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then its members can be transferred again.
+  const recipient = generateSigner(umi).publicKey;
+  await transfer(umi, {
+    asset: asset.publicKey,
+    signer: owner,
+    recipient,
+    group: groupAsset.publicKey,
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchAsset(umi, asset.publicKey), <Asset>{
+    owner: recipient,
+  });
+});
+
+test('the group authority cannot unlock a group locked by its owner', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const groupOwner = generateSigner(umi);
+
+  // And a group asset owned by a different owner than its authority.
+  const groupAsset = generateSigner(umi);
+  await mint(umi, {
+    asset: groupAsset,
+    owner: groupOwner.publicKey,
+    payer: umi.identity,
+    name: 'Group',
+    extensions: [grouping(10)],
+  }).sendAndConfirm(umi);
+
+  // And the owner locked the group.
+  await lock(umi, {
+    asset: groupAsset.publicKey,
+    signer: groupOwner,
+  }).sendAndConfirm(umi);
+
+  // When the authority tries to unlock the group.
+  const promise = unlock(umi, {
+    asset: groupAsset.publicKey,
+    signer: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise);
+
+  // And the group is still locked.
+  t.like(await fetchAsset(umi, groupAsset.publicKey), <Asset>{
+    state: State.Locked,
+  });
+});
@@ -76,6 +76,15 @@ pub enum AssetError {
     /// 21 - Extension length invalid
     #[error("Extension length invalid")]
     ExtensionLengthInvalid = 0x15,
+    /// 22 - Delegate expired
+    #[error("Delegate expired")]
+    DelegateExpired = 0x16,
+    /// 23 - Extension is immutable
+    #[error("Extension is immutable")]
+    ImmutableExtension = 0x17,
+    /// 24 - Rental not expired
+    #[error("Rental not expired")]
+    RentalNotExpired = 0x18,
 }
 
 impl solana_program::program_error::PrintProgramError for AssetError {
@@ -77,6 +77,7 @@ impl Default for ApproveInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApproveInstructionArgs {
     pub delegate_input: DelegateInput,
+    pub expiry: Option<i64>,
 }
 
 /// Instruction builder for `Approve`.
@@ -92,6 +93,7 @@ pub struct ApproveBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     delegate: Option<solana_program::pubkey::Pubkey>,
     delegate_input: Option<DelegateInput>,
+    expiry: Option<i64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -122,6 +124,12 @@ impl ApproveBuilder {
         self.delegate_input = Some(delegate_input);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn expiry(&mut self, expiry: i64) -> &mut Self {
+        self.expiry = Some(expiry);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -152,6 +160,7 @@ impl ApproveBuilder {
                 .delegate_input
                 .clone()
                 .expect("delegate_input is not set"),
+            expiry: self.expiry.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -295,6 +304,7 @@ impl<'a, 'b> ApproveCpiBuilder<'a, 'b> {
             owner: None,
             delegate: None,
             delegate_input: None,
+            expiry: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -325,6 +335,12 @@ impl<'a, 'b> ApproveCpiBuilder<'a, 'b> {
         self.instruction.delegate_input = Some(delegate_input);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn expiry(&mut self, expiry: i64) -> &mut Self {
+        self.instruction.expiry = Some(expiry);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -372,6 +388,7 @@ impl<'a, 'b> ApproveCpiBuilder<'a, 'b> {
                 .delegate_input
                 .clone()
                 .expect("delegate_input is not set"),
+            expiry: self.instruction.expiry.clone(),
         };
         let instruction = ApproveCpi {
             __program: self.instruction.__program,
@@ -397,6 +414,7 @@ struct ApproveCpiBuilderInstruction<'a, 'b> {
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     delegate_input: Option<DelegateInput>,
+    expiry: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,601 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use crate::generated::types::AssetInput;
+use crate::generated::types::Standard;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct CreateMany {
+    /// The authority of the assets
+    pub authority: (solana_program::pubkey::Pubkey, bool),
+    /// Asset account of the group
+    pub group: Option<solana_program::pubkey::Pubkey>,
+    /// Group authority for creating assets into a group
+    pub group_authority: Option<solana_program::pubkey::Pubkey>,
+    /// The account paying for the storage fees
+    pub payer: Option<solana_program::pubkey::Pubkey>,
+    /// The system program
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl CreateMany {
+    pub fn instruction(
+        &self,
+        args: CreateManyInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: CreateManyInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority.0,
+            self.authority.1,
+        ));
+        if let Some(group) = self.group {
+            accounts.push(solana_program::instruction::AccountMeta::new(group, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        if let Some(group_authority) = self.group_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                group_authority,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = CreateManyInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::ASSET_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CreateManyInstructionData {
+    discriminator: u8,
+}
+
+impl CreateManyInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 22 }
+    }
+}
+
+impl Default for CreateManyInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateManyInstructionArgs {
+    pub standard: Standard,
+    pub mutable: bool,
+    pub assets: Vec<AssetInput>,
+}
+
+/// Instruction builder for `CreateMany`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, optional]` group
+///   2. `[signer, optional]` group_authority
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system_program
+#[derive(Clone, Debug, Default)]
+pub struct CreateManyBuilder {
+    authority: Option<(solana_program::pubkey::Pubkey, bool)>,
+    group: Option<solana_program::pubkey::Pubkey>,
+    group_authority: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    standard: Option<Standard>,
+    mutable: Option<bool>,
+    assets: Option<Vec<AssetInput>>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CreateManyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The authority of the assets
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: solana_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.authority = Some((authority, as_signer));
+        self
+    }
+    /// `[optional account]`
+    /// Asset account of the group
+    #[inline(always)]
+    pub fn group(&mut self, group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.group = group;
+        self
+    }
+    /// `[optional account]`
+    /// Group authority for creating assets into a group
+    #[inline(always)]
+    pub fn group_authority(
+        &mut self,
+        group_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.group_authority = group_authority;
+        self
+    }
+    /// `[optional account]`
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional argument, defaults to 'Standard::NonFungible']`
+    #[inline(always)]
+    pub fn standard(&mut self, standard: Standard) -> &mut Self {
+        self.standard = Some(standard);
+        self
+    }
+    /// `[optional argument, defaults to 'true']`
+    #[inline(always)]
+    pub fn mutable(&mut self, mutable: bool) -> &mut Self {
+        self.mutable = Some(mutable);
+        self
+    }
+    #[inline(always)]
+    pub fn assets(&mut self, assets: Vec<AssetInput>) -> &mut Self {
+        self.assets = Some(assets);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CreateMany {
+            authority: self.authority.expect("authority is not set"),
+            group: self.group,
+            group_authority: self.group_authority,
+            payer: self.payer,
+            system_program: self.system_program,
+        };
+        let args = CreateManyInstructionArgs {
+            standard: self.standard.clone().unwrap_or(Standard::NonFungible),
+            mutable: self.mutable.clone().unwrap_or(true),
+            assets: self.assets.clone().expect("assets is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `create_many` CPI accounts.
+pub struct CreateManyCpiAccounts<'a, 'b> {
+    /// The authority of the assets
+    pub authority: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// Asset account of the group
+    pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Group authority for creating assets into a group
+    pub group_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account paying for the storage fees
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The system program
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `create_many` CPI instruction.
+pub struct CreateManyCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority of the assets
+    pub authority: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// Asset account of the group
+    pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Group authority for creating assets into a group
+    pub group_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account paying for the storage fees
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The system program
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: CreateManyInstructionArgs,
+}
+
+impl<'a, 'b> CreateManyCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CreateManyCpiAccounts<'a, 'b>,
+        args: CreateManyInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            group: accounts.group,
+            group_authority: accounts.group_authority,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.0.key,
+            self.authority.1,
+        ));
+        if let Some(group) = self.group {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *group.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        if let Some(group_authority) = self.group_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *group_authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer.key, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = CreateManyInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::ASSET_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.0.clone());
+        if let Some(group) = self.group {
+            account_infos.push(group.clone());
+        }
+        if let Some(group_authority) = self.group_authority {
+            account_infos.push(group_authority.clone());
+        }
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CreateMany` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, optional]` group
+///   2. `[signer, optional]` group_authority
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system_program
+#[derive(Clone, Debug)]
+pub struct CreateManyCpiBuilder<'a, 'b> {
+    instruction: Box<CreateManyCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CreateManyCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CreateManyCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            group: None,
+            group_authority: None,
+            payer: None,
+            system_program: None,
+            standard: None,
+            mutable: None,
+            assets: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The authority of the assets
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.authority = Some((authority, as_signer));
+        self
+    }
+    /// `[optional account]`
+    /// Asset account of the group
+    #[inline(always)]
+    pub fn group(
+        &mut self,
+        group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.group = group;
+        self
+    }
+    /// `[optional account]`
+    /// Group authority for creating assets into a group
+    #[inline(always)]
+    pub fn group_authority(
+        &mut self,
+        group_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.group_authority = group_authority;
+        self
+    }
+    /// `[optional account]`
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(
+        &mut self,
+        payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional argument, defaults to 'Standard::NonFungible']`
+    #[inline(always)]
+    pub fn standard(&mut self, standard: Standard) -> &mut Self {
+        self.instruction.standard = Some(standard);
+        self
+    }
+    /// `[optional argument, defaults to 'true']`
+    #[inline(always)]
+    pub fn mutable(&mut self, mutable: bool) -> &mut Self {
+        self.instruction.mutable = Some(mutable);
+        self
+    }
+    #[inline(always)]
+    pub fn assets(&mut self, assets: Vec<AssetInput>) -> &mut Self {
+        self.instruction.assets = Some(assets);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = CreateManyInstructionArgs {
+            standard: self
+                .instruction
+                .standard
+                .clone()
+                .unwrap_or(Standard::NonFungible),
+            mutable: self.instruction.mutable.clone().unwrap_or(true),
+            assets: self.instruction.assets.clone().expect("assets is not set"),
+        };
+        let instruction = CreateManyCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            group: self.instruction.group,
+
+            group_authority: self.instruction.group_authority,
+
+            payer: self.instruction.payer,
+
+            system_program: self.instruction.system_program,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CreateManyCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    authority: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
+    group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    group_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    standard: Option<Standard>,
+    mutable: Option<bool>,
+    assets: Option<Vec<AssetInput>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
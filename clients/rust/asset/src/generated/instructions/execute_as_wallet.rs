@@ -0,0 +1,492 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct ExecuteAsWallet {
+    /// Asset account
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The owner of the asset
+    pub owner: solana_program::pubkey::Pubkey,
+    /// The wallet of the asset
+    pub wallet: solana_program::pubkey::Pubkey,
+    /// The program to invoke
+    pub program: solana_program::pubkey::Pubkey,
+    /// The asset defining the group, required when the asset is part of a group
+    pub group: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl ExecuteAsWallet {
+    pub fn instruction(
+        &self,
+        args: ExecuteAsWalletInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ExecuteAsWalletInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.owner, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.wallet,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(group) = self.group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                group, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = ExecuteAsWalletInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::ASSET_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ExecuteAsWalletInstructionData {
+    discriminator: u8,
+}
+
+impl ExecuteAsWalletInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 28 }
+    }
+}
+
+impl Default for ExecuteAsWalletInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecuteAsWalletInstructionArgs {
+    pub data: Vec<u8>,
+}
+
+/// Instruction builder for `ExecuteAsWallet`.
+///
+/// ### Accounts:
+///
+///   0. `[]` asset
+///   1. `[signer]` owner
+///   2. `[writable]` wallet
+///   3. `[]` program
+///   4. `[optional]` group
+#[derive(Clone, Debug, Default)]
+pub struct ExecuteAsWalletBuilder {
+    asset: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<solana_program::pubkey::Pubkey>,
+    wallet: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    group: Option<solana_program::pubkey::Pubkey>,
+    data: Option<Vec<u8>>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ExecuteAsWalletBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Asset account
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// The owner of the asset
+    #[inline(always)]
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// The wallet of the asset
+    #[inline(always)]
+    pub fn wallet(&mut self, wallet: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.wallet = Some(wallet);
+        self
+    }
+    /// The program to invoke
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The asset defining the group, required when the asset is part of a group
+    #[inline(always)]
+    pub fn group(&mut self, group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.group = group;
+        self
+    }
+    #[inline(always)]
+    pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
+        self.data = Some(data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ExecuteAsWallet {
+            asset: self.asset.expect("asset is not set"),
+            owner: self.owner.expect("owner is not set"),
+            wallet: self.wallet.expect("wallet is not set"),
+            program: self.program.expect("program is not set"),
+            group: self.group,
+        };
+        let args = ExecuteAsWalletInstructionArgs {
+            data: self.data.clone().expect("data is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `execute_as_wallet` CPI accounts.
+pub struct ExecuteAsWalletCpiAccounts<'a, 'b> {
+    /// Asset account
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the asset
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The wallet of the asset
+    pub wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The program to invoke
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The asset defining the group, required when the asset is part of a group
+    pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `execute_as_wallet` CPI instruction.
+pub struct ExecuteAsWalletCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Asset account
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the asset
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The wallet of the asset
+    pub wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The program to invoke
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The asset defining the group, required when the asset is part of a group
+    pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: ExecuteAsWalletInstructionArgs,
+}
+
+impl<'a, 'b> ExecuteAsWalletCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ExecuteAsWalletCpiAccounts<'a, 'b>,
+        args: ExecuteAsWalletInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            asset: accounts.asset,
+            owner: accounts.owner,
+            wallet: accounts.wallet,
+            program: accounts.program,
+            group: accounts.group,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.owner.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.wallet.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(group) = self.group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *group.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = ExecuteAsWalletInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::ASSET_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.asset.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.wallet.clone());
+        account_infos.push(self.program.clone());
+        if let Some(group) = self.group {
+            account_infos.push(group.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ExecuteAsWallet` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` asset
+///   1. `[signer]` owner
+///   2. `[writable]` wallet
+///   3. `[]` program
+///   4. `[optional]` group
+#[derive(Clone, Debug)]
+pub struct ExecuteAsWalletCpiBuilder<'a, 'b> {
+    instruction: Box<ExecuteAsWalletCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ExecuteAsWalletCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ExecuteAsWalletCpiBuilderInstruction {
+            __program: program,
+            asset: None,
+            owner: None,
+            wallet: None,
+            program: None,
+            group: None,
+            data: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Asset account
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// The owner of the asset
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    /// The wallet of the asset
+    #[inline(always)]
+    pub fn wallet(
+        &mut self,
+        wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.wallet = Some(wallet);
+        self
+    }
+    /// The program to invoke
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The asset defining the group, required when the asset is part of a group
+    #[inline(always)]
+    pub fn group(
+        &mut self,
+        group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.group = group;
+        self
+    }
+    #[inline(always)]
+    pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
+        self.instruction.data = Some(data);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = ExecuteAsWalletInstructionArgs {
+            data: self.instruction.data.clone().expect("data is not set"),
+        };
+        let instruction = ExecuteAsWalletCpi {
+            __program: self.instruction.__program,
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            wallet: self.instruction.wallet.expect("wallet is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+
+            group: self.instruction.group,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExecuteAsWalletCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    data: Option<Vec<u8>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
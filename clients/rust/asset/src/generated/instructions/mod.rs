@@ -5,40 +5,74 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#accept_handover;
 pub(crate) mod r#allocate;
 pub(crate) mod r#approve;
 pub(crate) mod r#burn;
+pub(crate) mod r#cancel_handover;
 pub(crate) mod r#close;
 pub(crate) mod r#create;
+pub(crate) mod r#create_many;
+pub(crate) mod r#create_rule_set;
+pub(crate) mod r#execute_as_wallet;
+pub(crate) mod r#freeze_group;
 pub(crate) mod r#group;
 pub(crate) mod r#handover;
+pub(crate) mod r#lend;
 pub(crate) mod r#lock;
+pub(crate) mod r#make_immutable;
+pub(crate) mod r#pay_royalties;
+pub(crate) mod r#print;
+pub(crate) mod r#propose_handover;
+pub(crate) mod r#recall;
+pub(crate) mod r#reclaim;
 pub(crate) mod r#remove;
 pub(crate) mod r#resize;
 pub(crate) mod r#revoke;
+pub(crate) mod r#set_extension_authority;
+pub(crate) mod r#thaw_group;
 pub(crate) mod r#transfer;
+pub(crate) mod r#transfer_many;
 pub(crate) mod r#ungroup;
 pub(crate) mod r#unlock;
 pub(crate) mod r#unverify;
 pub(crate) mod r#update;
+pub(crate) mod r#update_rule_set;
 pub(crate) mod r#verify;
 pub(crate) mod r#write;
 
+pub use self::r#accept_handover::*;
 pub use self::r#allocate::*;
 pub use self::r#approve::*;
 pub use self::r#burn::*;
+pub use self::r#cancel_handover::*;
 pub use self::r#close::*;
 pub use self::r#create::*;
+pub use self::r#create_many::*;
+pub use self::r#create_rule_set::*;
+pub use self::r#execute_as_wallet::*;
+pub use self::r#freeze_group::*;
 pub use self::r#group::*;
 pub use self::r#handover::*;
+pub use self::r#lend::*;
 pub use self::r#lock::*;
+pub use self::r#make_immutable::*;
+pub use self::r#pay_royalties::*;
+pub use self::r#print::*;
+pub use self::r#propose_handover::*;
+pub use self::r#recall::*;
+pub use self::r#reclaim::*;
 pub use self::r#remove::*;
 pub use self::r#resize::*;
 pub use self::r#revoke::*;
+pub use self::r#set_extension_authority::*;
+pub use self::r#thaw_group::*;
 pub use self::r#transfer::*;
+pub use self::r#transfer_many::*;
 pub use self::r#ungroup::*;
 pub use self::r#unlock::*;
 pub use self::r#unverify::*;
 pub use self::r#update::*;
+pub use self::r#update_rule_set::*;
 pub use self::r#verify::*;
 pub use self::r#write::*;
@@ -13,7 +13,7 @@ use borsh::BorshSerialize;
 pub struct Remove {
     /// Asset account
     pub asset: solana_program::pubkey::Pubkey,
-    /// The authority of the asset
+    /// The authority of the asset or extension
     pub authority: solana_program::pubkey::Pubkey,
     /// The asset defining the group, if applicable
     pub group: Option<solana_program::pubkey::Pubkey>,
@@ -120,7 +120,7 @@ impl RemoveBuilder {
         self.asset = Some(asset);
         self
     }
-    /// The authority of the asset
+    /// The authority of the asset or extension
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -185,7 +185,7 @@ impl RemoveBuilder {
 pub struct RemoveCpiAccounts<'a, 'b> {
     /// Asset account
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The authority of the asset
+    /// The authority of the asset or extension
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// The asset defining the group, if applicable
     pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -199,7 +199,7 @@ pub struct RemoveCpi<'a, 'b> {
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Asset account
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The authority of the asset
+    /// The authority of the asset or extension
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// The asset defining the group, if applicable
     pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -348,7 +348,7 @@ impl<'a, 'b> RemoveCpiBuilder<'a, 'b> {
         self.instruction.asset = Some(asset);
         self
     }
-    /// The authority of the asset
+    /// The authority of the asset or extension
     #[inline(always)]
     pub fn authority(
         &mut self,
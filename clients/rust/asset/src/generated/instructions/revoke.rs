@@ -15,6 +15,8 @@ pub struct Revoke {
     pub asset: solana_program::pubkey::Pubkey,
     /// Owner of the asset or current delegate
     pub signer: solana_program::pubkey::Pubkey,
+    /// The delegate to revoke
+    pub delegate: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Revoke {
@@ -30,7 +32,7 @@ impl Revoke {
         args: RevokeInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.asset, false,
         ));
@@ -38,6 +40,16 @@ impl Revoke {
             self.signer,
             true,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                delegate, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = RevokeInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -80,10 +92,12 @@ pub struct RevokeInstructionArgs {
 ///
 ///   0. `[writable]` asset
 ///   1. `[signer]` signer
+///   2. `[optional]` delegate
 #[derive(Clone, Debug, Default)]
 pub struct RevokeBuilder {
     asset: Option<solana_program::pubkey::Pubkey>,
     signer: Option<solana_program::pubkey::Pubkey>,
+    delegate: Option<solana_program::pubkey::Pubkey>,
     delegate_input: Option<DelegateInput>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -104,6 +118,13 @@ impl RevokeBuilder {
         self.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    /// The delegate to revoke
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegate = delegate;
+        self
+    }
     #[inline(always)]
     pub fn delegate_input(&mut self, delegate_input: DelegateInput) -> &mut Self {
         self.delegate_input = Some(delegate_input);
@@ -132,6 +153,7 @@ impl RevokeBuilder {
         let accounts = Revoke {
             asset: self.asset.expect("asset is not set"),
             signer: self.signer.expect("signer is not set"),
+            delegate: self.delegate,
         };
         let args = RevokeInstructionArgs {
             delegate_input: self
@@ -150,6 +172,8 @@ pub struct RevokeCpiAccounts<'a, 'b> {
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
     /// Owner of the asset or current delegate
     pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The delegate to revoke
+    pub delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `revoke` CPI instruction.
@@ -160,6 +184,8 @@ pub struct RevokeCpi<'a, 'b> {
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
     /// Owner of the asset or current delegate
     pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The delegate to revoke
+    pub delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: RevokeInstructionArgs,
 }
@@ -174,6 +200,7 @@ impl<'a, 'b> RevokeCpi<'a, 'b> {
             __program: program,
             asset: accounts.asset,
             signer: accounts.signer,
+            delegate: accounts.delegate,
             __args: args,
         }
     }
@@ -210,7 +237,7 @@ impl<'a, 'b> RevokeCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.asset.key,
             false,
@@ -219,6 +246,17 @@ impl<'a, 'b> RevokeCpi<'a, 'b> {
             *self.signer.key,
             true,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ASSET_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -235,10 +273,13 @@ impl<'a, 'b> RevokeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.asset.clone());
         account_infos.push(self.signer.clone());
+        if let Some(delegate) = self.delegate {
+            account_infos.push(delegate.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -257,6 +298,7 @@ impl<'a, 'b> RevokeCpi<'a, 'b> {
 ///
 ///   0. `[writable]` asset
 ///   1. `[signer]` signer
+///   2. `[optional]` delegate
 #[derive(Clone, Debug)]
 pub struct RevokeCpiBuilder<'a, 'b> {
     instruction: Box<RevokeCpiBuilderInstruction<'a, 'b>>,
@@ -268,6 +310,7 @@ impl<'a, 'b> RevokeCpiBuilder<'a, 'b> {
             __program: program,
             asset: None,
             signer: None,
+            delegate: None,
             delegate_input: None,
             __remaining_accounts: Vec::new(),
         });
@@ -288,6 +331,16 @@ impl<'a, 'b> RevokeCpiBuilder<'a, 'b> {
         self.instruction.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    /// The delegate to revoke
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegate = delegate;
+        self
+    }
     #[inline(always)]
     pub fn delegate_input(&mut self, delegate_input: DelegateInput) -> &mut Self {
         self.instruction.delegate_input = Some(delegate_input);
@@ -347,6 +400,8 @@ impl<'a, 'b> RevokeCpiBuilder<'a, 'b> {
             asset: self.instruction.asset.expect("asset is not set"),
 
             signer: self.instruction.signer.expect("signer is not set"),
+
+            delegate: self.instruction.delegate,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -361,6 +416,7 @@ struct RevokeCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     delegate_input: Option<DelegateInput>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
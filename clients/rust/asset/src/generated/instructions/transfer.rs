@@ -16,7 +16,7 @@ pub struct Transfer {
     pub signer: solana_program::pubkey::Pubkey,
     /// The recipient of the asset
     pub recipient: solana_program::pubkey::Pubkey,
-    /// The asset defining the group, if applicable
+    /// The asset defining the group, required when the asset is part of a group
     pub group: Option<solana_program::pubkey::Pubkey>,
 }
 
@@ -119,7 +119,7 @@ impl TransferBuilder {
         self
     }
     /// `[optional account]`
-    /// The asset defining the group, if applicable
+    /// The asset defining the group, required when the asset is part of a group
     #[inline(always)]
     pub fn group(&mut self, group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.group = group;
@@ -164,7 +164,7 @@ pub struct TransferCpiAccounts<'a, 'b> {
     pub signer: &'b solana_program::account_info::AccountInfo<'a>,
     /// The recipient of the asset
     pub recipient: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The asset defining the group, if applicable
+    /// The asset defining the group, required when the asset is part of a group
     pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
@@ -178,7 +178,7 @@ pub struct TransferCpi<'a, 'b> {
     pub signer: &'b solana_program::account_info::AccountInfo<'a>,
     /// The recipient of the asset
     pub recipient: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The asset defining the group, if applicable
+    /// The asset defining the group, required when the asset is part of a group
     pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
@@ -335,7 +335,7 @@ impl<'a, 'b> TransferCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The asset defining the group, if applicable
+    /// The asset defining the group, required when the asset is part of a group
     #[inline(always)]
     pub fn group(
         &mut self,
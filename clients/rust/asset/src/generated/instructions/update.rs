@@ -13,7 +13,7 @@ use borsh::BorshSerialize;
 pub struct Update {
     /// Asset account
     pub asset: solana_program::pubkey::Pubkey,
-    /// The authority of the asset
+    /// The authority or update delegate of the asset
     pub authority: solana_program::pubkey::Pubkey,
     /// Extension buffer (uninitialized asset) account
     pub buffer: Option<solana_program::pubkey::Pubkey>,
@@ -155,7 +155,7 @@ impl UpdateBuilder {
         self.asset = Some(asset);
         self
     }
-    /// The authority of the asset
+    /// The authority or update delegate of the asset
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -252,7 +252,7 @@ impl UpdateBuilder {
 pub struct UpdateCpiAccounts<'a, 'b> {
     /// Asset account
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The authority of the asset
+    /// The authority or update delegate of the asset
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Extension buffer (uninitialized asset) account
     pub buffer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -270,7 +270,7 @@ pub struct UpdateCpi<'a, 'b> {
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Asset account
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The authority of the asset
+    /// The authority or update delegate of the asset
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Extension buffer (uninitialized asset) account
     pub buffer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -467,7 +467,7 @@ impl<'a, 'b> UpdateCpiBuilder<'a, 'b> {
         self.instruction.asset = Some(asset);
         self
     }
-    /// The authority of the asset
+    /// The authority or update delegate of the asset
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -35,4 +35,16 @@ pub enum ExtensionType {
     Proxy,
     Properties,
     Bucket,
+    Edition,
+    MasterEdition,
+    DelegateExpiry,
+    Delegates,
+    UpdateDelegate,
+    Authorities,
+    TransferHook,
+    Guards,
+    Rental,
+    PendingAuthority,
+    PrimarySale,
+    RoyaltyPayment,
 }
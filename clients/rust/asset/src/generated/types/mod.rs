@@ -5,15 +5,21 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#asset_input;
 pub(crate) mod r#attributes;
+pub(crate) mod r#authorities;
 pub(crate) mod r#blob;
 pub(crate) mod r#bucket;
 pub(crate) mod r#creator;
 pub(crate) mod r#creators;
 pub(crate) mod r#delegate;
+pub(crate) mod r#delegate_expiry;
 pub(crate) mod r#delegate_input;
 pub(crate) mod r#delegate_role;
+pub(crate) mod r#delegates;
 pub(crate) mod r#discriminator;
+pub(crate) mod r#edition;
+pub(crate) mod r#expiry;
 pub(crate) mod r#extension_header;
 pub(crate) mod r#extension_input;
 pub(crate) mod r#extension_type;
@@ -21,23 +27,37 @@ pub(crate) mod r#grouping;
 pub(crate) mod r#link;
 pub(crate) mod r#links;
 pub(crate) mod r#manager;
+pub(crate) mod r#master_edition;
 pub(crate) mod r#metadata;
+pub(crate) mod r#pending_authority;
+pub(crate) mod r#primary_sale;
 pub(crate) mod r#proxy;
+pub(crate) mod r#rental;
+pub(crate) mod r#royalty_payment;
+pub(crate) mod r#rule_set_input;
 pub(crate) mod r#standard;
 pub(crate) mod r#state;
 pub(crate) mod r#strategy;
 pub(crate) mod r#trait;
+pub(crate) mod r#transfer_hook;
 pub(crate) mod r#type;
+pub(crate) mod r#update_delegate;
 
+pub use self::r#asset_input::*;
 pub use self::r#attributes::*;
+pub use self::r#authorities::*;
 pub use self::r#blob::*;
 pub use self::r#bucket::*;
 pub use self::r#creator::*;
 pub use self::r#creators::*;
 pub use self::r#delegate::*;
+pub use self::r#delegate_expiry::*;
 pub use self::r#delegate_input::*;
 pub use self::r#delegate_role::*;
+pub use self::r#delegates::*;
 pub use self::r#discriminator::*;
+pub use self::r#edition::*;
+pub use self::r#expiry::*;
 pub use self::r#extension_header::*;
 pub use self::r#extension_input::*;
 pub use self::r#extension_type::*;
@@ -45,10 +65,18 @@ pub use self::r#grouping::*;
 pub use self::r#link::*;
 pub use self::r#links::*;
 pub use self::r#manager::*;
+pub use self::r#master_edition::*;
 pub use self::r#metadata::*;
+pub use self::r#pending_authority::*;
+pub use self::r#primary_sale::*;
 pub use self::r#proxy::*;
+pub use self::r#rental::*;
+pub use self::r#royalty_payment::*;
+pub use self::r#rule_set_input::*;
 pub use self::r#standard::*;
 pub use self::r#state::*;
 pub use self::r#strategy::*;
 pub use self::r#trait::*;
+pub use self::r#transfer_hook::*;
 pub use self::r#type::*;
+pub use self::r#update_delegate::*;
@@ -0,0 +1,173 @@
+#![cfg(feature = "test-sbf")]
+pub mod utils;
+
+use nifty_asset::{
+    errors::AssetError,
+    extensions::{ExtensionBuilder, GroupingBuilder},
+    instructions::{
+        CreateBuilder, FreezeGroupBuilder, GroupBuilder, ThawGroupBuilder, TransferBuilder,
+    },
+    state::{Asset, State},
+    types::{ExtensionInput, ExtensionType},
+    ZeroCopy,
+};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+mod group {
+    use super::*;
+
+    #[tokio::test]
+    async fn cannot_transfer_member_of_frozen_group() {
+        let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+            .start_with_context()
+            .await;
+
+        let group_signer = Keypair::new();
+        let group = group_signer.pubkey();
+        let asset_signer = Keypair::new();
+        let asset = asset_signer.pubkey();
+
+        // Given a group asset.
+
+        let mut grouping = GroupingBuilder::default();
+        grouping.set(Some(10), None);
+        let data = grouping.data();
+
+        let ix = CreateBuilder::new()
+            .asset(group)
+            .authority(context.payer.pubkey(), false)
+            .owner(context.payer.pubkey())
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .name("group".to_string())
+            .extensions(vec![ExtensionInput {
+                extension_type: ExtensionType::Grouping,
+                length: data.len() as u32,
+                data: Some(data),
+            }])
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &group_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // And an asset in the group.
+
+        let create_ix = CreateBuilder::new()
+            .asset(asset)
+            .authority(context.payer.pubkey(), false)
+            .owner(context.payer.pubkey())
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .name("name".to_string())
+            .instruction();
+
+        let group_ix = GroupBuilder::new()
+            .asset(asset)
+            .group(group)
+            .authority(context.payer.pubkey())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_ix, group_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &asset_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // When the group authority freezes the group.
+
+        let ix = FreezeGroupBuilder::new()
+            .group(group)
+            .authority(context.payer.pubkey())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Then the state of the group asset is unchanged.
+
+        let account = context
+            .banks_client
+            .get_account(group)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Asset::load(account.data.as_ref()).state, State::Unlocked);
+
+        // And the member of the group cannot be transferred.
+
+        let recipient = Keypair::new().pubkey();
+
+        let ix = TransferBuilder::new()
+            .asset(asset)
+            .signer(context.payer.pubkey())
+            .recipient(recipient)
+            .group(Some(group))
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(err, AssetError::LockedAsset);
+
+        // When the group authority thaws the group.
+
+        let thaw_ix = ThawGroupBuilder::new()
+            .group(group)
+            .authority(context.payer.pubkey())
+            .instruction();
+
+        let transfer_ix = TransferBuilder::new()
+            .asset(asset)
+            .signer(context.payer.pubkey())
+            .recipient(recipient)
+            .group(Some(group))
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[thaw_ix, transfer_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Then the member of the group can be transferred.
+
+        let account = context
+            .banks_client
+            .get_account(asset)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Asset::load(account.data.as_ref()).owner, recipient);
+    }
+}
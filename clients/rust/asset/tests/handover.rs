@@ -2,7 +2,8 @@
 pub mod utils;
 
 use nifty_asset::{
-    instructions::{CreateBuilder, HandoverBuilder},
+    extensions::PendingAuthority,
+    instructions::{AcceptHandoverBuilder, CreateBuilder, HandoverBuilder, ProposeHandoverBuilder},
     state::{Asset, Discriminator, State},
     ZeroCopy,
 };
@@ -93,4 +94,83 @@ mod handover {
 
         assert_instruction_error!(err, InstructionError::MissingRequiredSignature);
     }
+
+    #[tokio::test]
+    async fn two_step_handover() {
+        let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+            .start_with_context()
+            .await;
+
+        let asset_signer = Keypair::new();
+        let asset = asset_signer.pubkey();
+        let authority_signer = Keypair::new();
+        let authority = authority_signer.pubkey();
+        let new_authority_signer = Keypair::new();
+        let new_authority = new_authority_signer.pubkey();
+
+        // Given an asset.
+        let ix = CreateBuilder::new()
+            .asset(asset)
+            .authority(authority, false)
+            .owner(context.payer.pubkey())
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .name("name".to_string())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &asset_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // When we propose a new authority without its signature.
+        let ix = ProposeHandoverBuilder::new()
+            .asset(asset)
+            .authority(authority)
+            .new_authority(new_authority)
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &authority_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Then the handover is pending.
+        let account = context.banks_client.get_account(asset).await.unwrap();
+        let account_data = account.unwrap().data;
+
+        assert_eq!(Asset::load(&account_data).authority, authority);
+        let pending = Asset::get::<PendingAuthority>(&account_data).unwrap();
+        assert_eq!(pending.authority, &new_authority);
+
+        // And when the new authority accepts the handover.
+        let ix = AcceptHandoverBuilder::new()
+            .asset(asset)
+            .new_authority(new_authority)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &new_authority_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Then the asset has the new authority and no pending handover.
+        let account = context.banks_client.get_account(asset).await.unwrap();
+        let account_data = account.unwrap().data;
+
+        assert_eq!(Asset::load(&account_data).authority, new_authority);
+        let pending = Asset::get::<PendingAuthority>(&account_data).unwrap();
+        assert!(!pending.is_active());
+    }
 }
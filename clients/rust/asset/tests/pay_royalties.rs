@@ -0,0 +1,161 @@
+#![cfg(feature = "test-sbf")]
+pub mod utils;
+
+use nifty_asset::{
+    constraints::EmptyBuilder,
+    errors::AssetError,
+    extensions::{
+        CreatorsBuilder, ExtensionBuilder, RoyaltiesBuilder, RoyaltiesMode, RoyaltyPayment,
+    },
+    instructions::{CreateBuilder, PayRoyaltiesBuilder, TransferBuilder},
+    state::Asset,
+    types::{ExtensionInput, ExtensionType},
+    ZeroCopy,
+};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::AccountMeta,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+mod pay_royalties {
+    use super::*;
+
+    #[tokio::test]
+    async fn royalty_payment_cannot_be_reused() {
+        let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+            .start_with_context()
+            .await;
+
+        let asset_signer = Keypair::new();
+        let asset = asset_signer.pubkey();
+        let creator = Keypair::new().pubkey();
+
+        // Given an asset with royalties requiring a minimum payment to its creator.
+
+        let mut creators = CreatorsBuilder::default();
+        creators.add(&creator, false, 100);
+        let creators = creators.data();
+
+        let mut royalties = RoyaltiesBuilder::default();
+        royalties
+            .set(500, &mut EmptyBuilder::default())
+            .set_mode(RoyaltiesMode::Payment, 1_000_000);
+        let royalties = royalties.data();
+
+        let ix = CreateBuilder::new()
+            .asset(asset)
+            .authority(context.payer.pubkey(), false)
+            .owner(context.payer.pubkey())
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .name("name".to_string())
+            .extensions(vec![
+                ExtensionInput {
+                    extension_type: ExtensionType::Creators,
+                    length: creators.len() as u32,
+                    data: Some(creators),
+                },
+                ExtensionInput {
+                    extension_type: ExtensionType::Royalties,
+                    length: royalties.len() as u32,
+                    data: Some(royalties),
+                },
+            ])
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &asset_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // And the royalties of a sale are paid.
+
+        let ix = PayRoyaltiesBuilder::new()
+            .payer(context.payer.pubkey())
+            .asset(asset)
+            .price(100_000_000)
+            .add_remaining_account(AccountMeta::new(creator, false))
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let account = context
+            .banks_client
+            .get_account(asset)
+            .await
+            .unwrap()
+            .unwrap();
+        let payment = Asset::get::<RoyaltyPayment>(account.data.as_ref()).unwrap();
+
+        assert_eq!(*payment.amount, 5_000_000);
+
+        // When the asset is transferred.
+
+        let buyer = Keypair::new();
+
+        let ix = TransferBuilder::new()
+            .asset(asset)
+            .signer(context.payer.pubkey())
+            .recipient(buyer.pubkey())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Then the payment is consumed.
+
+        let account = context
+            .banks_client
+            .get_account(asset)
+            .await
+            .unwrap()
+            .unwrap();
+        let account_data = account.data.as_ref();
+
+        assert_eq!(Asset::load(account_data).owner, buyer.pubkey());
+        assert_eq!(
+            *Asset::get::<RoyaltyPayment>(account_data).unwrap().amount,
+            0
+        );
+
+        // And the asset cannot be transferred again without a new payment.
+
+        let ix = TransferBuilder::new()
+            .asset(asset)
+            .signer(buyer.pubkey())
+            .recipient(Keypair::new().pubkey())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &buyer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(err, AssetError::AssertionFailure);
+    }
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "test-sbf")]
+pub mod utils;
+
+use nifty_asset::{
+    errors::AssetError,
+    extensions::{AttributesBuilder, ExtensionBuilder, MasterEditionBuilder},
+    instructions::{CreateBuilder, MakeImmutableBuilder, PrintBuilder, RemoveBuilder},
+    types::{ExtensionInput, ExtensionType},
+};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+mod remove {
+    use super::*;
+
+    #[tokio::test]
+    async fn cannot_remove_immutable_extension() {
+        let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+            .start_with_context()
+            .await;
+
+        let asset_signer = Keypair::new();
+        let asset = asset_signer.pubkey();
+
+        // Given an asset with an attributes extension.
+
+        let mut attributes = AttributesBuilder::default();
+        attributes.add("hat", "nifty");
+        let data = attributes.data();
+
+        let ix = CreateBuilder::new()
+            .asset(asset)
+            .authority(context.payer.pubkey(), false)
+            .owner(context.payer.pubkey())
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .name("name".to_string())
+            .extensions(vec![ExtensionInput {
+                extension_type: ExtensionType::Attributes,
+                length: data.len() as u32,
+                data: Some(data),
+            }])
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &asset_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // And the extension is made immutable.
+
+        let ix = MakeImmutableBuilder::new()
+            .asset(asset)
+            .authority(context.payer.pubkey())
+            .extension_type(ExtensionType::Attributes)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // When we try to remove the extension.
+
+        let ix = RemoveBuilder::new()
+            .asset(asset)
+            .authority(context.payer.pubkey())
+            .recipient(context.payer.pubkey())
+            .extension_type(ExtensionType::Attributes)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        // Then we get an error.
+
+        assert_custom_error!(err, AssetError::ImmutableExtension);
+    }
+
+    #[tokio::test]
+    async fn cannot_remove_edition_from_print() {
+        let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+            .start_with_context()
+            .await;
+
+        let master_signer = Keypair::new();
+        let master = master_signer.pubkey();
+        let print_signer = Keypair::new();
+        let print = print_signer.pubkey();
+
+        // Given a master asset.
+
+        let mut master_edition = MasterEditionBuilder::default();
+        master_edition.set(Some(10));
+        let data = master_edition.data();
+
+        let ix = CreateBuilder::new()
+            .asset(master)
+            .authority(context.payer.pubkey(), false)
+            .owner(context.payer.pubkey())
+            .payer(Some(context.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .name("master".to_string())
+            .extensions(vec![ExtensionInput {
+                extension_type: ExtensionType::MasterEdition,
+                length: data.len() as u32,
+                data: Some(data),
+            }])
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &master_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // And a print of the master.
+
+        let ix = PrintBuilder::new()
+            .master(master)
+            .asset(print)
+            .authority(context.payer.pubkey())
+            .owner(context.payer.pubkey())
+            .payer(context.payer.pubkey())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &print_signer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // When we try to remove the edition extension from the print.
+
+        let ix = RemoveBuilder::new()
+            .asset(print)
+            .authority(context.payer.pubkey())
+            .recipient(context.payer.pubkey())
+            .extension_type(ExtensionType::Edition)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        // Then we get an error.
+
+        assert_custom_error!(err, AssetError::ExtensionDataInvalid);
+    }
+}
@@ -1,8 +1,8 @@
 #![cfg(feature = "test-sbf")]
 
 use nifty_asset::{
-    constraints::{Account, OperatorType, OwnedByBuilder},
-    extensions::{ExtensionBuilder, Royalties, RoyaltiesBuilder},
+    constraints::{Account, EmptyBuilder, OperatorType, OwnedByBuilder},
+    extensions::{ExtensionBuilder, Royalties, RoyaltiesBuilder, RoyaltiesMode},
     instructions::CreateBuilder,
     state::{Asset, Discriminator, Standard, State},
     types::{ExtensionInput, ExtensionType},
@@ -88,3 +88,121 @@ async fn create() {
         OperatorType::OwnedBy
     );
 }
+
+#[tokio::test]
+async fn create_with_payment_mode() {
+    let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a new keypair.
+
+    let asset = Keypair::new();
+
+    // And an extension requiring a minimum royalty payment.
+
+    let mut royalties = RoyaltiesBuilder::default();
+    royalties
+        .set(500, &mut EmptyBuilder::default())
+        .set_mode(RoyaltiesMode::Payment, 1_000_000);
+    let data = royalties.data();
+
+    // When we create a new asset.
+
+    let ix = CreateBuilder::new()
+        .asset(asset.pubkey())
+        .authority(context.payer.pubkey(), false)
+        .owner(context.payer.pubkey())
+        .payer(Some(context.payer.pubkey()))
+        .system_program(Some(system_program::id()))
+        .name("name".to_string())
+        .extensions(vec![ExtensionInput {
+            extension_type: ExtensionType::Royalties,
+            length: data.len() as u32,
+            data: Some(data),
+        }])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the royalties extension has the payment mode.
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let royalties = Asset::get::<Royalties>(account.data.as_ref()).unwrap();
+
+    assert_eq!(*royalties.basis_points, 500);
+    assert_eq!(royalties.mode(), Ok(RoyaltiesMode::Payment));
+    assert_eq!(*royalties.amount, 1_000_000);
+}
+
+#[tokio::test]
+async fn create_with_primary_rate() {
+    let mut context = ProgramTest::new("asset_program", nifty_asset::ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a new keypair.
+
+    let asset = Keypair::new();
+
+    // And an extension with a primary sale rate.
+
+    let mut royalties = RoyaltiesBuilder::default();
+    royalties
+        .set(500, &mut EmptyBuilder::default())
+        .set_mode(RoyaltiesMode::Constraint, 0)
+        .set_primary(1_000);
+    let data = royalties.data();
+
+    // When we create a new asset.
+
+    let ix = CreateBuilder::new()
+        .asset(asset.pubkey())
+        .authority(context.payer.pubkey(), false)
+        .owner(context.payer.pubkey())
+        .payer(Some(context.payer.pubkey()))
+        .system_program(Some(system_program::id()))
+        .name("name".to_string())
+        .extensions(vec![ExtensionInput {
+            extension_type: ExtensionType::Royalties,
+            length: data.len() as u32,
+            data: Some(data),
+        }])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the royalties extension uses the primary rate.
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let royalties = Asset::get::<Royalties>(account.data.as_ref()).unwrap();
+
+    assert_eq!(*royalties.basis_points, 500);
+    assert_eq!(royalties.primary_basis_points, Some(&1_000));
+    assert_eq!(royalties.rate(true), 1_000);
+    assert_eq!(royalties.rate(false), 500);
+}
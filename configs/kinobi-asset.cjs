@@ -84,6 +84,7 @@ kinobi.update(
               type: k.enumTypeNode([
                 k.enumEmptyVariantTypeNode("Uninitialized"),
                 k.enumEmptyVariantTypeNode("Asset"),
+                k.enumEmptyVariantTypeNode("RuleSet"),
               ]),
             }),
             // standard
@@ -130,6 +131,18 @@ kinobi.update(
                 k.enumEmptyVariantTypeNode("Proxy"),
                 k.enumEmptyVariantTypeNode("Properties"),
                 k.enumEmptyVariantTypeNode("Bucket"),
+                k.enumEmptyVariantTypeNode("Edition"),
+                k.enumEmptyVariantTypeNode("MasterEdition"),
+                k.enumEmptyVariantTypeNode("DelegateExpiry"),
+                k.enumEmptyVariantTypeNode("Delegates"),
+                k.enumEmptyVariantTypeNode("UpdateDelegate"),
+                k.enumEmptyVariantTypeNode("Authorities"),
+                k.enumEmptyVariantTypeNode("TransferHook"),
+                k.enumEmptyVariantTypeNode("Guards"),
+                k.enumEmptyVariantTypeNode("Rental"),
+                k.enumEmptyVariantTypeNode("PendingAuthority"),
+                k.enumEmptyVariantTypeNode("PrimarySale"),
+                k.enumEmptyVariantTypeNode("RoyaltyPayment"),
               ]),
             }),
             // delegate
@@ -386,6 +399,158 @@ kinobi.update(
                 }),
               ]),
             }),
+            // edition
+            k.definedTypeNode({
+              name: "edition",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "master",
+                  type: k.publicKeyTypeNode(),
+                }),
+                k.structFieldTypeNode({
+                  name: "number",
+                  type: k.numberTypeNode("u64"),
+                }),
+              ]),
+            }),
+            // master edition
+            k.definedTypeNode({
+              name: "masterEdition",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "supply",
+                  type: k.numberTypeNode("u64"),
+                }),
+                k.structFieldTypeNode({
+                  name: "maxSupply",
+                  type: k.numberTypeNode("u64"),
+                }),
+              ]),
+            }),
+            // expiry
+            k.definedTypeNode({
+              name: "expiry",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "delegate",
+                  type: k.publicKeyTypeNode(),
+                }),
+                k.structFieldTypeNode({
+                  name: "timestamp",
+                  type: k.numberTypeNode("i64"),
+                }),
+              ]),
+            }),
+            // delegate expiry
+            k.definedTypeNode({
+              name: "delegateExpiry",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "values",
+                  type: k.arrayTypeNode(
+                    k.definedTypeLinkNode("expiry"),
+                    k.remainderCountNode()
+                  ),
+                }),
+              ]),
+            }),
+            // delegates
+            k.definedTypeNode({
+              name: "delegates",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "values",
+                  type: k.arrayTypeNode(
+                    k.definedTypeLinkNode("delegate"),
+                    k.remainderCountNode()
+                  ),
+                }),
+              ]),
+            }),
+            // update delegate
+            k.definedTypeNode({
+              name: "updateDelegate",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "delegate",
+                  type: k.publicKeyTypeNode(),
+                }),
+                k.structFieldTypeNode({
+                  name: "extensionTypes",
+                  type: k.arrayTypeNode(
+                    k.definedTypeLinkNode("extensionType"),
+                    k.remainderCountNode()
+                  ),
+                }),
+              ]),
+            }),
+            // authorities
+            k.definedTypeNode({
+              name: "authorities",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "values",
+                  type: k.arrayTypeNode(
+                    k.publicKeyTypeNode(),
+                    k.remainderCountNode()
+                  ),
+                }),
+              ]),
+            }),
+            // transfer hook
+            k.definedTypeNode({
+              name: "transferHook",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "program",
+                  type: k.publicKeyTypeNode(),
+                }),
+              ]),
+            }),
+            // rental
+            k.definedTypeNode({
+              name: "rental",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "lender",
+                  type: k.publicKeyTypeNode(),
+                }),
+                k.structFieldTypeNode({
+                  name: "expiry",
+                  type: k.numberTypeNode("i64"),
+                }),
+              ]),
+            }),
+            // pending authority
+            k.definedTypeNode({
+              name: "pendingAuthority",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "authority",
+                  type: k.publicKeyTypeNode(),
+                }),
+              ]),
+            }),
+            // primary sale
+            k.definedTypeNode({
+              name: "primarySale",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "happened",
+                  type: k.numberTypeNode("u64"),
+                }),
+              ]),
+            }),
+            // royalty payment
+            k.definedTypeNode({
+              name: "royaltyPayment",
+              type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "amount",
+                  type: k.numberTypeNode("u64"),
+                }),
+              ]),
+            }),
             // type (for properties extension)
             k.definedTypeNode({
               name: "type",
@@ -405,6 +570,11 @@ kinobi.update(
 // Update instructions.
 kinobi.update(
   k.updateInstructionsVisitor({
+    acceptHandover: {
+      accounts: {
+        newAuthority: { defaultValue: k.identityValueNode() },
+      },
+    },
     allocate: {
       accounts: {
         systemProgram: {
@@ -422,6 +592,11 @@ kinobi.update(
       accounts: {
         owner: { defaultValue: k.identityValueNode() },
       },
+      arguments: {
+        expiry: {
+          defaultValue: k.noneValueNode(),
+        },
+      },
     },
     create: {
       accounts: {
@@ -443,6 +618,41 @@ kinobi.update(
         },
       },
     },
+    createMany: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("payer"),
+            ifTrue: k.publicKeyValueNode(
+              "11111111111111111111111111111111",
+              "systemProgram"
+            ),
+          }),
+        },
+      },
+    },
+    cancelHandover: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+      },
+    },
+    createRuleSet: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.publicKeyValueNode(
+            "11111111111111111111111111111111",
+            "systemProgram"
+          ),
+        },
+      },
+    },
+    executeAsWallet: {
+      accounts: {
+        owner: { defaultValue: k.identityValueNode() },
+      },
+    },
     handover: {
       accounts: {
         authority: { defaultValue: k.identityValueNode() },
@@ -458,6 +668,66 @@ kinobi.update(
         signer: { defaultValue: k.identityValueNode() },
       },
     },
+    makeImmutable: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+      },
+    },
+    lend: {
+      accounts: {
+        owner: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("payer"),
+            ifTrue: k.publicKeyValueNode(
+              "11111111111111111111111111111111",
+              "systemProgram"
+            ),
+          }),
+        },
+      },
+    },
+    payRoyalties: {
+      accounts: {
+        systemProgram: {
+          defaultValue: k.publicKeyValueNode(
+            "11111111111111111111111111111111",
+            "systemProgram"
+          ),
+        },
+      },
+    },
+    print: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+        owner: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.publicKeyValueNode(
+            "11111111111111111111111111111111",
+            "systemProgram"
+          ),
+        },
+      },
+    },
+    proposeHandover: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("payer"),
+            ifTrue: k.publicKeyValueNode(
+              "11111111111111111111111111111111",
+              "systemProgram"
+            ),
+          }),
+        },
+      },
+    },
+    recall: {
+      accounts: {
+        manager: { defaultValue: k.identityValueNode() },
+      },
+    },
     remove: {
       accounts: {
         authority: { defaultValue: k.identityValueNode() },
@@ -483,11 +753,35 @@ kinobi.update(
         signer: { defaultValue: k.identityValueNode() },
       },
     },
+    setExtensionAuthority: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("payer"),
+            ifTrue: k.publicKeyValueNode(
+              "11111111111111111111111111111111",
+              "systemProgram"
+            ),
+          }),
+        },
+      },
+      arguments: {
+        newAuthority: {
+          defaultValue: k.noneValueNode(),
+        },
+      },
+    },
     transfer: {
       accounts: {
         signer: { defaultValue: k.identityValueNode() },
       },
     },
+    transferMany: {
+      accounts: {
+        signer: { defaultValue: k.identityValueNode() },
+      },
+    },
     ungroup: {
       accounts: {
         authority: { defaultValue: k.identityValueNode() },
@@ -512,6 +806,20 @@ kinobi.update(
         },
       },
     },
+    updateRuleSet: {
+      accounts: {
+        authority: { defaultValue: k.identityValueNode() },
+        systemProgram: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("payer"),
+            ifTrue: k.publicKeyValueNode(
+              "11111111111111111111111111111111",
+              "systemProgram"
+            ),
+          }),
+        },
+      },
+    },
     write: {
       accounts: {
         systemProgram: {
@@ -593,13 +901,21 @@ kinobi.accept(
         "approve",
         "burn",
         "create",
+        "createMany",
+        "executeAsWallet",
         "group",
         "handover",
+        "lend",
         "lock",
+        "makeImmutable",
+        "payRoyalties",
+        "print",
         "remove",
         "resize",
         "revoke",
+        "setExtensionAuthority",
         "transfer",
+        "transferMany",
         "ungroup",
         "unlock",
         "unverify",
@@ -7,7 +7,7 @@ use solana_program::{
 
 use crate::{error::AssetError, processor};
 
-entrypoint!(process_instruction, 7);
+entrypoint!(process_instruction, 64);
 
 fn process_instruction<'a>(
     program_id: &'a Pubkey,
@@ -95,6 +95,18 @@ pub enum AssetError {
     /// 21 - Extension length invalid.
     #[error("Extension length invalid")]
     ExtensionLengthInvalid,
+
+    /// 22 - Delegate expired
+    #[error("Delegate expired")]
+    DelegateExpired,
+
+    /// 23 - Extension is immutable
+    #[error("Extension is immutable")]
+    ImmutableExtension,
+
+    /// 24 - Rental not expired
+    #[error("Rental not expired")]
+    RentalNotExpired,
 }
 
 impl PrintProgramError for AssetError {
@@ -5,6 +5,8 @@ use nifty_asset_types::{
 };
 use nitrate::Accounts;
 use shank::ShankInstruction;
+use solana_program::pubkey::Pubkey;
+use std::io::Read;
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankInstruction, Accounts)]
 #[rustfmt::skip]
@@ -34,10 +36,13 @@ pub enum Instruction {
     Create(MetadataInput),
 
     /// Approves a delegate to manage an asset.
+    /// 
+    /// The payer, system program and group accounts can be provided as (optional)
+    /// remaining accounts, in this order.
     #[account(0, writable, name="asset", desc = "Asset account")]
     #[account(1, signer, name="owner", desc = "The owner of the asset")]
     #[account(2, name="delegate", desc = "The delegate account")]
-    Approve(DelegateInput),
+    Approve(ApproveInput),
 
     /// Allocates an extension into an uninitialized asset (buffer) account.
     #[account(0, signer, writable, name="asset", desc = "Asset account")]
@@ -53,13 +58,20 @@ pub enum Instruction {
     /// Revokes a delegate.
     #[account(0, writable, name="asset", desc = "Asset account")]
     #[account(1, signer, name="signer", desc = "Owner of the asset or current delegate")]
+    #[account(2, optional, name="delegate", desc = "The delegate to revoke")]
     Revoke(DelegateInput),
 
     /// Transfers ownership of the aseet to a new public key.
+    /// 
+    /// The group account is required for every member of a group, since the transfer fails
+    /// when the group is locked or frozen.
+    ///
+    /// The rule set accounts and instructions sysvar can be provided as (optional) trailing
+    /// remaining accounts.
     #[account(0, writable, name="asset", desc = "Asset account")]
     #[account(1, signer, name="signer", desc = "Current owner of the asset or transfer delegate")]
     #[account(2, name="recipient", desc = "The recipient of the asset")]
-    #[account(3, optional, name="group", desc = "The asset defining the group, if applicable")]
+    #[account(3, optional, name="group", desc = "The asset defining the group, required when the asset is part of a group")]
     Transfer,
 
     /// Unlocks an asset.
@@ -74,7 +86,7 @@ pub enum Instruction {
 
     /// Updates an asset.
     #[account(0, writable, name="asset", desc = "Asset account")]
-    #[account(1, signer, name="authority", desc = "The authority of the asset")]
+    #[account(1, signer, name="authority", desc = "The authority or update delegate of the asset")]
     #[account(2, optional, writable, name="buffer", desc = "Extension buffer (uninitialized asset) account")]
     #[account(3, optional, name="group", desc = "The asset defining the group, if applicable")]
     #[account(4, optional, signer, writable, name="payer", desc = "The account paying for the storage fees")]
@@ -112,7 +124,7 @@ pub enum Instruction {
 
     /// Removes an extension from an asset.
     #[account(0, writable, name="asset", desc = "Asset account")]
-    #[account(1, signer, name="authority", desc = "The authority of the asset")]
+    #[account(1, signer, name="authority", desc = "The authority of the asset or extension")]
     #[account(2, optional, name="group", desc = "The asset defining the group, if applicable")]
     #[account(3, writable, name="recipient", desc = "The account receiving refunded rent")]
     Remove(ExtensionType),
@@ -123,6 +135,148 @@ pub enum Instruction {
     #[account(2, optional_signer, writable, name="payer", desc = "The account paying for the storage fees")]
     #[account(3, optional, name="system_program", desc = "The system program")]
     Resize(Strategy),
+
+    /// Prints a new edition of a master asset.
+    #[account(0, writable, name="master", desc = "Master asset account")]
+    #[account(1, signer, writable, name="asset", desc = "Asset account of the print")]
+    #[account(2, signer, name="authority", desc = "The authority of the master asset")]
+    #[account(3, name="owner", desc = "The owner of the print")]
+    #[account(4, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(5, name="system_program", desc = "The system program")]
+    Print,
+
+    /// Sets the authority of an extension.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="authority", desc = "The current authority of the extension")]
+    #[account(2, optional, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, name="system_program", desc = "The system program")]
+    SetExtensionAuthority(ExtensionAuthorityInput),
+
+    /// Makes an extension immutable.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="authority", desc = "The authority of the extension (or the asset account when uninitialized)")]
+    MakeImmutable(ExtensionType),
+
+    /// Transfers ownership of multiple assets to a new public key.
+    ///
+    /// The asset accounts (writable) and any group account required (read-only) are passed
+    /// as remaining accounts in any order, optionally followed by the rule set accounts and
+    /// instructions sysvar. Royalty payments in the same transaction are shared by all assets.
+    #[account(0, signer, name="signer", desc = "Current owner of the assets or transfer delegate")]
+    #[account(1, name="recipient", desc = "The recipient of the assets")]
+    TransferMany,
+
+    /// Creates multiple assets.
+    ///
+    /// The asset accounts are passed as remaining accounts, in the same order as the assets
+    /// on the instruction input.
+    #[account(0, optional_signer, name="authority", desc = "The authority of the assets")]
+    #[account(1, optional, writable, name="group", desc = "Asset account of the group")]
+    #[account(2, optional, signer, name="group_authority", desc = "Group authority for creating assets into a group")]
+    #[account(3, optional, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name="system_program", desc = "The system program")]
+    CreateMany(CreateManyInput),
+
+    /// Creates a rule set account.
+    ///
+    /// A rule set holds a constraint that assets can reference through an `External`
+    /// constraint, allowing multiple assets (or groups) to share the same rules.
+    #[account(0, signer, writable, name="rule_set", desc = "Rule set account")]
+    #[account(1, optional_signer, name="authority", desc = "The authority of the rule set")]
+    #[account(2, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, name="system_program", desc = "The system program")]
+    CreateRuleSet(RuleSetInput),
+
+    /// Updates the constraint of a rule set account.
+    #[account(0, writable, name="rule_set", desc = "Rule set account")]
+    #[account(1, signer, name="authority", desc = "The authority of the rule set")]
+    #[account(2, optional, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, name="system_program", desc = "The system program")]
+    UpdateRuleSet(RuleSetInput),
+
+    /// Pays the royalties of an asset sale to its creators.
+    ///
+    /// The creator accounts are passed as remaining accounts, in the same order as the
+    /// `Creators` extension. The payment is recorded on the asset and consumed by its next
+    /// transfer, which counts it towards the minimum amount when the royalties are enforced
+    /// by payment.
+    #[account(0, signer, writable, name="payer", desc = "The account paying the royalties")]
+    #[account(1, writable, name="asset", desc = "Asset account")]
+    #[account(2, optional, name="group", desc = "The asset defining the group, if applicable")]
+    #[account(3, name="system_program", desc = "The system program")]
+    PayRoyalties(PayRoyaltiesInput),
+
+    /// Lends an asset to a renter until the expiry timestamp.
+    ///
+    /// The asset is transferred to the renter and locked, so the renter cannot transfer
+    /// or burn it. Once the rental expires, anyone can return the asset to the lender
+    /// with the `Reclaim` instruction. Lending is subject to the transfer guard and
+    /// royalties of the asset; assets with a transfer hook cannot be lent.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="owner", desc = "The owner of the asset")]
+    #[account(2, name="renter", desc = "The account receiving the asset")]
+    #[account(3, optional, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="group", desc = "The asset defining the group, if applicable")]
+    Lend(LendInput),
+
+    /// Returns an expired rental to the lender.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    Reclaim,
+
+    /// Invokes a program signing as the wallet of an asset.
+    ///
+    /// The wallet is a PDA derived from `["wallet", asset]` that can hold SOL, tokens and
+    /// other assets. The accounts of the invoked instruction are passed as remaining accounts.
+    #[account(0, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="owner", desc = "The owner of the asset")]
+    #[account(2, writable, name="wallet", desc = "The wallet of the asset")]
+    #[account(3, name="program", desc = "The program to invoke")]
+    #[account(4, optional, name="group", desc = "The asset defining the group, required when the asset is part of a group")]
+    ExecuteAsWallet(ExecuteAsWalletInput),
+
+    /// Proposes a new authority for an asset.
+    ///
+    /// The handover completes when the proposed authority signs an `AcceptHandover`
+    /// instruction. Proposing a new authority replaces any pending proposal.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="authority", desc = "The authority of the asset")]
+    #[account(2, name="new_authority", desc = "The proposed authority of the asset")]
+    #[account(3, optional, signer, writable, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name="system_program", desc = "The system program")]
+    ProposeHandover,
+
+    /// Accepts a pending handover of an asset.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="new_authority", desc = "The proposed authority of the asset")]
+    AcceptHandover,
+
+    /// Cancels a pending handover of an asset.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="authority", desc = "The authority of the asset")]
+    CancelHandover,
+
+    /// Recalls an asset to an address chosen by its manager.
+    ///
+    /// The `Manager` delegate can recall managed and soulbound assets, even when they are
+    /// locked. Guards, royalties and transfer hooks do not apply to a recall.
+    #[account(0, writable, name="asset", desc = "Asset account")]
+    #[account(1, signer, name="manager", desc = "The manager delegate of the asset")]
+    #[account(2, name="recipient", desc = "The account receiving the asset")]
+    Recall,
+
+    /// Freezes a group, which locks all of its members.
+    ///
+    /// The freeze does not change the state of the group asset and it can only be released
+    /// by the authority with the `ThawGroup` instruction.
+    #[account(0, writable, name="group", desc = "Asset account of the group")]
+    #[account(1, signer, name="authority", desc = "The authority of the group")]
+    FreezeGroup,
+
+    /// Thaws a group frozen by its authority, which unlocks its members.
+    #[account(0, writable, name="group", desc = "Asset account of the group")]
+    #[account(1, signer, name="authority", desc = "The authority of the group")]
+    ThawGroup,
 }
 
 /// Input for the `allocate` instruction.
@@ -133,6 +287,61 @@ pub struct AllocateInput {
     pub extension: ExtensionInput,
 }
 
+/// Input for the `approve` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, Clone, Debug, PartialEq)]
+pub struct ApproveInput {
+    /// Delegate roles to approve.
+    pub delegate_input: DelegateInput,
+
+    /// Unix timestamp (in seconds) when the delegate expires.
+    pub expiry: Option<i64>,
+}
+
+impl BorshDeserialize for ApproveInput {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let delegate_input = DelegateInput::deserialize_reader(reader)?;
+
+        // the expiry is a trailing field, so the input can be serialized without it
+        let mut tag = [0u8; 1];
+        let expiry = if reader.read(&mut tag)? == 0 {
+            None
+        } else {
+            Option::<i64>::deserialize_reader(&mut tag.as_slice().chain(reader))?
+        };
+
+        Ok(Self {
+            delegate_input,
+            expiry,
+        })
+    }
+}
+
+/// Input data of an asset for the `create_many` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct AssetInput {
+    /// Name of the asset.
+    pub name: String,
+
+    /// The owner of the asset.
+    pub owner: Pubkey,
+}
+
+/// Input for the `create_many` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CreateManyInput {
+    /// Indicates the standard of the assets.
+    pub standard: Standard,
+
+    /// Indicates whether the assets are mutable or not.
+    pub mutable: bool,
+
+    /// Name and owner of each asset.
+    pub assets: Vec<AssetInput>,
+}
+
 /// Input for the `approve` and `revoke` instructions.
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
@@ -169,6 +378,20 @@ pub struct ExtensionInput {
     pub data: Option<Vec<u8>>,
 }
 
+/// Input for the `set_extension_authority` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ExtensionAuthorityInput {
+    /// Extension type to set the authority.
+    pub extension_type: ExtensionType,
+
+    /// The new authority of the extension.
+    ///
+    /// When the new authority is `None`, the extension is controlled by the authority
+    /// of the asset.
+    pub new_authority: Option<Pubkey>,
+}
+
 /// Input for the `create` instruction.
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -202,6 +425,38 @@ pub struct UpdateInput {
     pub extension: Option<ExtensionInput>,
 }
 
+/// Input for the `pay_royalties` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct PayRoyaltiesInput {
+    /// Sale price (in lamports) of the asset.
+    pub price: u64,
+}
+
+/// Input for the `lend` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct LendInput {
+    /// Unix timestamp (in seconds) when the rental expires.
+    pub expiry: i64,
+}
+
+/// Input for the `execute_as_wallet` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ExecuteAsWalletInput {
+    /// Instruction data of the invoked program.
+    pub data: Vec<u8>,
+}
+
+/// Input for the `create_rule_set` and `update_rule_set` instructions.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct RuleSetInput {
+    /// Serialized constraint of the rule set.
+    pub constraint: Vec<u8>,
+}
+
 /// Input for the `resize` instruction.
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
@@ -0,0 +1,73 @@
+use nifty_asset_types::{
+    extensions::{Extension, PendingAuthorityMut},
+    podded::{pod::PodBool, ZeroCopy},
+    state::{Asset, Discriminator},
+};
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::accounts::{AcceptHandover, Context},
+    require,
+};
+
+/// Accepts a pending handover of an asset.
+///
+/// The proposed authority becomes the authority of the asset and the pending handover is
+/// cleared.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` new_authority
+pub fn process_accept_handover(program_id: &Pubkey, ctx: Context<AcceptHandover>) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.new_authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "new_authority"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
+
+    // the asset could have been made immutable after the handover was proposed
+    require!(
+        <PodBool as Into<bool>>::into(asset.mutable),
+        AssetError::ImmutableAsset,
+        "asset"
+    );
+
+    let mut pending = Extension::get_mut::<PendingAuthorityMut>(extensions)
+        .filter(|pending| *pending.authority != Pubkey::default())
+        .ok_or_else(|| {
+            msg!("[ERROR] Asset has no pending handover");
+            AssetError::ExtensionNotFound
+        })?;
+
+    require!(
+        *pending.authority == *ctx.accounts.new_authority.key(),
+        AssetError::InvalidAuthority,
+        "new_authority"
+    );
+
+    asset.authority = *ctx.accounts.new_authority.key();
+    pending.clear();
+
+    Ok(())
+}
@@ -1,18 +1,31 @@
 use nifty_asset_types::{
+    extensions::{Action, DelegateExpiryMut, DelegatesMut, Expiry, Extension, ExtensionType},
     podded::ZeroCopy,
     state::{Asset, Delegate, Discriminator, NullablePubkey},
 };
+use nitrate::program::AccountInfo;
 use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     error::AssetError,
     instruction::{
         accounts::{Approve, Context},
-        DelegateInput,
+        ApproveInput, DelegateInput,
     },
+    processor::extend,
     require,
+    utils::{
+        assert_group_unlocked, assert_guard, get_caller, get_optional_account, get_owner_depth,
+        get_rule_sets, split_trailing_accounts,
+    },
 };
 
+/// Length of an expiry entry on the `DelegateExpiry` extension.
+const EXPIRY_LENGTH: usize = std::mem::size_of::<Expiry>();
+
+/// Length of a delegate entry on the `Delegates` extension.
+const DELEGATE_LENGTH: usize = std::mem::size_of::<Delegate>();
+
 /// Approves a delegate to manage an asset.
 ///
 /// ### Accounts:
@@ -20,11 +33,32 @@ use crate::{
 ///   0. `[writable]` asset
 ///   1. `[signer]` owner
 ///   2. `[]` delegate
+///
+/// The following optional accounts can be provided as remaining accounts, in order,
+/// using the program id as a placeholder for a missing account:
+///
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system_program
+///   5. `[optional]` group
+///
+/// The payer and system program are required when the account needs to be resized to
+/// store the delegate (or its expiry); the group is required when the asset belongs to
+/// a group.
+///
+/// The instructions sysvar and the rule set accounts can be provided as (optional) trailing
+/// accounts to evaluate guard constraints on the calling program or external rule sets.
 pub fn process_approve(
     program_id: &Pubkey,
     ctx: Context<Approve>,
-    args: DelegateInput,
+    remaining_accounts: &[AccountInfo],
+    args: ApproveInput,
 ) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
+    let payer = get_optional_account(remaining_accounts, 0);
+    let system_program = get_optional_account(remaining_accounts, 1);
+    let group = get_optional_account(remaining_accounts, 2);
+
     // account validation
 
     require!(
@@ -41,10 +75,27 @@ pub fn process_approve(
         "asset"
     );
 
-    let asset = Asset::load_mut(&mut data);
+    // Program invoking the instruction and rule sets referenced by `External` constraints.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
 
+    // the asset must satisfy its approve guard (if any)
+    assert_guard(
+        Action::Approve,
+        &data,
+        ctx.accounts.asset,
+        ctx.accounts.owner,
+        None,
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
+
+    // a parent asset cannot act as the owner of its children
     require!(
-        asset.owner == *ctx.accounts.owner.key(),
+        get_owner_depth(program_id, &asset.owner, ctx.accounts.owner, &[])? == Some(0),
         AssetError::InvalidAssetOwner,
         "owner"
     );
@@ -55,27 +106,155 @@ pub fn process_approve(
         "owner"
     );
 
+    // a locked group prevents its members from being delegated
+    if let Some(group_key) = asset.group.value() {
+        assert_group_unlocked(program_id, group_key, group)?;
+    }
+
     // Find the roles to apply
-    let roles = match args {
+    let roles = match args.delegate_input {
         DelegateInput::All => Delegate::ALL_ROLES_MASK,
         DelegateInput::Some { roles } => roles.iter().fold(0, |all, role| all | role.mask()),
     };
 
-    // if there is a delegate set and it matches the delegate account, then we
-    // only need to enable the roles; otherwise we are setting a new delegate
-    // and replacing the existing one (if any)
-    if let Some(delegate) = asset.delegate.value_mut() {
-        if *delegate.address == *ctx.accounts.delegate.key() {
-            delegate.roles |= roles;
-            return Ok(());
-        }
-    }
-
     let delegate = Delegate {
         address: NullablePubkey::new(*ctx.accounts.delegate.key()),
         roles,
     };
-    asset.delegate = delegate.into();
+
+    // when the header delegate is already in use, the delegate is added to the
+    // delegates list, which is created on first use
+    let mut delegates = Extension::get_mut::<DelegatesMut>(extensions);
+
+    // indicates whether the delegates list needs to be resized
+    let mut resize_list = false;
+    // the delegate replaced on the asset header (if any)
+    let mut replaced = None;
+
+    // if the delegate is already set (either on the header or on the delegates
+    // list), then we only need to enable the roles; otherwise we are setting a new
+    // delegate on the header if it is free or adding it to the delegates list;
+    // without a payer to create the list, the new delegate replaces the existing one
+    if let Some(current) = asset
+        .delegate
+        .value_mut()
+        .filter(|current| *current.address == *ctx.accounts.delegate.key())
+    {
+        current.roles |= roles;
+    } else if let Some(current) = delegates
+        .as_mut()
+        .and_then(|delegates| delegates.get(ctx.accounts.delegate.key()))
+    {
+        current.roles |= roles;
+    } else if asset.delegate.value().is_none() || (delegates.is_none() && payer.is_none()) {
+        replaced = asset.delegate.value().map(|current| *current.address);
+        asset.delegate = delegate.into();
+    } else if let Some(empty) = delegates
+        .as_mut()
+        .and_then(|delegates| delegates.get_empty())
+    {
+        *empty = delegate;
+    } else {
+        resize_list = true;
+    }
+
+    // the expiry of a replaced delegate no longer applies
+    if let Some(replaced) = replaced {
+        if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+            expiry.remove(&replaced);
+        }
+    }
+
+    // drop the borrow to resize the account
+    drop(data);
+
+    if resize_list {
+        add_delegate(ctx.accounts.asset, payer, system_program, delegate)?;
+    }
+
+    set_expiry(
+        ctx.accounts.asset,
+        payer,
+        system_program,
+        ctx.accounts.delegate.key(),
+        args.expiry,
+    )
+}
+
+/// Adds a delegate to the `Delegates` extension, resizing the account to fit the
+/// new entry. The extension is created if the asset does not have one.
+fn add_delegate(
+    asset: &AccountInfo,
+    payer: Option<&AccountInfo>,
+    system_program: Option<&AccountInfo>,
+    delegate: Delegate,
+) -> ProgramResult {
+    let offset = extend(
+        ExtensionType::Delegates,
+        DELEGATE_LENGTH,
+        asset,
+        payer,
+        system_program,
+    )?;
+
+    let mut data = asset.try_borrow_mut_data()?;
+    *Delegate::load_mut(&mut data[offset..offset + DELEGATE_LENGTH]) = delegate;
+
+    Ok(())
+}
+
+/// Sets the expiry of a delegate on the `DelegateExpiry` extension, resizing the
+/// account to fit a new entry if needed.
+///
+/// When no expiry is specified, any existing expiry of the delegate is cleared so
+/// it remains valid until revoked.
+fn set_expiry(
+    asset: &AccountInfo,
+    payer: Option<&AccountInfo>,
+    system_program: Option<&AccountInfo>,
+    delegate: &Pubkey,
+    timestamp: Option<i64>,
+) -> ProgramResult {
+    let mut data = asset.try_borrow_mut_data()?;
+    let mut expiry = Asset::get_mut::<DelegateExpiryMut>(&mut data);
+
+    let timestamp = match (timestamp, expiry.as_mut()) {
+        (Some(timestamp), Some(expiry)) => {
+            if let Some(entry) = expiry.get(delegate) {
+                entry.timestamp = timestamp;
+                return Ok(());
+            } else if let Some(entry) = expiry.get_empty() {
+                entry.delegate = *delegate;
+                entry.timestamp = timestamp;
+                return Ok(());
+            }
+            timestamp
+        }
+        (Some(timestamp), None) => timestamp,
+        (None, expiry) => {
+            if let Some(expiry) = expiry {
+                expiry.remove(delegate);
+            }
+            return Ok(());
+        }
+    };
+
+    // drop the borrow to resize the account
+    drop(data);
+
+    let offset = extend(
+        ExtensionType::DelegateExpiry,
+        EXPIRY_LENGTH,
+        asset,
+        payer,
+        system_program,
+    )?;
+
+    let mut data = asset.try_borrow_mut_data()?;
+    *Expiry::load_mut(&mut data[offset..offset + EXPIRY_LENGTH]) = Expiry {
+        delegate: *delegate,
+        timestamp,
+    };
 
     Ok(())
 }
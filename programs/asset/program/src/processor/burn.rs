@@ -1,8 +1,9 @@
 use nifty_asset_types::{
-    extensions::{Extension, Grouping, GroupingMut, Manager},
+    extensions::{Action, DelegateExpiry, Delegates, Extension, Grouping, GroupingMut, Manager},
     podded::ZeroCopy,
     state::{Asset, DelegateRole, Discriminator},
 };
+use nitrate::program::AccountInfo;
 use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
 use std::ops::Deref;
 
@@ -11,7 +12,10 @@ use crate::{
     error::AssetError,
     instruction::accounts::{Burn, Context},
     require,
-    utils::{assert_delegate, close_program_account},
+    utils::{
+        assert_delegate, assert_group_unlocked, assert_guard, close_program_account, get_caller,
+        get_owner_depth, get_rule_sets, split_trailing_accounts,
+    },
 };
 
 /// Burns an asset.
@@ -22,7 +26,20 @@ use crate::{
 ///   1. `[writable, signer]` signer
 ///   2. `[writable, optional]` recipient
 ///   3. `[writable, optional]` group
-pub fn process_burn(program_id: &Pubkey, ctx: Context<Burn>) -> ProgramResult {
+///
+/// When the asset is owned by another asset (nested asset), the signer must be the owner of
+/// the top-level parent and the parent chain must be provided as remaining accounts,
+/// starting with the parent of the asset.
+///
+/// The instructions sysvar and the rule set accounts can be provided as (optional) trailing
+/// accounts to evaluate guard constraints on the calling program or external rule sets.
+pub fn process_burn(
+    program_id: &Pubkey,
+    ctx: Context<Burn>,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
     require!(
         ctx.accounts.asset.owner() == program_id,
         ProgramError::IllegalOwner,
@@ -44,16 +61,44 @@ pub fn process_burn(program_id: &Pubkey, ctx: Context<Burn>) -> ProgramResult {
         "asset"
     );
 
+    // Program invoking the instruction and rule sets referenced by `External` constraints.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
+
+    // the asset must satisfy its burn guard (if any)
+    assert_guard(
+        Action::Burn,
+        &data,
+        ctx.accounts.asset,
+        ctx.accounts.signer,
+        None,
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
     let (asset, extensions) = data.split_at(Asset::LEN);
     let asset = Asset::load(asset);
 
     // Validate the signer is the owner or the burn delegate.
-    let is_allowed = asset.owner == *ctx.accounts.signer.key()
+    let is_allowed = get_owner_depth(
+        program_id,
+        &asset.owner,
+        ctx.accounts.signer,
+        remaining_accounts,
+    )?
+    .is_some()
         || assert_delegate(
-            &[
-                asset.delegate.value(),
-                Extension::get::<Manager>(extensions).map(|s| s.delegate),
-            ],
+            asset
+                .delegate
+                .value()
+                .into_iter()
+                .chain(Extension::get::<Manager>(extensions).map(|s| s.delegate))
+                .chain(
+                    Extension::get::<Delegates>(extensions)
+                        .iter()
+                        .flat_map(|delegates| delegates.values),
+                ),
+            Extension::get::<DelegateExpiry>(extensions).as_ref(),
             ctx.accounts.signer.key(),
             DelegateRole::Burn,
         )
@@ -81,6 +126,9 @@ pub fn process_burn(program_id: &Pubkey, ctx: Context<Burn>) -> ProgramResult {
             "group mismatch"
         );
 
+        // a locked group prevents its members from being burned
+        assert_group_unlocked(program_id, group, Some(group_asset))?;
+
         let mut group_data = group_asset.try_borrow_mut_data()?;
 
         // sanity check: this should not happen since the asset is referencing the group
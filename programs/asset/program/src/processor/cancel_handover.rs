@@ -0,0 +1,62 @@
+use nifty_asset_types::{
+    extensions::{Extension, PendingAuthorityMut},
+    podded::ZeroCopy,
+    state::{Asset, Discriminator},
+};
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::accounts::{CancelHandover, Context},
+    require,
+};
+
+/// Cancels a pending handover of an asset.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` authority
+pub fn process_cancel_handover(program_id: &Pubkey, ctx: Context<CancelHandover>) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load(asset);
+
+    require!(
+        asset.authority == *ctx.accounts.authority.key(),
+        AssetError::InvalidAuthority,
+        "authority"
+    );
+
+    let mut pending = Extension::get_mut::<PendingAuthorityMut>(extensions)
+        .filter(|pending| *pending.authority != Pubkey::default())
+        .ok_or_else(|| {
+            msg!("[ERROR] Asset has no pending handover");
+            AssetError::ExtensionNotFound
+        })?;
+
+    pending.clear();
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use nifty_asset_types::{
-    extensions::{on_create, Extension, ExtensionType, Proxy},
+    extensions::{on_create, Extension, ExtensionType, Manager, Proxy},
     podded::ZeroCopy,
     state::{Asset, Discriminator, Standard, DEFAULT_EXTENSION_COUNT},
 };
@@ -212,13 +212,19 @@ pub fn process_create(
 
     // make sure that a managed asset is created with the manager
     // extension; and vice versa, a non-managed asset is created
-    // without the manager extension
+    // without the manager extension (soulbound assets can optionally
+    // have a manager without delegate roles, which can only recall them)
     let has_manager = extensions
         .iter()
         .any(|extension| extension == &ExtensionType::Manager);
 
     require!(
-        matches!(args.standard, Standard::Managed) == has_manager,
+        match args.standard {
+            Standard::Managed => has_manager,
+            Standard::Soulbound => !Asset::get::<Manager>(&data)
+                .is_some_and(|manager| manager.delegate.has_active_roles()),
+            _ => !has_manager,
+        },
         AssetError::ExtensionDataInvalid,
         "{:?} asset + manager extension ({})",
         args.standard,
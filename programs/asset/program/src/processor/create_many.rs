@@ -0,0 +1,229 @@
+use nifty_asset_types::{
+    extensions::{Extension, GroupingMut},
+    podded::{pod::PodOption, ZeroCopy},
+    state::{Asset, Discriminator, Standard},
+};
+use nitrate::program::{system, AccountInfo};
+use solana_program::{
+    entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    err,
+    error::AssetError,
+    instruction::{
+        accounts::{Context, CreateMany},
+        CreateManyInput,
+    },
+    require,
+};
+
+/// Creates multiple assets.
+///
+/// The assets are created with the same authority, standard, mutable flag and group, while
+/// the name and owner are specified for each asset. Only assets without extensions can be
+/// created; asset accounts can be pre-allocated (owned by the program and uninitialized) or
+/// empty, in which case they are created using the payer account.
+///
+/// When a group is specified, the group is validated once and its size is incremented by
+/// the number of assets created.
+///
+/// ### Accounts:
+///
+///   0. `[optional_signer]` authority
+///   1. `[writable, optional]` group
+///   2. `[signer, optional]` group_authority
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system_program
+///   5. `[writable, signer]` asset accounts
+pub fn process_create_many(
+    program_id: &Pubkey,
+    ctx: Context<CreateMany>,
+    args: CreateManyInput,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        remaining_accounts.len() == args.assets.len(),
+        ProgramError::NotEnoughAccountKeys,
+        "expected {} asset accounts, got {}",
+        args.assets.len(),
+        remaining_accounts.len()
+    );
+
+    // managed and proxied assets require extensions
+    if matches!(args.standard, Standard::Managed | Standard::Proxied) {
+        return err!(
+            AssetError::ExtensionDataInvalid,
+            "{:?} assets cannot be created in batch",
+            args.standard
+        );
+    }
+
+    // validates the group (if there is one) once for all assets
+    if let Some(group) = ctx.accounts.group {
+        let group_authority = ctx
+            .accounts
+            .group_authority
+            .unwrap_or(ctx.accounts.authority);
+
+        require!(
+            group_authority.is_signer(),
+            ProgramError::MissingRequiredSignature,
+            "group_authority"
+        );
+
+        require!(
+            group.owner() == program_id,
+            ProgramError::IllegalOwner,
+            "group"
+        );
+
+        let mut group_data = group.try_borrow_mut_data()?;
+
+        require!(
+            group_data.len() >= Asset::LEN && group_data[0] == u8::from(Discriminator::Asset),
+            AssetError::Uninitialized,
+            "group"
+        );
+
+        let (group, extensions) = group_data.split_at_mut(Asset::LEN);
+        let group = Asset::load_mut(group);
+
+        // authority of the group must match the assets
+        require!(
+            group.authority == *ctx.accounts.authority.key(),
+            AssetError::InvalidAuthority,
+            "Group and asset authority mismatch"
+        );
+
+        let grouping = if let Some(grouping) = Extension::get_mut::<GroupingMut>(extensions) {
+            grouping
+        } else {
+            return err!(
+                AssetError::ExtensionNotFound,
+                "Missing required [Grouping] extension"
+            );
+        };
+
+        // if the signing authority doesn't match the group authority
+        if *group_authority.key() != group.authority {
+            // then the authority must match the grouping delegate
+            if let Some(delegate) = grouping.delegate.value() {
+                require!(
+                    **delegate == *group_authority.key(),
+                    AssetError::InvalidAuthority,
+                    "Group authority or delegate mismatch"
+                );
+            } else {
+                return err!(
+                    AssetError::InvalidAuthority,
+                    "Invalid group authority delegate"
+                );
+            };
+        }
+
+        let size = grouping
+            .size
+            .checked_add(args.assets.len() as u64)
+            .ok_or(AssetError::ExtensionDataInvalid)?;
+
+        // group size validation
+        if let Some(max_size) = grouping.max_size.value() {
+            require!(
+                size <= **max_size,
+                AssetError::ExtensionDataInvalid,
+                "Maximum group size reached"
+            );
+        }
+
+        // the group size is incremented once for all assets
+        *grouping.size = size;
+    }
+
+    for (account, input) in remaining_accounts.iter().zip(args.assets) {
+        require!(
+            account.is_signer(),
+            ProgramError::MissingRequiredSignature,
+            "asset \"{}\"",
+            account.key()
+        );
+
+        if account.data_is_empty() {
+            let payer = ctx.accounts.payer.ok_or_else(|| {
+                msg!("[ERROR] Missing payer account");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+
+            require!(
+                payer.is_signer(),
+                ProgramError::MissingRequiredSignature,
+                "payer"
+            );
+
+            let system_program = ctx.accounts.system_program.ok_or_else(|| {
+                msg!("[ERROR] Missing system program account");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+
+            require!(
+                system_program.key() == &system_program::ID,
+                ProgramError::IncorrectProgramId,
+                "system_program"
+            );
+
+            system::create_account(
+                payer,
+                account,
+                Rent::get()?.minimum_balance(Asset::LEN),
+                Asset::LEN as u64,
+                program_id,
+            );
+        } else {
+            require!(
+                account.owner() == program_id,
+                ProgramError::IllegalOwner,
+                "asset \"{}\"",
+                account.key()
+            );
+
+            require!(
+                account.data_len() == Asset::LEN,
+                AssetError::InvalidAccountLength,
+                "asset \"{}\"",
+                account.key()
+            );
+        }
+
+        let mut data = account.try_borrow_mut_data()?;
+
+        // make sure that the asset is not already initialized
+        require!(
+            data[0] == u8::from(Discriminator::Uninitialized),
+            AssetError::AlreadyInitialized,
+            "asset \"{}\"",
+            account.key()
+        );
+
+        // creates the asset
+
+        let asset = Asset::load_mut(&mut data);
+
+        asset.discriminator = Discriminator::Asset;
+        asset.standard = args.standard;
+        asset.mutable = args.mutable.into();
+        asset.owner = input.owner;
+        asset.authority = *ctx.accounts.authority.key();
+        asset.name = input.name.into();
+
+        if let Some(group) = ctx.accounts.group {
+            asset.group = PodOption::new(group.key().into());
+        }
+    }
+
+    msg!("Created {} asset(s)", remaining_accounts.len());
+
+    Ok(())
+}
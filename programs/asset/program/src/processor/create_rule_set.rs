@@ -0,0 +1,81 @@
+use nifty_asset_types::{
+    podded::ZeroCopy,
+    state::{Discriminator, RuleSet},
+};
+use nitrate::program::system;
+use solana_program::{
+    entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::AssetError,
+    instruction::{
+        accounts::{Context, CreateRuleSet},
+        RuleSetInput,
+    },
+    require,
+};
+
+/// Creates a rule set account.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` rule_set
+///   1. `[optional_signer]` authority
+///   2. `[writable, signer]` payer
+///   3. `[]` system_program
+pub fn process_create_rule_set(
+    program_id: &Pubkey,
+    ctx: Context<CreateRuleSet>,
+    args: RuleSetInput,
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.rule_set.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "rule_set"
+    );
+
+    require!(
+        ctx.accounts.rule_set.data_is_empty(),
+        AssetError::AlreadyInitialized,
+        "rule_set"
+    );
+
+    require!(
+        ctx.accounts.payer.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "payer"
+    );
+
+    require!(
+        ctx.accounts.system_program.key() == &system_program::ID,
+        ProgramError::IncorrectProgramId,
+        "system_program"
+    );
+
+    super::validate_rule_set_constraint(&args.constraint)?;
+
+    let space = RuleSet::LEN + args.constraint.len();
+
+    system::create_account(
+        ctx.accounts.payer,
+        ctx.accounts.rule_set,
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        program_id,
+    );
+
+    let mut data = ctx.accounts.rule_set.try_borrow_mut_data()?;
+    let (rule_set, constraint) = data.split_at_mut(RuleSet::LEN);
+
+    let rule_set = RuleSet::load_mut(rule_set);
+    rule_set.discriminator = Discriminator::RuleSet;
+    rule_set.authority = *ctx.accounts.authority.key();
+
+    constraint.copy_from_slice(&args.constraint);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use nifty_asset_types::{
+    podded::ZeroCopy,
+    state::{Asset, Discriminator, State},
+};
+use nitrate::program::AccountInfo;
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::{
+        accounts::{Context, ExecuteAsWallet},
+        ExecuteAsWalletInput,
+    },
+    require,
+    utils::{assert_group_unlocked, get_owner_depth, invoke_as_wallet},
+};
+
+/// Invokes a program signing as the wallet of an asset.
+///
+/// The wallet is a PDA derived from `["wallet", asset]`, which can hold SOL, tokens and
+/// other assets. Only the owner of the asset can sign for its wallet, so the contents of
+/// the wallet follow the asset on transfers. Locked assets (e.g., rented assets) and
+/// members of a locked or frozen group cannot use their wallet.
+///
+/// ### Accounts:
+///
+///   0. `[]` asset
+///   1. `[signer]` owner
+///   2. `[writable]` wallet
+///   3. `[]` program
+///   4. `[optional]` group
+///
+/// When the asset is part of a group, the group account is required.
+///
+/// The accounts of the invoked instruction are passed as remaining accounts.
+pub fn process_execute_as_wallet(
+    program_id: &Pubkey,
+    ctx: Context<ExecuteAsWallet>,
+    remaining_accounts: &[AccountInfo],
+    args: ExecuteAsWalletInput,
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.owner.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "owner"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    {
+        let data = ctx.accounts.asset.try_borrow_data()?;
+
+        require!(
+            data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+            AssetError::Uninitialized,
+            "asset"
+        );
+
+        let asset = Asset::load(&data);
+
+        require!(
+            get_owner_depth(program_id, &asset.owner, ctx.accounts.owner, &[])? == Some(0),
+            AssetError::InvalidAssetOwner,
+            "owner"
+        );
+
+        require!(
+            asset.state != State::Locked,
+            AssetError::LockedAsset,
+            "asset is locked"
+        );
+
+        // a locked (or frozen) group locks the wallets of its members
+        if let Some(group) = asset.group.value() {
+            assert_group_unlocked(program_id, group, ctx.accounts.group)?;
+        }
+    }
+
+    let (wallet, bump) = Asset::find_wallet_address(program_id, ctx.accounts.asset.key());
+
+    require!(
+        ctx.accounts.wallet.key() == &wallet,
+        ProgramError::InvalidSeeds,
+        "wallet"
+    );
+
+    invoke_as_wallet(
+        ctx.accounts.program,
+        ctx.accounts.asset.key(),
+        &wallet,
+        bump,
+        remaining_accounts,
+        &args.data,
+    )
+}
@@ -0,0 +1,55 @@
+use nifty_asset_types::{
+    podded::ZeroCopy,
+    state::{Asset, Discriminator},
+};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::accounts::{Context, FreezeGroup},
+    require,
+    utils::set_group_frozen,
+};
+
+/// Freezes a group, which locks all of its members.
+///
+/// The freeze is kept separate from the state of the group asset, so freezing a group
+/// does not lock the group asset itself and only the authority can release it with the
+/// `ThawGroup` instruction.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` group
+///   1. `[signer]` authority
+pub fn process_freeze_group(program_id: &Pubkey, ctx: Context<FreezeGroup>) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.group.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "group"
+    );
+
+    let mut data = ctx.accounts.group.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "group"
+    );
+
+    require!(
+        Asset::load(&data).authority == *ctx.accounts.authority.key(),
+        AssetError::InvalidAuthority,
+        "authority"
+    );
+
+    // fails when the asset is not a group
+    set_group_frozen(&mut data, true)
+}
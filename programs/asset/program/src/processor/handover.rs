@@ -1,4 +1,5 @@
 use nifty_asset_types::{
+    extensions::{Extension, PendingAuthorityMut},
     podded::{pod::PodBool, ZeroCopy},
     state::{Asset, Discriminator},
 };
@@ -12,6 +13,9 @@ use crate::{
 
 /// Handover an asset to a new authority.
 ///
+/// Both authorities sign the same transaction, so the handover completes immediately and
+/// any pending two-step handover is cancelled.
+///
 /// ### Accounts:
 ///
 ///   0. `[writable]` asset
@@ -46,7 +50,8 @@ pub fn process_handover(program_id: &Pubkey, ctx: Context<Handover>) -> ProgramR
         "asset"
     );
 
-    let asset = Asset::load_mut(&mut data);
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
 
     require!(
         asset.authority == *ctx.accounts.authority.key(),
@@ -62,5 +67,10 @@ pub fn process_handover(program_id: &Pubkey, ctx: Context<Handover>) -> ProgramR
 
     asset.authority = *ctx.accounts.new_authority.key();
 
+    // clears the pending handover (if any)
+    if let Some(mut pending) = Extension::get_mut::<PendingAuthorityMut>(extensions) {
+        pending.clear();
+    }
+
     Ok(())
 }
@@ -0,0 +1,243 @@
+use nifty_asset_types::{
+    constraints::{Assertion, Context as ConstraintContext},
+    extensions::{
+        Action, DelegateExpiryMut, DelegatesMut, Extension, ExtensionDataMut, ExtensionType,
+        RentalMut, Royalties, RoyaltyPaymentMut,
+    },
+    podded::{pod::PodOption, ZeroCopy},
+    state::{Asset, Delegate, Discriminator, Standard, State},
+};
+use nitrate::program::AccountInfo;
+use solana_program::{
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    err,
+    error::AssetError,
+    instruction::{
+        accounts::{Context, Lend},
+        LendInput,
+    },
+    process_royalties,
+    processor::extend,
+    require,
+    utils::{
+        assert_group_unlocked, assert_guard, assert_not_self_nested, get_caller, get_owner_depth,
+        get_royalty_payments, get_rule_sets, is_primary_sale, split_trailing_accounts,
+    },
+};
+
+/// Length of the `Rental` extension data.
+const RENTAL_LENGTH: usize = std::mem::size_of::<Pubkey>() + std::mem::size_of::<i64>();
+
+/// Lends an asset to a renter until the expiry timestamp.
+///
+/// The asset is transferred to the renter and locked, so the lock checks prevent the renter
+/// from transferring or burning the asset. The asset cannot be unlocked while the rental is
+/// active; once it expires, the `Reclaim` instruction returns the asset to the lender.
+///
+/// Lending an asset is subject to the same transfer guard and royalties (either its own or
+/// the ones of its group) as a transfer to the renter. Since the rental is not a sale, it
+/// does not end the primary sale of the asset. Assets with a `TransferHook` extension
+/// cannot be lent, since the hook is not invoked when the asset returns to the lender.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` owner
+///   2. `[]` renter
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system_program
+///   5. `[optional]` group
+///
+/// The rule set accounts and the instructions sysvar can be provided as (optional) trailing
+/// accounts to evaluate constraints on external rule sets or the calling program, and to
+/// determine any royalty payments in the same transaction.
+pub fn process_lend(
+    program_id: &Pubkey,
+    ctx: Context<Lend>,
+    args: LendInput,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
+    // account validation
+
+    require!(
+        ctx.accounts.owner.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "owner"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    require!(
+        args.expiry > Clock::get()?.unix_timestamp,
+        ProgramError::InvalidArgument,
+        "expiry must be in the future"
+    );
+
+    assert_not_self_nested(program_id, ctx.accounts.asset.key(), ctx.accounts.renter)?;
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    if Asset::contains(ExtensionType::TransferHook, &data) {
+        return err!(
+            AssetError::ExtensionDataInvalid,
+            "asset has a transfer hook and cannot be lent"
+        );
+    }
+
+    // Program invoking the instruction and rule sets referenced by `External` constraints.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
+
+    // lending an asset transfers it to the renter, so the asset must satisfy its
+    // transfer guard (if any)
+    assert_guard(
+        Action::Transfer,
+        &data,
+        ctx.accounts.asset,
+        ctx.accounts.owner,
+        Some(ctx.accounts.renter),
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
+    // royalty payments for the asset (if any), either recorded by `PayRoyalties` or funded
+    // by the owner or the renter in the same transaction
+    let mut payments = get_royalty_payments(
+        program_id,
+        trailing.sysvar_instructions,
+        &[ctx.accounts.owner.key(), ctx.accounts.renter.key()],
+    )?;
+    payments.set_paid(&data);
+
+    let is_primary_sale = is_primary_sale(&data);
+
+    // the asset must satisfy its royalties (if any), falling back to the group royalties
+    let royalties_checked = process_royalties!(
+        ctx.accounts.asset,
+        ctx.accounts.owner,
+        ctx.accounts.renter,
+        caller,
+        &rule_sets,
+        &mut payments,
+        is_primary_sale,
+        &data,
+        &data
+    );
+
+    let asset = Asset::load(&data);
+
+    // a parent asset cannot act as the owner of its children
+    require!(
+        get_owner_depth(program_id, &asset.owner, ctx.accounts.owner, &[])? == Some(0),
+        AssetError::InvalidAssetOwner,
+        "owner"
+    );
+
+    require!(
+        asset.owner != *ctx.accounts.renter.key(),
+        ProgramError::InvalidArgument,
+        "renter must not be the owner"
+    );
+
+    // Cannot lend soulbound assets.
+    require!(
+        asset.standard != Standard::Soulbound,
+        AssetError::CannotTransferSoulbound,
+        "soulbound asset"
+    );
+
+    // a locked group prevents its members from being lent
+    if let Some(group) = asset.group.value() {
+        assert_group_unlocked(program_id, group, ctx.accounts.group)?;
+
+        if !royalties_checked {
+            let group_data = ctx
+                .accounts
+                .group
+                .ok_or(ProgramError::NotEnoughAccountKeys)?
+                .try_borrow_data()?;
+
+            process_royalties!(
+                ctx.accounts.asset,
+                ctx.accounts.owner,
+                ctx.accounts.renter,
+                caller,
+                &rule_sets,
+                &mut payments,
+                is_primary_sale,
+                &data,
+                &group_data
+            );
+        }
+    }
+
+    // the asset is loaded again to be updated
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
+
+    // Transfer the asset to the renter and lock it.
+    asset.owner = *ctx.accounts.renter.key();
+    asset.state = State::Locked;
+
+    // Clear the delegate.
+    asset.delegate = PodOption::new(Delegate::default());
+
+    // Clear the delegates list (if any).
+    if let Some(mut delegates) = Extension::get_mut::<DelegatesMut>(extensions) {
+        delegates.clear();
+    }
+
+    // Clear the delegate expiries (if any).
+    if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+        expiry.clear();
+    }
+
+    // Consume the royalty payment (if any).
+    if let Some(mut payment) = Extension::get_mut::<RoyaltyPaymentMut>(extensions) {
+        payment.clear();
+    }
+
+    // sets the rental; the extension is reused when the asset was lent before
+    if let Some(rental) = Extension::get_mut::<RentalMut>(extensions) {
+        *rental.lender = *ctx.accounts.owner.key();
+        *rental.expiry = args.expiry;
+    } else {
+        // drop the borrow to resize the account
+        drop(data);
+
+        let offset = extend(
+            ExtensionType::Rental,
+            RENTAL_LENGTH,
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+
+        let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+        let rental = RentalMut::from_bytes_mut(&mut data[offset..offset + RENTAL_LENGTH]);
+        *rental.lender = *ctx.accounts.owner.key();
+        *rental.expiry = args.expiry;
+    }
+
+    Ok(())
+}
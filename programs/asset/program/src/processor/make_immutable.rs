@@ -0,0 +1,99 @@
+use nifty_asset_types::{
+    extensions::{Extension, ExtensionType},
+    podded::{pod::PodBool, ZeroCopy},
+    state::{Asset, Discriminator},
+};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    err,
+    error::AssetError,
+    instruction::accounts::{Context, MakeImmutable},
+    require,
+    utils::assert_extension_authority,
+};
+
+/// Makes an extension immutable.
+///
+/// Once an extension is immutable, its data cannot be updated (or overwritten) and the
+/// extension cannot be removed from the asset. This is independent of the `mutable` flag
+/// of the asset, so other extensions can still be modified.
+///
+/// On an initialized asset, the signer must be the authority of the extension; when the
+/// asset is not yet initialized (e.g., the extension was allocated before `Create`), the
+/// asset account itself must sign as the authority.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` authority
+pub fn process_make_immutable(
+    program_id: &Pubkey,
+    ctx: Context<MakeImmutable>,
+    extension_type: ExtensionType,
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN,
+        AssetError::InvalidAccountLength,
+        "asset"
+    );
+
+    // extensions managed by the program cannot be made immutable
+    if matches!(
+        extension_type,
+        ExtensionType::DelegateExpiry | ExtensionType::Delegates | ExtensionType::Authorities
+    ) {
+        return err!(
+            AssetError::ExtensionDataInvalid,
+            "invalid extension type: {:?}",
+            extension_type
+        );
+    }
+
+    let (extension, offset) =
+        Asset::get_extension(extension_type, &data).ok_or(AssetError::ExtensionNotFound)?;
+
+    if data[0] == u8::from(Discriminator::Asset) {
+        require!(
+            <PodBool as Into<bool>>::into(Asset::load(&data).mutable),
+            AssetError::ImmutableAsset,
+            "asset"
+        );
+
+        assert_extension_authority(&data, extension, ctx.accounts.authority.key())?;
+    } else {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.asset.key(),
+            AssetError::InvalidAuthority,
+            "authority"
+        );
+        // the extension must have all its data written
+        require!(
+            offset + extension.length() as usize <= data.len(),
+            AssetError::IncompleteExtensionData,
+            "incomplete [{:?}] extension data",
+            extension_type
+        );
+    }
+
+    let extension = Extension::load_mut(&mut data[offset - Extension::LEN..offset]);
+    extension.set_flags(extension.flags() | Extension::IMMUTABLE);
+
+    Ok(())
+}
@@ -1,29 +1,52 @@
+mod accept_handover;
 mod allocate;
 mod approve;
 mod burn;
+mod cancel_handover;
 mod close;
 mod create;
+mod create_many;
+mod create_rule_set;
+mod execute_as_wallet;
+mod freeze_group;
 mod group;
 mod handover;
+mod lend;
 mod lock;
+mod make_immutable;
+mod pay_royalties;
+mod print;
+mod propose_handover;
+mod recall;
+mod reclaim;
 mod remove;
 mod resize;
 mod revoke;
+mod set_extension_authority;
+mod thaw_group;
 mod transfer;
+mod transfer_many;
 mod ungroup;
 mod unlock;
 mod unverify;
 mod update;
+mod update_rule_set;
 mod verify;
 mod write;
 
 use borsh::BorshDeserialize;
-use nifty_asset_types::state::{Discriminator, Standard, State};
+use nifty_asset_types::{
+    constraints::{Operator, OperatorType},
+    extensions::{Extension, ExtensionType},
+    podded::ZeroCopy,
+    state::{Asset, Discriminator, Standard, State},
+};
 use nitrate::program::{system, AccountInfo};
 use solana_program::{
     entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
     msg,
     program_error::ProgramError,
+    program_memory::sol_memmove,
     pubkey::Pubkey,
     rent::Rent,
     system_program,
@@ -35,8 +58,11 @@ use crate::{
     error::AssetError,
     instruction::{
         accounts::{
-            Allocate, Approve, Burn, Close, Create, Group, Handover, Lock, Remove, Resize, Revoke,
-            Transfer, Ungroup, Unlock, Unverify, Update, Verify, Write,
+            AcceptHandover, Allocate, Approve, Burn, CancelHandover, Close, Create, CreateMany,
+            CreateRuleSet, ExecuteAsWallet, FreezeGroup, Group, Handover, Lend, Lock,
+            MakeImmutable, PayRoyalties, Print, ProposeHandover, Recall, Reclaim, Remove, Resize,
+            Revoke, SetExtensionAuthority, ThawGroup, Transfer, TransferMany, Ungroup, Unlock,
+            Unverify, Update, UpdateRuleSet, Verify, Write,
         },
         Instruction,
     },
@@ -57,26 +83,43 @@ pub fn process_instruction(
             instruction,
             Instruction::Approve(_)
                 | Instruction::Burn
+                | Instruction::Lend(_)
                 | Instruction::Lock
                 | Instruction::Revoke(_)
                 | Instruction::Transfer
+                | Instruction::TransferMany
         ) {
             return err!(AssetError::LockedAsset, "Asset \"{}\" is locked", account);
         }
     }
 
     match instruction {
+        Instruction::AcceptHandover => {
+            msg!("Instruction: AcceptHandover");
+            accept_handover::process_accept_handover(program_id, AcceptHandover::context(accounts)?)
+        }
         Instruction::Allocate(args) => {
             msg!("Instruction: Allocate");
             allocate::process_allocate(program_id, Allocate::context(accounts)?, args)
         }
         Instruction::Approve(args) => {
             msg!("Instruction: Approve");
-            approve::process_approve(program_id, Approve::context(accounts)?, args)
+            // accounts after the instruction accounts are optional accounts
+            approve::process_approve(
+                program_id,
+                Approve::context(accounts)?,
+                &accounts[3..],
+                args,
+            )
         }
         Instruction::Burn => {
             msg!("Instruction: Burn");
-            burn::process_burn(program_id, Burn::context(accounts)?)
+            // accounts after the instruction accounts are the parents of a nested asset
+            burn::process_burn(program_id, Burn::context(accounts)?, &accounts[4..])
+        }
+        Instruction::CancelHandover => {
+            msg!("Instruction: CancelHandover");
+            cancel_handover::process_cancel_handover(program_id, CancelHandover::context(accounts)?)
         }
         Instruction::Close => {
             msg!("Instruction: Close");
@@ -86,6 +129,38 @@ pub fn process_instruction(
             msg!("Instruction: Create");
             create::process_create(program_id, Create::context(accounts)?, args)
         }
+        Instruction::CreateMany(args) => {
+            msg!("Instruction: CreateMany");
+            // accounts after the instruction accounts are the assets to create
+            create_many::process_create_many(
+                program_id,
+                CreateMany::context(accounts)?,
+                args,
+                &accounts[5..],
+            )
+        }
+        Instruction::CreateRuleSet(args) => {
+            msg!("Instruction: CreateRuleSet");
+            create_rule_set::process_create_rule_set(
+                program_id,
+                CreateRuleSet::context(accounts)?,
+                args,
+            )
+        }
+        Instruction::ExecuteAsWallet(args) => {
+            msg!("Instruction: ExecuteAsWallet");
+            // accounts after the instruction accounts are passed to the invoked program
+            execute_as_wallet::process_execute_as_wallet(
+                program_id,
+                ExecuteAsWallet::context(accounts)?,
+                &accounts[5..],
+                args,
+            )
+        }
+        Instruction::FreezeGroup => {
+            msg!("Instruction: FreezeGroup");
+            freeze_group::process_freeze_group(program_id, FreezeGroup::context(accounts)?)
+        }
         Instruction::Group => {
             msg!("Instruction: Group");
             group::process_group(program_id, Group::context(accounts)?)
@@ -94,13 +169,54 @@ pub fn process_instruction(
             msg!("Instruction: Handover");
             handover::process_handover(program_id, Handover::context(accounts)?)
         }
+        Instruction::Lend(args) => {
+            msg!("Instruction: Lend");
+            lend::process_lend(program_id, Lend::context(accounts)?, args, &accounts[6..])
+        }
         Instruction::Lock => {
             msg!("Instruction: Lock");
-            lock::process_lock(program_id, Lock::context(accounts)?)
+            lock::process_lock(program_id, Lock::context(accounts)?, &accounts[2..])
+        }
+        Instruction::MakeImmutable(args) => {
+            msg!("Instruction: MakeImmutable");
+            make_immutable::process_make_immutable(
+                program_id,
+                MakeImmutable::context(accounts)?,
+                args,
+            )
+        }
+        Instruction::PayRoyalties(args) => {
+            msg!("Instruction: PayRoyalties");
+            // accounts after the instruction accounts are the creators
+            pay_royalties::process_pay_royalties(
+                program_id,
+                PayRoyalties::context(accounts)?,
+                args,
+                &accounts[4..],
+            )
+        }
+        Instruction::Print => {
+            msg!("Instruction: Print");
+            print::process_print(program_id, Print::context(accounts)?)
+        }
+        Instruction::ProposeHandover => {
+            msg!("Instruction: ProposeHandover");
+            propose_handover::process_propose_handover(
+                program_id,
+                ProposeHandover::context(accounts)?,
+            )
+        }
+        Instruction::Recall => {
+            msg!("Instruction: Recall");
+            recall::process_recall(program_id, Recall::context(accounts)?)
+        }
+        Instruction::Reclaim => {
+            msg!("Instruction: Reclaim");
+            reclaim::process_reclaim(program_id, Reclaim::context(accounts)?)
         }
         Instruction::Remove(args) => {
             msg!("Instruction: Remove");
-            remove::process_remove(program_id, Remove::context(accounts)?, args)
+            remove::process_remove(program_id, Remove::context(accounts)?, &accounts[4..], args)
         }
         Instruction::Resize(args) => {
             msg!("Instruction: Resize");
@@ -110,9 +226,32 @@ pub fn process_instruction(
             msg!("Instruction: Revoke");
             revoke::process_revoke(program_id, Revoke::context(accounts)?, args)
         }
+        Instruction::SetExtensionAuthority(args) => {
+            msg!("Instruction: SetExtensionAuthority");
+            set_extension_authority::process_set_extension_authority(
+                program_id,
+                SetExtensionAuthority::context(accounts)?,
+                args,
+            )
+        }
+        Instruction::ThawGroup => {
+            msg!("Instruction: ThawGroup");
+            thaw_group::process_thaw_group(program_id, ThawGroup::context(accounts)?)
+        }
         Instruction::Transfer => {
             msg!("Instruction: Transfer");
-            transfer::process_transfer(program_id, Transfer::context(accounts)?)
+            // accounts after the instruction accounts are the parents of a nested asset,
+            // followed by the accounts passed to the transfer hook
+            transfer::process_transfer(program_id, Transfer::context(accounts)?, &accounts[4..])
+        }
+        Instruction::TransferMany => {
+            msg!("Instruction: TransferMany");
+            // accounts after the instruction accounts are the assets to transfer
+            transfer_many::process_transfer_many(
+                program_id,
+                TransferMany::context(accounts)?,
+                &accounts[2..],
+            )
         }
         Instruction::Ungroup => {
             msg!("Instruction: Ungroup");
@@ -120,7 +259,7 @@ pub fn process_instruction(
         }
         Instruction::Unlock => {
             msg!("Instruction: Unlock");
-            unlock::process_unlock(program_id, Unlock::context(accounts)?)
+            unlock::process_unlock(program_id, Unlock::context(accounts)?, &accounts[2..])
         }
         Instruction::Unverify => {
             msg!("Instruction: Unverify");
@@ -128,7 +267,15 @@ pub fn process_instruction(
         }
         Instruction::Update(args) => {
             msg!("Instruction: Update");
-            update::process_update(program_id, Update::context(accounts)?, args)
+            update::process_update(program_id, Update::context(accounts)?, &accounts[6..], args)
+        }
+        Instruction::UpdateRuleSet(args) => {
+            msg!("Instruction: UpdateRuleSet");
+            update_rule_set::process_update_rule_set(
+                program_id,
+                UpdateRuleSet::context(accounts)?,
+                args,
+            )
         }
         Instruction::Verify => {
             msg!("Instruction: Verify");
@@ -278,3 +425,157 @@ fn resize<'a>(
 
     Ok(())
 }
+
+/// Validates the serialized constraint of a rule set.
+///
+/// The data must contain a single constraint that does not include an `External` constraint
+/// at any level (e.g., nested in an `And`), since rule sets cannot reference other rule sets.
+fn validate_rule_set_constraint(data: &[u8]) -> ProgramResult {
+    require!(
+        validate_constraint(data)? == data.len(),
+        ProgramError::InvalidInstructionData,
+        "constraint length"
+    );
+
+    Ok(())
+}
+
+/// Validates a serialized constraint of a rule set and its nested constraints.
+///
+/// Returns the size of the constraint (including the operator header).
+fn validate_constraint(data: &[u8]) -> Result<usize, ProgramError> {
+    require!(
+        data.len() >= std::mem::size_of::<Operator>(),
+        ProgramError::InvalidInstructionData,
+        "constraint"
+    );
+
+    let (operator, rest) = data.split_at(std::mem::size_of::<Operator>());
+    let operator = Operator::load(operator);
+
+    // `External` is the last operator type
+    require!(
+        u32::from_le_bytes([data[0], data[1], data[2], data[3]])
+            <= u32::from(OperatorType::External),
+        ProgramError::InvalidInstructionData,
+        "constraint operator"
+    );
+
+    let constraint = rest
+        .get(..operator.size() as usize)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match operator.operator_type() {
+        OperatorType::External => {
+            return err!(
+                ProgramError::InvalidInstructionData,
+                "rule set constraint cannot be external"
+            );
+        }
+        OperatorType::And | OperatorType::Or => validate_constraints(constraint)?,
+        OperatorType::AtLeast => validate_constraints(
+            constraint
+                .get(std::mem::size_of::<u64>()..)
+                .ok_or(ProgramError::InvalidInstructionData)?,
+        )?,
+        OperatorType::Not => require!(
+            validate_constraint(constraint)? == constraint.len(),
+            ProgramError::InvalidInstructionData,
+            "constraint length"
+        ),
+        _ => (),
+    }
+
+    Ok(std::mem::size_of::<Operator>() + constraint.len())
+}
+
+/// Validates a list of serialized constraints of a rule set.
+fn validate_constraints(mut data: &[u8]) -> ProgramResult {
+    while !data.is_empty() {
+        data = &data[validate_constraint(data)?..];
+    }
+
+    Ok(())
+}
+
+/// Extends the data of an extension by `length` bytes.
+///
+/// If the extension is not present, it is added at the end of the account; otherwise any
+/// data after the extension is moved to its new boundary. Returns the offset of the new
+/// bytes on the account data, which are left for the caller to initialize.
+fn extend<'a>(
+    extension_type: ExtensionType,
+    length: usize,
+    account: &'a AccountInfo,
+    payer: Option<&'a AccountInfo>,
+    system_program: Option<&'a AccountInfo>,
+) -> Result<usize, ProgramError> {
+    let data = account.try_borrow_data()?;
+
+    let account_boundary = Asset::last_extension(&data)
+        .map(|(extension, _)| extension.boundary() as usize)
+        .unwrap_or(Asset::LEN);
+
+    // extension details:
+    //   - offset of the extension header
+    //   - current length
+    //   - extension boundary
+    let (offset, current_length, boundary, update) =
+        if let Some((extension, offset)) = Asset::get_extension(extension_type, &data) {
+            (
+                offset - Extension::LEN,
+                extension.length() as usize,
+                extension.boundary() as usize,
+                true,
+            )
+        } else {
+            (account_boundary, 0, account_boundary, false)
+        };
+
+    // determine the new boundary of the extension and any required padding
+    // to maintain byte alignment
+    let updated_boundary = std::alloc::Layout::from_size_align(
+        offset + Extension::LEN + current_length + length,
+        std::mem::size_of::<u64>(),
+    )
+    .map_err(|_| AssetError::InvalidAlignment)?
+    .pad_to_align()
+    .size();
+
+    let delta = updated_boundary - boundary;
+    let bytes_to_move = account_boundary - boundary;
+    // drop the borrow to resize the account
+    drop(data);
+
+    resize(account_boundary + delta, account, payer, system_program)?;
+
+    if bytes_to_move > 0 {
+        unsafe {
+            let ptr = account.unchecked_borrow_mut_data().as_mut_ptr();
+            // move the bytes after the extension to the new boundary
+            sol_memmove(ptr.add(updated_boundary), ptr.add(boundary), bytes_to_move);
+        }
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    let extension = Extension::load_mut(&mut data[offset..offset + Extension::LEN]);
+
+    if update {
+        extension.set_length((current_length + length) as u32);
+        extension.set_boundary(updated_boundary as u32);
+    } else {
+        *extension = Extension::new(extension_type, length as u32, updated_boundary as u32);
+    }
+
+    // updates the boundaries of any subsequent extensions
+    let mut cursor = updated_boundary;
+
+    while cursor < account_boundary + delta {
+        let extension = Extension::load_mut(&mut data[cursor..cursor + Extension::LEN]);
+        let boundary = extension.boundary() + delta as u32;
+        extension.set_boundary(boundary);
+        cursor = boundary as usize;
+    }
+
+    Ok(offset + Extension::LEN + current_length)
+}
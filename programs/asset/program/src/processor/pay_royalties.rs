@@ -0,0 +1,173 @@
+use nifty_asset_types::{
+    extensions::{
+        Creators, Extension, ExtensionDataMut, ExtensionType, Royalties, RoyaltyPaymentMut,
+    },
+    podded::ZeroCopy,
+    state::{Asset, Discriminator},
+};
+use nitrate::program::{system, AccountInfo};
+use solana_program::{
+    entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    error::AssetError,
+    instruction::{
+        accounts::{Context, PayRoyalties},
+        PayRoyaltiesInput,
+    },
+    processor::extend,
+    require,
+    utils::is_primary_sale,
+};
+
+/// Length of the `RoyaltyPayment` extension data.
+const ROYALTY_PAYMENT_LENGTH: usize = std::mem::size_of::<u64>();
+
+/// Pays the royalties of an asset sale to its creators.
+///
+/// The royalty amount is `price * basis_points / 10000`, split across the creators by
+/// their share; the remainder of the split is paid to the first creator. The primary rate
+/// is used while the asset is in its primary sale (tracked by its `PrimarySale` extension).
+/// When the asset does not have a `Royalties` or `Creators` extension, the extension of its
+/// group is used.
+///
+/// The amount paid is recorded on the `RoyaltyPayment` extension of the asset, which is
+/// consumed by the next transfer of the asset.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` asset
+///   2. `[optional]` group
+///   3. `[]` system_program
+///   4. `[writable]` creator accounts
+pub fn process_pay_royalties(
+    program_id: &Pubkey,
+    ctx: Context<PayRoyalties>,
+    args: PayRoyaltiesInput,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.payer.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "payer"
+    );
+
+    require!(
+        ctx.accounts.system_program.key() == &system_program::ID,
+        ProgramError::IncorrectProgramId,
+        "system_program"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    let data = ctx.accounts.asset.try_borrow_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    // group data is only required when the asset does not have the extensions
+    let group_data = if let Some(group) = Asset::load(&data).group.value() {
+        let group_account = ctx
+            .accounts
+            .group
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        require!(
+            group_account.key() == &**group && group_account.owner() == program_id,
+            AssetError::InvalidGroup,
+            "group"
+        );
+
+        Some(group_account.try_borrow_data()?)
+    } else {
+        None
+    };
+
+    let royalties = Asset::get::<Royalties>(&data)
+        .or_else(|| group_data.as_ref().and_then(|d| Asset::get::<Royalties>(d)))
+        .ok_or_else(|| {
+            msg!("[ERROR] Missing royalties extension");
+            AssetError::ExtensionDataInvalid
+        })?;
+
+    let creators = Asset::get::<Creators>(&data)
+        .or_else(|| group_data.as_ref().and_then(|d| Asset::get::<Creators>(d)))
+        .ok_or_else(|| {
+            msg!("[ERROR] Missing creators extension");
+            AssetError::ExtensionDataInvalid
+        })?;
+
+    require!(
+        remaining_accounts.len() >= creators.values.len(),
+        ProgramError::NotEnoughAccountKeys,
+        "creator accounts"
+    );
+
+    let amount = royalties.amount(args.price, is_primary_sale(&data));
+
+    // each share is rounded down, so the remainder is paid to the first creator
+    let remainder = amount.saturating_sub(creators.values.iter().fold(0u64, |total, creator| {
+        total.saturating_add(creator.royalty(amount))
+    }));
+
+    for (index, (creator, account)) in creators.values.iter().zip(remaining_accounts).enumerate() {
+        require!(
+            account.key() == &creator.address,
+            ProgramError::InvalidArgument,
+            "creator \"{}\" does not match account \"{}\"",
+            creator.address,
+            account.key()
+        );
+
+        let royalty = if index == 0 {
+            creator.royalty(amount) + remainder
+        } else {
+            creator.royalty(amount)
+        };
+
+        if royalty > 0 {
+            system::transfer(ctx.accounts.payer, account, royalty);
+        }
+    }
+
+    // drop the borrows to record the payment on the asset
+    drop(royalties);
+    drop(group_data);
+    drop(data);
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    if let Some(mut payment) = Extension::get_mut::<RoyaltyPaymentMut>(&mut data[Asset::LEN..]) {
+        payment.add(amount);
+    } else {
+        // drop the borrow to resize the account
+        drop(data);
+
+        let offset = extend(
+            ExtensionType::RoyaltyPayment,
+            ROYALTY_PAYMENT_LENGTH,
+            ctx.accounts.asset,
+            Some(ctx.accounts.payer),
+            Some(ctx.accounts.system_program),
+        )?;
+
+        let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+        RoyaltyPaymentMut::from_bytes_mut(&mut data[offset..offset + ROYALTY_PAYMENT_LENGTH])
+            .add(amount);
+    }
+
+    msg!("Paid {} lamports in royalties", amount);
+
+    Ok(())
+}
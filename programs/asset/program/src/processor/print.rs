@@ -0,0 +1,201 @@
+use nifty_asset_types::{
+    extensions::{EditionMut, Extension, ExtensionDataMut, ExtensionType, MasterEditionMut},
+    podded::ZeroCopy,
+    state::{Asset, Discriminator, Standard},
+};
+use nitrate::program::system;
+use solana_program::{
+    entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    err,
+    error::AssetError,
+    instruction::accounts::{Context, Print},
+    processor::extend,
+    require,
+};
+
+/// Length of the `Edition` extension data.
+const EDITION_LENGTH: usize = std::mem::size_of::<Pubkey>() + std::mem::size_of::<u64>();
+
+/// Extensions of the master copied to each print.
+const INHERITED_EXTENSIONS: [ExtensionType; 2] =
+    [ExtensionType::Creators, ExtensionType::Royalties];
+
+/// Prints a new edition of a master asset.
+///
+/// The print references the master on its `Edition` extension and carries a copy of the
+/// `Creators` and `Royalties` extensions of the master (if any), so the royalties of the
+/// master also apply to its prints. Prints are non-fungible assets, so the master must be
+/// a non-fungible asset.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` master
+///   1. `[writable, signer]` asset
+///   2. `[signer]` authority
+///   3. `[]` owner
+///   4. `[writable, signer]` payer
+///   5. `[]` system_program
+pub fn process_print(program_id: &Pubkey, ctx: Context<Print>) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.asset.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "asset"
+    );
+
+    require!(
+        ctx.accounts.asset.data_is_empty(),
+        AssetError::AlreadyInitialized,
+        "asset"
+    );
+
+    require!(
+        ctx.accounts.payer.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "payer"
+    );
+
+    require!(
+        ctx.accounts.system_program.key() == &system_program::ID,
+        ProgramError::IncorrectProgramId,
+        "system_program"
+    );
+
+    require!(
+        ctx.accounts.master.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "master"
+    );
+
+    let mut master_data = ctx.accounts.master.try_borrow_mut_data()?;
+
+    require!(
+        master_data.len() >= Asset::LEN && master_data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "master"
+    );
+
+    let (master, extensions) = master_data.split_at_mut(Asset::LEN);
+    let master = Asset::load(master);
+
+    require!(
+        master.authority == *ctx.accounts.authority.key(),
+        AssetError::InvalidAuthority,
+        "authority"
+    );
+
+    // prints are non-fungible assets, so only non-fungible masters can be printed
+    require!(
+        master.standard == Standard::NonFungible,
+        AssetError::ExtensionDataInvalid,
+        "{:?} master cannot be printed",
+        master.standard
+    );
+
+    let master_edition =
+        if let Some(master_edition) = Extension::get_mut::<MasterEditionMut>(extensions) {
+            master_edition
+        } else {
+            return err!(
+                AssetError::ExtensionNotFound,
+                "Missing required [MasterEdition] extension"
+            );
+        };
+
+    // supply validation
+    if let Some(max_supply) = master_edition.max_supply.value() {
+        require!(
+            *master_edition.supply < **max_supply,
+            AssetError::ExtensionDataInvalid,
+            "Maximum edition supply reached"
+        );
+    }
+
+    // increment the supply; the new value is the edition number of the print
+    *master_edition.supply += 1;
+    let number = *master_edition.supply;
+
+    // the royalties and creators of the master also apply to its prints
+    let inherited = INHERITED_EXTENSIONS
+        .iter()
+        .filter_map(|extension_type| {
+            Asset::get_extension(*extension_type, &master_data).map(|(extension, offset)| {
+                (
+                    *extension_type,
+                    &master_data[offset..offset + extension.length() as usize],
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // creates the print asset
+
+    let boundary = std::alloc::Layout::from_size_align(
+        Asset::LEN + Extension::LEN + EDITION_LENGTH,
+        std::mem::size_of::<u64>(),
+    )
+    .map_err(|_| AssetError::InvalidAlignment)?
+    .pad_to_align()
+    .size();
+
+    system::create_account(
+        ctx.accounts.payer,
+        ctx.accounts.asset,
+        Rent::get()?.minimum_balance(boundary),
+        boundary as u64,
+        program_id,
+    );
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+    let master = Asset::load(&master_data);
+    let (asset, extension_data) = data.split_at_mut(Asset::LEN);
+
+    let asset = Asset::load_mut(asset);
+
+    asset.discriminator = Discriminator::Asset;
+    asset.standard = Standard::NonFungible;
+    asset.mutable = master.mutable;
+    asset.owner = *ctx.accounts.owner.key();
+    asset.authority = master.authority;
+    asset.name = master.name;
+
+    let (header, extension_data) = extension_data.split_at_mut(Extension::LEN);
+    *Extension::load_mut(header) = Extension::new(
+        ExtensionType::Edition,
+        EDITION_LENGTH as u32,
+        boundary as u32,
+    );
+
+    let edition = EditionMut::from_bytes_mut(&mut extension_data[..EDITION_LENGTH]);
+    *edition.master = *ctx.accounts.master.key();
+    *edition.number = number;
+
+    // drop the account data borrow to add the inherited extensions
+    drop(data);
+
+    for (extension_type, extension_data) in inherited {
+        let offset = extend(
+            extension_type,
+            extension_data.len(),
+            ctx.accounts.asset,
+            Some(ctx.accounts.payer),
+            Some(ctx.accounts.system_program),
+        )?;
+
+        let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+        data[offset..offset + extension_data.len()].copy_from_slice(extension_data);
+    }
+
+    Ok(())
+}
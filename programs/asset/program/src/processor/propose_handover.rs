@@ -0,0 +1,101 @@
+use nifty_asset_types::{
+    extensions::{Extension, ExtensionDataMut, ExtensionType, PendingAuthorityMut},
+    podded::{pod::PodBool, ZeroCopy},
+    state::{Asset, Discriminator},
+};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::accounts::{Context, ProposeHandover},
+    processor::extend,
+    require,
+};
+
+/// Length of the `PendingAuthority` extension data.
+const PENDING_AUTHORITY_LENGTH: usize = std::mem::size_of::<Pubkey>();
+
+/// Proposes a new authority for an asset.
+///
+/// The proposed authority is stored on a `PendingAuthority` extension until it signs an
+/// `AcceptHandover` instruction. Unlike `Handover`, the authorities do not need to sign
+/// the same transaction.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` authority
+///   2. `[]` new_authority
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system_program
+pub fn process_propose_handover(
+    program_id: &Pubkey,
+    ctx: Context<ProposeHandover>,
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    require!(
+        *ctx.accounts.new_authority.key() != Pubkey::default(),
+        ProgramError::InvalidArgument,
+        "new_authority"
+    );
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load(asset);
+
+    require!(
+        asset.authority == *ctx.accounts.authority.key(),
+        AssetError::InvalidAuthority,
+        "authority"
+    );
+
+    require!(
+        <PodBool as Into<bool>>::into(asset.mutable),
+        AssetError::ImmutableAsset,
+        "asset"
+    );
+
+    // sets the pending authority; the extension is reused when a handover was proposed before
+    if let Some(pending) = Extension::get_mut::<PendingAuthorityMut>(extensions) {
+        *pending.authority = *ctx.accounts.new_authority.key();
+    } else {
+        // drop the borrow to resize the account
+        drop(data);
+
+        let offset = extend(
+            ExtensionType::PendingAuthority,
+            PENDING_AUTHORITY_LENGTH,
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+
+        let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+        let pending = PendingAuthorityMut::from_bytes_mut(
+            &mut data[offset..offset + PENDING_AUTHORITY_LENGTH],
+        );
+        *pending.authority = *ctx.accounts.new_authority.key();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use nifty_asset_types::{
+    extensions::{DelegateExpiry, DelegateExpiryMut, DelegatesMut, Extension, Manager, RentalMut},
+    podded::{pod::PodOption, ZeroCopy},
+    state::{Asset, Delegate, Discriminator, State},
+};
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::accounts::{Context, Recall},
+    require,
+    utils::{assert_not_self_nested, is_expired},
+};
+
+/// Recalls an asset to an address chosen by its manager.
+///
+/// Only the `Manager` delegate can recall an asset, which makes it possible to revoke
+/// managed and soulbound assets (e.g., credentials) back to the issuer. The recall
+/// succeeds even when the asset is locked or rented, and the recalled asset is unlocked
+/// with its delegates and rental cleared.
+///
+/// Guards, royalties and transfer hooks do not apply to a recall.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` manager
+///   2. `[]` recipient
+pub fn process_recall(program_id: &Pubkey, ctx: Context<Recall>) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.manager.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "manager"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    assert_not_self_nested(program_id, ctx.accounts.asset.key(), ctx.accounts.recipient)?;
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
+
+    {
+        let manager = Extension::get::<Manager>(extensions).ok_or_else(|| {
+            msg!("[ERROR] Asset does not have a manager");
+            AssetError::ExtensionNotFound
+        })?;
+
+        require!(
+            *manager.delegate.address == *ctx.accounts.manager.key(),
+            AssetError::InvalidDelegate,
+            "not the manager of the asset"
+        );
+
+        require!(
+            !is_expired(
+                manager.delegate,
+                Extension::get::<DelegateExpiry>(extensions).as_ref()
+            )?,
+            AssetError::DelegateExpired,
+            "manager expired"
+        );
+    }
+
+    msg!(
+        "Recalling asset \"{}\" from \"{}\" to \"{}\"",
+        ctx.accounts.asset.key(),
+        asset.owner,
+        ctx.accounts.recipient.key()
+    );
+
+    // Transfer the asset to the recipient and unlock it.
+    asset.owner = *ctx.accounts.recipient.key();
+    asset.state = State::Unlocked;
+
+    // Clear the delegate.
+    asset.delegate = PodOption::new(Delegate::default());
+
+    // Clear the delegates list (if any).
+    if let Some(mut delegates) = Extension::get_mut::<DelegatesMut>(extensions) {
+        delegates.clear();
+    }
+
+    // Clear the delegate expiries (if any).
+    if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+        expiry.clear();
+    }
+
+    // Clear the rental (if any), so the asset is not returned to the lender.
+    if let Some(mut rental) = Extension::get_mut::<RentalMut>(extensions) {
+        rental.clear();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use nifty_asset_types::{
+    extensions::{DelegateExpiryMut, DelegatesMut, Extension, RentalMut},
+    podded::{pod::PodOption, ZeroCopy},
+    state::{Asset, Delegate, Discriminator, State},
+};
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::AssetError,
+    instruction::accounts::{Context, Reclaim},
+    require,
+};
+
+/// Returns an expired rental to the lender.
+///
+/// Anyone can reclaim an asset once its rental expires. The asset is transferred back to
+/// the lender and unlocked.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+pub fn process_reclaim(program_id: &Pubkey, ctx: Context<Reclaim>) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
+
+    let lender = {
+        let mut rental = Extension::get_mut::<RentalMut>(extensions)
+            .filter(|rental| *rental.lender != Pubkey::default())
+            .ok_or_else(|| {
+                msg!("[ERROR] Asset is not rented");
+                AssetError::ExtensionNotFound
+            })?;
+
+        require!(
+            Clock::get()?.unix_timestamp >= *rental.expiry,
+            AssetError::RentalNotExpired,
+            "rental expires at {}",
+            rental.expiry
+        );
+
+        let lender = *rental.lender;
+        rental.clear();
+
+        lender
+    };
+
+    // Return the asset to the lender and unlock it.
+    asset.owner = lender;
+    asset.state = State::Unlocked;
+
+    // Clear the delegate.
+    asset.delegate = PodOption::new(Delegate::default());
+
+    // Clear the delegates list (if any).
+    if let Some(mut delegates) = Extension::get_mut::<DelegatesMut>(extensions) {
+        delegates.clear();
+    }
+
+    // Clear the delegate expiries (if any).
+    if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+        expiry.clear();
+    }
+
+    Ok(())
+}
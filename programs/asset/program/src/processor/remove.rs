@@ -1,8 +1,9 @@
 use nifty_asset_types::{
-    extensions::{Extension, ExtensionType, Grouping},
+    extensions::{Action, Extension, ExtensionType, Grouping, MasterEdition},
     podded::{pod::PodBool, ZeroCopy},
     state::{Asset, Discriminator},
 };
+use nitrate::program::AccountInfo;
 use solana_program::{
     entrypoint::ProgramResult, msg, program_error::ProgramError, program_memory::sol_memmove,
     pubkey::Pubkey,
@@ -14,22 +15,37 @@ use crate::{
     instruction::accounts::{Context, Remove},
     processor::resize,
     require,
+    utils::{
+        assert_extension_authority, assert_guard, get_caller, get_rule_sets,
+        split_trailing_accounts,
+    },
 };
 
 /// Removes an extension from an asset.
 ///
+/// The signer must be the authority of the extension, which is the authority of the asset
+/// unless the extension has its own authority. Immutable extensions cannot be removed.
+///
 /// ### Accounts:
 ///
 ///   0. `[writable]` asset
 ///   1. `[signer]` authority
 ///   2. `[optional]` group
 ///   3. `[writable]` recipient
+///
+/// Removing an extension is an update of the asset, so the `Update` guard of the asset
+/// (if any) applies. The instructions sysvar and the rule set accounts can be provided as
+/// (optional) trailing accounts to evaluate the guard on the calling program or external
+/// rule sets.
 #[inline(always)]
 pub fn process_remove(
     program_id: &Pubkey,
     ctx: Context<Remove>,
+    remaining_accounts: &[AccountInfo],
     extension_type: ExtensionType,
 ) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
     // account validation
 
     require!(
@@ -44,7 +60,7 @@ pub fn process_remove(
         "asset"
     );
 
-    let mut account_data = ctx.accounts.asset.try_borrow_mut_data()?;
+    let account_data = ctx.accounts.asset.try_borrow_data()?;
 
     require!(
         account_data.len() >= Asset::LEN && account_data[0] == u8::from(Discriminator::Asset),
@@ -52,13 +68,23 @@ pub fn process_remove(
         "asset"
     );
 
-    let asset = Asset::load_mut(&mut account_data);
+    // Program invoking the instruction and rule sets referenced by `External` constraints.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
 
-    require!(
-        asset.authority == *ctx.accounts.authority.key(),
-        AssetError::InvalidAuthority,
-        "authority"
-    );
+    // removing an extension is an update of the asset, so the asset must satisfy its
+    // update guard (if any)
+    assert_guard(
+        Action::Update,
+        &account_data,
+        ctx.accounts.asset,
+        ctx.accounts.authority,
+        None,
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
+    let asset = Asset::load(&account_data);
 
     require!(
         <PodBool as Into<bool>>::into(asset.mutable),
@@ -88,8 +114,29 @@ pub fn process_remove(
     // signal this by returning an error
     let boundary = boundary.ok_or(AssetError::ExtensionNotFound)?;
 
+    let extension = Extension::load(&account_data[offset..offset + Extension::LEN]);
+
+    require!(
+        !extension.is_immutable(),
+        AssetError::ImmutableExtension,
+        "[{:?}] extension is immutable",
+        extension_type
+    );
+
+    // the signer must be the authority of the extension
+    assert_extension_authority(&account_data, extension, ctx.accounts.authority.key())?;
+
     match extension_type {
-        ExtensionType::Manager | ExtensionType::Proxy => {
+        ExtensionType::Manager
+        | ExtensionType::Edition
+        | ExtensionType::Proxy
+        | ExtensionType::DelegateExpiry
+        | ExtensionType::Delegates
+        | ExtensionType::Authorities
+        | ExtensionType::Rental
+        | ExtensionType::PendingAuthority
+        | ExtensionType::PrimarySale
+        | ExtensionType::RoyaltyPayment => {
             return err!(
                 AssetError::ExtensionDataInvalid,
                 "invalid extension type: {:?}",
@@ -106,6 +153,17 @@ pub fn process_remove(
                 grouping.size
             );
         }
+        ExtensionType::MasterEdition => {
+            let master_edition = Asset::get::<MasterEdition>(&account_data).unwrap();
+            // the master cannot be removed once it has prints, since adding it again
+            // would reset the supply
+            require!(
+                *master_edition.supply == 0,
+                AssetError::ExtensionDataInvalid,
+                "master edition has prints ({})",
+                master_edition.supply
+            );
+        }
         _ => (),
     }
     // drop the account data borrow
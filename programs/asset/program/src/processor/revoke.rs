@@ -1,4 +1,5 @@
 use nifty_asset_types::{
+    extensions::{DelegateExpiryMut, DelegatesMut, Extension},
     podded::{pod::PodOption, ZeroCopy},
     state::{Asset, Delegate, Discriminator},
 };
@@ -20,6 +21,7 @@ use crate::{
 ///
 ///   0. `[writable]` asset
 ///   1. `[signer]` signer
+///   2. `[optional]` delegate
 pub fn process_revoke(
     program_id: &Pubkey,
     ctx: Context<Revoke>,
@@ -47,39 +49,80 @@ pub fn process_revoke(
         "asset"
     );
 
-    let asset = Asset::load_mut(&mut data);
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
 
-    let is_allowed = asset.owner == *ctx.accounts.signer.key()
-        || asset
-            .delegate
-            .value()
-            .map(|delegate| *delegate.address == *ctx.accounts.signer.key())
-            == Some(true);
+    // determines the delegate to revoke: the delegate account (if provided), the
+    // signer (when it is not the owner) or the asset delegate
+    let target = if let Some(delegate) = ctx.accounts.delegate {
+        Some(*delegate.key())
+    } else if asset.owner != *ctx.accounts.signer.key() {
+        Some(*ctx.accounts.signer.key())
+    } else {
+        asset.delegate.value().map(|delegate| *delegate.address)
+    };
 
-    // we only revoke a delegate if the signer is the owner or the current delegate
+    let is_allowed =
+        asset.owner == *ctx.accounts.signer.key() || target == Some(*ctx.accounts.signer.key());
+
+    // we only revoke a delegate if the signer is the owner or the delegate itself
     if !is_allowed {
         return err!(AssetError::InvalidAuthority, "not an owner or delegate");
     }
 
-    // If the All role is passed in we completely revoke the delegate
+    let target = if let Some(target) = target {
+        target
+    } else {
+        // nothing to revoke
+        return Ok(());
+    };
 
-    match args {
-        DelegateInput::All => {
+    let mut revoked = false;
+
+    if let Some(delegate) = asset
+        .delegate
+        .value_mut()
+        .filter(|delegate| *delegate.address == target)
+    {
+        if revoke(delegate, &args) {
             asset.delegate = PodOption::new(Delegate::default());
-            return Ok(());
+            revoked = true;
         }
-        DelegateInput::Some { roles } => {
-            // otherwise we only disable the roles passed in
-            if let Some(delegate) = asset.delegate.value_mut() {
-                roles.iter().for_each(|role| delegate.disable(*role));
-
-                if !delegate.has_active_roles() {
-                    // and if the delegate has no active roles, then we completely revoke it
-                    asset.delegate = PodOption::new(Delegate::default());
-                }
-            }
+    } else if let Some(delegate) = Extension::get_mut::<DelegatesMut>(extensions)
+        .as_mut()
+        .and_then(|delegates| delegates.get(&target))
+    {
+        if revoke(delegate, &args) {
+            // clears the entry so the slot can be reused
+            *delegate = Delegate::default();
+            revoked = true;
+        }
+    }
+
+    // clears the expiry of a revoked delegate, so it does not apply if the
+    // delegate is approved again
+    if revoked {
+        if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+            expiry.remove(&target);
         }
     }
 
     Ok(())
 }
+
+/// Disables the roles of a delegate.
+///
+/// Returns `true` if the delegate has no active roles and should be completely revoked.
+#[inline(always)]
+fn revoke(delegate: &mut Delegate, args: &DelegateInput) -> bool {
+    match args {
+        // If the All role is passed in we completely revoke the delegate
+        DelegateInput::All => true,
+        DelegateInput::Some { roles } => {
+            // otherwise we only disable the roles passed in and if the delegate
+            // has no active roles, then we completely revoke it
+            roles.iter().for_each(|role| delegate.disable(*role));
+            !delegate.has_active_roles()
+        }
+    }
+}
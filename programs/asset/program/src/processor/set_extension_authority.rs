@@ -0,0 +1,128 @@
+use nifty_asset_types::{
+    extensions::{Authorities, Extension, ExtensionType},
+    podded::{pod::PodBool, ZeroCopy},
+    state::{Asset, Discriminator},
+};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::{
+        accounts::{Context, SetExtensionAuthority},
+        ExtensionAuthorityInput,
+    },
+    processor::extend,
+    require,
+    utils::assert_extension_authority,
+};
+
+/// Sets the authority of an extension.
+///
+/// The authority is stored on the `Authorities` extension table and the extension header
+/// references its index on the table. Only the current authority of the extension can
+/// set a new authority.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` asset
+///   1. `[signer]` authority
+///   2. `[writable, signer, optional]` payer
+///   3. `[optional]` system_program
+pub fn process_set_extension_authority(
+    program_id: &Pubkey,
+    ctx: Context<SetExtensionAuthority>,
+    args: ExtensionAuthorityInput,
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.asset.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "asset"
+    );
+
+    let data = ctx.accounts.asset.try_borrow_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::Uninitialized,
+        "asset"
+    );
+
+    let asset = Asset::load(&data);
+
+    require!(
+        <PodBool as Into<bool>>::into(asset.mutable),
+        AssetError::ImmutableAsset,
+        "asset"
+    );
+
+    require!(
+        args.extension_type != ExtensionType::Authorities,
+        AssetError::ExtensionDataInvalid,
+        "invalid extension type: {:?}",
+        args.extension_type
+    );
+
+    let (extension, _) =
+        Asset::get_extension(args.extension_type, &data).ok_or(AssetError::ExtensionNotFound)?;
+
+    assert_extension_authority(&data, extension, ctx.accounts.authority.key())?;
+
+    // determines the index of the new authority on the authorities table and
+    // whether the authority needs to be added to the table
+    let (index, authority) = match args.new_authority {
+        Some(authority) if authority != asset.authority => {
+            let authorities = Asset::get::<Authorities>(&data);
+
+            match authorities
+                .as_ref()
+                .and_then(|authorities| authorities.index_of(&authority))
+            {
+                Some(index) => (index, None),
+                None => (
+                    authorities
+                        .map(|authorities| authorities.values.len())
+                        .unwrap_or_default()
+                        .checked_add(1)
+                        .and_then(|index| u16::try_from(index).ok())
+                        .ok_or(AssetError::ExtensionDataInvalid)?,
+                    Some(authority),
+                ),
+            }
+        }
+        // the asset authority is represented by the index 0
+        _ => (0, None),
+    };
+
+    // drop the borrow to (potentially) resize the account
+    drop(data);
+
+    if let Some(authority) = authority {
+        let offset = extend(
+            ExtensionType::Authorities,
+            std::mem::size_of::<Pubkey>(),
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+
+        ctx.accounts.asset.try_borrow_mut_data()?[offset..offset + std::mem::size_of::<Pubkey>()]
+            .copy_from_slice(authority.as_ref());
+    }
+
+    // the extension might have moved after adding the authority to the table
+    let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
+    let (_, offset) =
+        Asset::get_extension(args.extension_type, &data).ok_or(AssetError::ExtensionNotFound)?;
+
+    Extension::load_mut(&mut data[offset - Extension::LEN..offset]).set_authority(index);
+
+    Ok(())
+}
@@ -1,14 +1,13 @@
 use nifty_asset_types::{
     constraints::{Assertion, Context as ConstraintContext},
-    extensions::{Extension, Manager, Royalties},
-    podded::{
-        pod::{Nullable, PodOption},
-        ZeroCopy,
+    extensions::{
+        Action, DelegateExpiry, DelegateExpiryMut, Delegates, DelegatesMut, Extension, Manager,
+        Royalties, RoyaltyPaymentMut, TransferHook,
     },
+    podded::{pod::PodOption, ZeroCopy},
     state::{Asset, Delegate, DelegateRole, Discriminator, Standard},
 };
-use std::ops::Deref;
-
+use nitrate::program::AccountInfo;
 use solana_program::{
     entrypoint::ProgramResult,
     instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
@@ -20,7 +19,11 @@ use crate::{
     error::AssetError,
     instruction::accounts::{Context, Transfer},
     process_royalties, require,
-    utils::assert_delegate,
+    utils::{
+        assert_delegate, assert_group_unlocked, assert_guard, assert_not_self_nested, get_caller,
+        get_owner_depth, get_royalty_payments, get_rule_sets, invoke_transfer_hook,
+        is_primary_sale, split_trailing_accounts, update_primary_sale,
+    },
 };
 
 /// Transfers ownership of the aseet to a new public key.
@@ -31,7 +34,38 @@ use crate::{
 ///   1. `[signer]` signer
 ///   2. `[]` recipient
 ///   3. `[optional]` group_asset
-pub fn process_transfer(program_id: &Pubkey, ctx: Context<Transfer>) -> ProgramResult {
+///
+/// When the asset is part of a group, the group account is required even if neither the
+/// asset nor the group have royalties, since the transfer fails if the group is locked or
+/// frozen. Clients that only provided the group account for group royalties must now
+/// provide it for every member of a group.
+///
+/// The rule set accounts are required when the royalties or transfer guard constraints
+/// reference rule sets through `External` constraints, and they are provided as trailing
+/// remaining accounts (before the instructions sysvar, if any).
+///
+/// When the asset has a `PrimarySale` extension, the primary rate of the royalties (either
+/// its own or the ones of its group) applies until the asset is transferred to an account
+/// that is neither the authority nor a creator, which flags the primary sale as happened.
+///
+/// When the asset is owned by another asset (nested asset), the signer must be the owner of
+/// the top-level parent and the parent chain must be provided as the first remaining
+/// accounts, starting with the parent of the asset. Transferring a parent asset carries its
+/// children, since they remain owned by the parent.
+///
+/// When the asset has a `TransferHook` extension, the hook program must be provided as
+/// the first remaining account (after the parent chain, if any), followed by any extra
+/// accounts required by the hook.
+///
+/// The instructions sysvar can be provided as the last remaining account to determine the
+/// calling program and any royalty payments in the same transaction.
+pub fn process_transfer(
+    program_id: &Pubkey,
+    ctx: Context<Transfer>,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
     require!(
         ctx.accounts.asset.owner() == program_id,
         ProgramError::IllegalOwner,
@@ -45,6 +79,8 @@ pub fn process_transfer(program_id: &Pubkey, ctx: Context<Transfer>) -> ProgramR
         "signer"
     );
 
+    assert_not_self_nested(program_id, ctx.accounts.asset.key(), ctx.accounts.recipient)?;
+
     let mut data = ctx.accounts.asset.try_borrow_mut_data()?;
 
     // Must be an initialized asset.
@@ -54,11 +90,48 @@ pub fn process_transfer(program_id: &Pubkey, ctx: Context<Transfer>) -> ProgramR
         "asset"
     );
 
+    // Program invoking the transfer, used by royalties constraints on the caller.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+
+    // Rule sets referenced by `External` constraints.
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
+
+    // Royalty payments for the asset (if any), either recorded by `PayRoyalties` or funded
+    // by the signer or the recipient of the transfer in the same transaction.
+    let mut payments = get_royalty_payments(
+        program_id,
+        trailing.sysvar_instructions,
+        &[ctx.accounts.signer.key(), ctx.accounts.recipient.key()],
+    )?;
+    payments.set_paid(&data);
+
+    // The asset must satisfy its transfer guard (if any), even on wallet-to-wallet transfers.
+    assert_guard(
+        Action::Transfer,
+        &data,
+        ctx.accounts.asset,
+        ctx.accounts.signer,
+        Some(ctx.accounts.recipient),
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
+    // Whether the primary rate applies to the royalties of the asset or its group.
+    let is_primary_sale = is_primary_sale(&data);
+
     // First we check if the asset itself has the royalties extension, and validate the constraint.
-    let royalties_checked = process_royalties!(ctx, &data);
+    let royalties_checked = process_royalties!(
+        ctx,
+        caller,
+        &rule_sets,
+        &mut payments,
+        is_primary_sale,
+        &data,
+        &data
+    );
 
-    let (asset, extensions) = data.split_at_mut(Asset::LEN);
-    let asset = Asset::load_mut(asset);
+    let (asset, extensions) = data.split_at(Asset::LEN);
+    let asset = Asset::load(asset);
 
     // Cannot transfer soulbound assets.
     require!(
@@ -67,12 +140,27 @@ pub fn process_transfer(program_id: &Pubkey, ctx: Context<Transfer>) -> ProgramR
         "soulbound asset"
     );
 
-    let is_allowed = asset.owner == *ctx.accounts.signer.key()
+    // Number of parent accounts when the signer owns the asset through its parents.
+    let depth = get_owner_depth(
+        program_id,
+        &asset.owner,
+        ctx.accounts.signer,
+        remaining_accounts,
+    )?;
+
+    let is_allowed = depth.is_some()
         || assert_delegate(
-            &[
-                asset.delegate.value(),
-                Extension::get::<Manager>(extensions).map(|s| s.delegate),
-            ],
+            asset
+                .delegate
+                .value()
+                .into_iter()
+                .chain(Extension::get::<Manager>(extensions).map(|s| s.delegate))
+                .chain(
+                    Extension::get::<Delegates>(extensions)
+                        .iter()
+                        .flat_map(|delegates| delegates.values),
+                ),
+            Extension::get::<DelegateExpiry>(extensions).as_ref(),
             ctx.accounts.signer.key(),
             DelegateRole::Transfer,
         )
@@ -90,53 +178,93 @@ pub fn process_transfer(program_id: &Pubkey, ctx: Context<Transfer>) -> ProgramR
         return Ok(());
     }
 
-    // If the asset the asset is part of a group we need to check if royalties
-    // are enabled and if so, if the destination account is allowed to receive the asset.
+    // If the asset is part of a group, the group must not be locked and we need to check
+    // if royalties are enabled and if so, if the destination account is allowed to receive
+    // the asset.
     if let Some(group) = asset.group.value() {
-        // If royalties were not checked yet, we need to check them now.
-        if group.is_some() && !royalties_checked {
-            // We need group asset account to be provided.
-            require!(
-                ctx.accounts.group.is_some(),
-                ProgramError::NotEnoughAccountKeys,
-                "asset is part of a group but no group account was provided"
-            );
-
-            let group_asset_info = ctx.accounts.group.unwrap();
-
-            // Group asset account must be owned by the program.
-            require!(
-                group_asset_info.owner() == program_id,
-                AssetError::InvalidGroup,
-                "group account is not owned by the program"
-            );
-
-            // Group asset account must match the asset group.
-            require!(
-                group.deref() == group_asset_info.key(),
-                AssetError::InvalidGroup,
-                "group account does not match the asset group"
-            );
-
-            let group_data = group_asset_info.try_borrow_data()?;
+        // The group account is validated when checking whether the group is locked.
+        assert_group_unlocked(program_id, group, ctx.accounts.group)?;
 
-            // Group asset account must be initialized.
-            require!(
-                group_data[0] == u8::from(Discriminator::Asset),
-                AssetError::InvalidGroup,
-                "group account is not initialized"
-            );
+        // If royalties were not checked yet, we need to check them now.
+        if !royalties_checked {
+            let group_data = ctx
+                .accounts
+                .group
+                .ok_or(ProgramError::NotEnoughAccountKeys)?
+                .try_borrow_data()?;
 
             // Check if royalties extension is present on the group asset and validate the constraint.
-            process_royalties!(ctx, &group_data);
+            process_royalties!(
+                ctx,
+                caller,
+                &rule_sets,
+                &mut payments,
+                is_primary_sale,
+                &data,
+                &group_data
+            );
         }
     }
 
+    // The asset is loaded again to be updated.
+    let (asset, extensions) = data.split_at_mut(Asset::LEN);
+    let asset = Asset::load_mut(asset);
+
     // Transfer the asset.
     asset.owner = *ctx.accounts.recipient.key();
 
     // Clear the delegate.
     asset.delegate = PodOption::new(Delegate::default());
 
+    // Clear the delegates list (if any).
+    if let Some(mut delegates) = Extension::get_mut::<DelegatesMut>(extensions) {
+        delegates.clear();
+    }
+
+    // Clear the delegate expiries (if any).
+    if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+        expiry.clear();
+    }
+
+    // Consume the royalty payment (if any).
+    if let Some(mut payment) = Extension::get_mut::<RoyaltyPaymentMut>(extensions) {
+        payment.clear();
+    }
+
+    // The first transfer away from the authority and creators is the primary sale.
+    update_primary_sale(
+        &asset.authority,
+        extensions,
+        asset.group.value().and(ctx.accounts.group),
+        ctx.accounts.recipient.key(),
+    )?;
+
+    let hook = Extension::get::<TransferHook>(extensions).map(|hook| *hook.program);
+
+    // Drop the account data borrow before invoking the transfer hook.
+    drop(data);
+
+    if let Some(hook) = hook {
+        let (program, extra_accounts) = remaining_accounts[depth.unwrap_or_default()..]
+            .split_first()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        require!(
+            program.key() == &hook,
+            ProgramError::IncorrectProgramId,
+            "transfer hook program"
+        );
+
+        invoke_transfer_hook(
+            program,
+            &[
+                ctx.accounts.asset,
+                ctx.accounts.signer,
+                ctx.accounts.recipient,
+            ],
+            extra_accounts,
+        )?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,327 @@
+use nifty_asset_types::{
+    constraints::{Assertion, Context as ConstraintContext},
+    extensions::{
+        Action, DelegateExpiry, DelegateExpiryMut, Delegates, DelegatesMut, Extension,
+        ExtensionType, Manager, Royalties, RoyaltyPaymentMut,
+    },
+    podded::{pod::PodOption, ZeroCopy},
+    state::{Asset, Delegate, DelegateRole, Discriminator, Standard},
+};
+use nitrate::program::AccountInfo;
+use solana_program::{
+    entrypoint::ProgramResult,
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    err,
+    error::AssetError,
+    instruction::accounts::{Context, TransferMany},
+    process_royalties, require,
+    utils::{
+        assert_delegate, assert_group_unlocked, assert_guard, assert_not_self_nested, get_caller,
+        get_owner_depth, get_royalty_payments, get_rule_sets, is_primary_sale,
+        split_trailing_accounts, update_primary_sale,
+    },
+};
+
+/// Transfers ownership of multiple assets to a new public key.
+///
+/// The same rules of the `Transfer` instruction apply to each asset. When an asset is part
+/// of a group and does not have its own royalties, the group royalties are evaluated for
+/// the asset. The group royalties are evaluated once per asset (even for assets of the same
+/// group), since their constraint can depend on the asset (e.g., its data or primary sale)
+/// and, in the `Payment` mode, each asset consumes its own payment.
+///
+/// In the `Payment` mode, the amount recorded on an asset by `PayRoyalties` only pays for
+/// that asset, while transfers to the creators funded by the signer or the recipient in the
+/// same transaction are shared by all assets and consumed as each asset is transferred.
+///
+/// Assets with a `TransferHook` extension and nested assets (assets owned by another asset)
+/// must be transferred individually.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` signer
+///   1. `[]` recipient
+///
+/// The assets and their groups are passed as remaining accounts (in any order), which are
+/// classified by their writability:
+///
+///   - `[writable]` accounts are the assets to transfer.
+///   - `[]` accounts are group accounts, which are only used to validate the group
+///     royalties and locks.
+///
+/// The group account of every asset that is part of a group must be provided (either as a
+/// read-only account or as one of the assets to transfer), and the transfer fails if any of
+/// the groups is locked. The rule set accounts and the instructions sysvar can be provided
+/// as (optional) trailing accounts, which are detected from the tail of the remaining
+/// accounts by their data and address.
+pub fn process_transfer_many(
+    program_id: &Pubkey,
+    ctx: Context<TransferMany>,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
+    // Account must be a signer.
+    require!(
+        ctx.accounts.signer.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "signer"
+    );
+
+    // Determine the groups referenced by the assets.
+    let mut groups: Vec<Pubkey> = Vec::new();
+
+    for (index, account) in remaining_accounts.iter().enumerate() {
+        require!(
+            account.owner() == program_id,
+            ProgramError::IllegalOwner,
+            "asset \"{}\"",
+            account.key()
+        );
+
+        // An asset can only be transferred once.
+        require!(
+            !remaining_accounts[..index]
+                .iter()
+                .any(|previous| previous.key() == account.key()),
+            ProgramError::InvalidArgument,
+            "duplicated account \"{}\"",
+            account.key()
+        );
+
+        let data = account.try_borrow_data()?;
+
+        // Must be an initialized asset.
+        require!(
+            data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+            AssetError::Uninitialized,
+            "asset \"{}\"",
+            account.key()
+        );
+
+        if account.is_writable() {
+            if let Some(group) = Asset::load(&data).group.value() {
+                if !groups.contains(group) {
+                    groups.push(**group);
+                }
+            }
+        }
+    }
+
+    // Read-only accounts must be the group of one of the assets.
+    for account in remaining_accounts
+        .iter()
+        .filter(|account| !account.is_writable())
+    {
+        require!(
+            groups.contains(account.key()),
+            AssetError::InvalidGroup,
+            "account \"{}\" is not the group of any asset",
+            account.key()
+        );
+    }
+
+    // A locked group prevents all of its members from being transferred, so group
+    // accounts are always required.
+    for group in &groups {
+        assert_group_unlocked(
+            program_id,
+            group,
+            remaining_accounts
+                .iter()
+                .find(|account| account.key() == group),
+        )?;
+    }
+
+    // Program invoking the transfer, used by royalties constraints on the caller.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+
+    // Rule sets referenced by `External` constraints.
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
+
+    // Royalty payments in the same transaction (if any), funded by the signer or the
+    // recipient. The payments are shared by all assets and consumed as each asset is
+    // transferred, so the transaction must pay the royalties of every asset.
+    let mut payments = get_royalty_payments(
+        program_id,
+        trailing.sysvar_instructions,
+        &[ctx.accounts.signer.key(), ctx.accounts.recipient.key()],
+    )?;
+
+    let mut transferred = 0;
+
+    for account in remaining_accounts
+        .iter()
+        .filter(|account| account.is_writable())
+    {
+        assert_not_self_nested(program_id, account.key(), ctx.accounts.recipient)?;
+
+        let mut data = account.try_borrow_mut_data()?;
+
+        if Asset::contains(ExtensionType::TransferHook, &data) {
+            return err!(
+                AssetError::ExtensionDataInvalid,
+                "asset \"{}\" has a transfer hook and must be transferred individually",
+                account.key()
+            );
+        }
+
+        // The asset must satisfy its transfer guard (if any).
+        assert_guard(
+            Action::Transfer,
+            &data,
+            account,
+            ctx.accounts.signer,
+            Some(ctx.accounts.recipient),
+            caller.as_ref(),
+            &rule_sets,
+        )?;
+
+        // Royalty payments recorded for the asset by `PayRoyalties` (if any).
+        payments.set_paid(&data);
+
+        // Whether the primary rate applies to the royalties of the asset or its group.
+        let is_primary_sale = is_primary_sale(&data);
+
+        // First we check if the asset itself has the royalties extension, and validate the constraint.
+        let royalties_checked = process_royalties!(
+            account,
+            ctx.accounts.signer,
+            ctx.accounts.recipient,
+            caller,
+            &rule_sets,
+            &mut payments,
+            is_primary_sale,
+            &data,
+            &data
+        );
+
+        let (asset, extensions) = data.split_at(Asset::LEN);
+        let asset = Asset::load(asset);
+
+        // Proxied assets can only be transferred by their proxy program.
+        require!(
+            asset.standard != Standard::Proxied || account.is_signer(),
+            ProgramError::MissingRequiredSignature,
+            "proxied asset \"{}\" is not a signer",
+            account.key()
+        );
+
+        // Cannot transfer soulbound assets.
+        require!(
+            asset.standard != Standard::Soulbound,
+            AssetError::CannotTransferSoulbound,
+            "soulbound asset \"{}\"",
+            account.key()
+        );
+
+        // Nested assets must be transferred individually with their parent chain.
+        let is_allowed = get_owner_depth(program_id, &asset.owner, ctx.accounts.signer, &[])?
+            .is_some()
+            || assert_delegate(
+                asset
+                    .delegate
+                    .value()
+                    .into_iter()
+                    .chain(Extension::get::<Manager>(extensions).map(|s| s.delegate))
+                    .chain(
+                        Extension::get::<Delegates>(extensions)
+                            .iter()
+                            .flat_map(|delegates| delegates.values),
+                    ),
+                Extension::get::<DelegateExpiry>(extensions).as_ref(),
+                ctx.accounts.signer.key(),
+                DelegateRole::Transfer,
+            )
+            .is_ok();
+
+        // Signing account must be owner or a transfer delegate.
+        require!(
+            is_allowed,
+            AssetError::InvalidTransferAuthority,
+            "not an owner or transfer delegate of asset \"{}\"",
+            account.key()
+        );
+
+        // Self transfer is skipped so as not to clear the delegate.
+        if asset.owner == *ctx.accounts.recipient.key() {
+            continue;
+        }
+
+        // Group accounts were validated when checking whether the groups are locked.
+        let group_account = asset.group.value().and_then(|group| {
+            remaining_accounts
+                .iter()
+                .find(|account| account.key() == &**group)
+        });
+
+        // If the asset is part of a group and does not have its own royalties, the group
+        // royalties are checked.
+        if let Some(group) = asset.group.value().filter(|_| !royalties_checked) {
+            let group_asset_info = group_account.ok_or_else(|| {
+                msg!("[ERROR] Missing group account \"{}\"", **group);
+                ProgramError::NotEnoughAccountKeys
+            })?;
+
+            let group_data = group_asset_info.try_borrow_data()?;
+
+            // Check if royalties extension is present on the group asset and validate the constraint.
+            process_royalties!(
+                account,
+                ctx.accounts.signer,
+                ctx.accounts.recipient,
+                caller,
+                &rule_sets,
+                &mut payments,
+                is_primary_sale,
+                &data,
+                &group_data
+            );
+        }
+
+        // The asset is loaded again to be updated.
+        let (asset, extensions) = data.split_at_mut(Asset::LEN);
+        let asset = Asset::load_mut(asset);
+
+        // Transfer the asset.
+        asset.owner = *ctx.accounts.recipient.key();
+
+        // Clear the delegate.
+        asset.delegate = PodOption::new(Delegate::default());
+
+        // Clear the delegates list (if any).
+        if let Some(mut delegates) = Extension::get_mut::<DelegatesMut>(extensions) {
+            delegates.clear();
+        }
+
+        // Clear the delegate expiries (if any).
+        if let Some(mut expiry) = Extension::get_mut::<DelegateExpiryMut>(extensions) {
+            expiry.clear();
+        }
+
+        // Consume the royalty payment (if any).
+        if let Some(mut payment) = Extension::get_mut::<RoyaltyPaymentMut>(extensions) {
+            payment.clear();
+        }
+
+        // The first transfer away from the authority and creators is the primary sale.
+        update_primary_sale(
+            &asset.authority,
+            extensions,
+            group_account,
+            ctx.accounts.recipient.key(),
+        )?;
+
+        transferred += 1;
+    }
+
+    msg!("Transferred {} asset(s)", transferred);
+
+    Ok(())
+}
@@ -1,15 +1,19 @@
 use nifty_asset_types::{
-    extensions::{Extension, Manager},
-    podded::ZeroCopy,
+    extensions::{Action, DelegateExpiry, Delegates, Extension, Manager, Rental},
+    podded::{pod::Nullable, ZeroCopy},
     state::{Asset, DelegateRole, Discriminator, State},
 };
+use nitrate::program::AccountInfo;
 use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     error::AssetError,
     instruction::accounts::{Context, Unlock},
     require,
-    utils::assert_delegate,
+    utils::{
+        assert_delegate, assert_guard, get_caller, get_rule_sets, is_expired,
+        split_trailing_accounts,
+    },
 };
 
 /// Unlocks an asset.
@@ -18,7 +22,16 @@ use crate::{
 ///
 ///   0. `[writable]` asset
 ///   1. `[signer]` signer
-pub fn process_unlock(program_id: &Pubkey, ctx: Context<Unlock>) -> ProgramResult {
+///
+/// The instructions sysvar and the rule set accounts can be provided as (optional) trailing
+/// accounts to evaluate guard constraints on the calling program or external rule sets.
+pub fn process_unlock(
+    program_id: &Pubkey,
+    ctx: Context<Unlock>,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
     // account validation
 
     require!(
@@ -41,19 +54,58 @@ pub fn process_unlock(program_id: &Pubkey, ctx: Context<Unlock>) -> ProgramResul
         "asset"
     );
 
+    // Program invoking the instruction and rule sets referenced by `External` constraints.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
+
+    // the asset must satisfy its unlock guard (if any)
+    assert_guard(
+        Action::Unlock,
+        &data,
+        ctx.accounts.asset,
+        ctx.accounts.signer,
+        None,
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
     // unlocks the asset
 
     let (asset, extensions) = data.split_at_mut(Asset::LEN);
     let asset = Asset::load_mut(asset);
+
     let manager = Extension::get::<Manager>(extensions).map(|s| s.delegate);
+    let expiry = Extension::get::<DelegateExpiry>(extensions);
+    let delegates = Extension::get::<Delegates>(extensions);
+    let delegates = delegates.iter().flat_map(|delegates| delegates.values);
+
+    // an expired delegate is treated as if the asset did not have a delegate
+    let delegate = match asset.delegate.value() {
+        Some(delegate) if !is_expired(delegate, expiry.as_ref())? => Some(delegate),
+        _ => None,
+    };
+
+    let mut has_delegate = delegate.is_some();
+
+    for delegate in delegates.clone().filter(|delegate| delegate.is_some()) {
+        has_delegate |= !is_expired(delegate, expiry.as_ref())?;
+    }
+
+    // a rented asset remains locked until it is reclaimed
+    require!(
+        !Extension::get::<Rental>(extensions).is_some_and(|rental| rental.is_active()),
+        AssetError::LockedAsset,
+        "asset is rented"
+    );
 
     // Validate whether signer is the owner or a lock delegate.
     //
-    // if the asset has a delegate, the signer must be the delegate or the manager
-    // delegate (if there is one)
-    if asset.delegate.value().is_some() {
+    // if the asset has a delegate, the signer must be one of the delegates or the
+    // manager delegate (if there is one)
+    if has_delegate {
         assert_delegate(
-            &[asset.delegate.value(), manager],
+            delegate.into_iter().chain(manager).chain(delegates),
+            expiry.as_ref(),
             ctx.accounts.signer.key(),
             DelegateRole::Lock,
         )?;
@@ -61,7 +113,12 @@ pub fn process_unlock(program_id: &Pubkey, ctx: Context<Unlock>) -> ProgramResul
     // otherwise, if the signer is not the owner, the signer must be the
     // manager delegate
     else if asset.owner != *ctx.accounts.signer.key() {
-        assert_delegate(&[manager], ctx.accounts.signer.key(), DelegateRole::Lock)?;
+        assert_delegate(
+            manager,
+            expiry.as_ref(),
+            ctx.accounts.signer.key(),
+            DelegateRole::Lock,
+        )?;
     }
 
     asset.state = State::Unlocked;
@@ -1,10 +1,11 @@
 use std::cmp::Ordering;
 
 use nifty_asset_types::{
-    extensions::{on_create, on_update, Extension, ExtensionType},
+    extensions::{on_create, on_update, Action, Extension, ExtensionType, UpdateDelegate},
     podded::{pod::PodBool, ZeroCopy},
     state::{Asset, Discriminator},
 };
+use nitrate::program::AccountInfo;
 use solana_program::{
     entrypoint::ProgramResult,
     msg,
@@ -22,6 +23,10 @@ use crate::{
     },
     processor::resize,
     require,
+    utils::{
+        assert_extension_authority, assert_guard, get_caller, get_rule_sets,
+        split_trailing_accounts,
+    },
 };
 
 /// Updates an asset's metadata.
@@ -31,18 +36,29 @@ use crate::{
 /// data can be specified as instruction args or through a buffer account. The "layout"
 /// of the buffer is the same as as the one created by the `Allocate` instruction.
 ///
+/// Extensions that have their own authority (see `SetExtensionAuthority`) can only be updated
+/// by that authority. When the asset has an `UpdateDelegate` extension, the delegate can sign
+/// in place of the asset authority to update the extension types that it has been granted.
+/// Immutable extensions cannot be updated.
+///
 /// ### Accounts:
 ///
 ///   0. `[writable]` asset
-///   1. `[signer]` authority
+///   1. `[signer]` authority (or update delegate)
 ///   2. `[writable, signer, optional]` payer
 ///   3. `[optional]` system_program
+///
+/// The instructions sysvar and the rule set accounts can be provided as (optional) trailing
+/// accounts to evaluate guard constraints on the calling program or external rule sets.
 #[inline(always)]
 pub fn process_update(
     program_id: &Pubkey,
     ctx: Context<Update>,
+    remaining_accounts: &[AccountInfo],
     args: UpdateInput,
 ) -> ProgramResult {
+    let (remaining_accounts, trailing) = split_trailing_accounts(program_id, remaining_accounts);
+
     // account validation
 
     require!(
@@ -65,13 +81,36 @@ pub fn process_update(
         "asset"
     );
 
-    let asset = Asset::load_mut(&mut account_data);
+    // Program invoking the instruction and rule sets referenced by `External` constraints.
+    let caller = get_caller(trailing.sysvar_instructions, remaining_accounts)?;
+    let rule_sets = get_rule_sets(program_id, &trailing.rule_sets)?;
+
+    // the asset must satisfy its update guard (if any)
+    assert_guard(
+        Action::Update,
+        &account_data,
+        ctx.accounts.asset,
+        ctx.accounts.authority,
+        None,
+        caller.as_ref(),
+        &rule_sets,
+    )?;
+
+    // the name and mutable flag can only be updated by the authority of the asset; other
+    // signers (extension authorities or the update delegate) can only update extensions
+    let is_authority = Asset::load(&account_data).authority == *ctx.accounts.authority.key();
+
+    if !is_authority {
+        require!(
+            args.name.is_none()
+                && args.mutable.is_none()
+                && (args.extension.is_some() || ctx.accounts.buffer.is_some()),
+            AssetError::InvalidAuthority,
+            "authority"
+        );
+    }
 
-    require!(
-        asset.authority == *ctx.accounts.authority.key(),
-        AssetError::InvalidAuthority,
-        "authority"
-    );
+    let asset = Asset::load_mut(&mut account_data);
 
     require!(
         <PodBool as Into<bool>>::into(asset.mutable),
@@ -178,6 +217,38 @@ pub fn process_update(
             (0, offset, false)
         };
 
+        // the signer must be the authority of the extension, which can be the authority
+        // of the asset or a specific authority (when set); the update delegate can only
+        // update the extension types it has been granted
+        let header = if update {
+            Some(Extension::load(
+                &account_data[offset..offset + Extension::LEN],
+            ))
+        } else {
+            None
+        };
+
+        require!(
+            !matches!(header, Some(header) if header.is_immutable()),
+            AssetError::ImmutableExtension,
+            "[{:?}] extension is immutable",
+            extension_type
+        );
+
+        if let Some(header) = header.filter(|header| header.authority() > 0) {
+            assert_extension_authority(&account_data, header, ctx.accounts.authority.key())?;
+        } else if !is_authority {
+            require!(
+                Asset::get::<UpdateDelegate>(&account_data).map(|delegate| {
+                    delegate.delegate == ctx.accounts.authority.key()
+                        && delegate.contains(extension_type)
+                }) == Some(true),
+                AssetError::InvalidAuthority,
+                "authority cannot update {:?} extension",
+                extension_type
+            );
+        }
+
         let (extension_length, extension_data) = if let Some(buffer) = ctx.accounts.buffer {
             let extension_data = buffer.try_borrow_data()?;
             let (header, _) =
@@ -343,6 +414,12 @@ pub fn process_update(
         extension.set_length(extension_length as u32);
         extension.set_boundary(updated_boundary as u32);
 
+        if !update {
+            // new extensions are controlled by the authority of the asset
+            extension.set_authority(0);
+            extension.set_flags(0);
+        }
+
         // updates the boundaries of any subsequent extensions
         let mut cursor = updated_boundary;
 
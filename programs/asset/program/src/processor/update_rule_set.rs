@@ -0,0 +1,75 @@
+use nifty_asset_types::{
+    podded::ZeroCopy,
+    state::{Discriminator, RuleSet},
+};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::AssetError,
+    instruction::{
+        accounts::{Context, UpdateRuleSet},
+        RuleSetInput,
+    },
+    require,
+};
+
+/// Updates the constraint of a rule set account.
+///
+/// The rule set account is resized to fit the new constraint; the payer and system
+/// program accounts are required when the size of the account increases.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` rule_set
+///   1. `[signer]` authority
+///   2. `[writable, signer, optional]` payer
+///   3. `[optional]` system_program
+pub fn process_update_rule_set(
+    program_id: &Pubkey,
+    ctx: Context<UpdateRuleSet>,
+    args: RuleSetInput,
+) -> ProgramResult {
+    // account validation
+
+    require!(
+        ctx.accounts.authority.is_signer(),
+        ProgramError::MissingRequiredSignature,
+        "authority"
+    );
+
+    require!(
+        ctx.accounts.rule_set.owner() == program_id,
+        ProgramError::IllegalOwner,
+        "rule_set"
+    );
+
+    {
+        let data = ctx.accounts.rule_set.try_borrow_data()?;
+
+        require!(
+            data.len() >= RuleSet::LEN && data[0] == u8::from(Discriminator::RuleSet),
+            AssetError::Uninitialized,
+            "rule_set"
+        );
+
+        require!(
+            RuleSet::load(&data[..RuleSet::LEN]).authority == *ctx.accounts.authority.key(),
+            AssetError::InvalidAuthority,
+            "authority"
+        );
+    }
+
+    super::validate_rule_set_constraint(&args.constraint)?;
+
+    super::resize(
+        RuleSet::LEN + args.constraint.len(),
+        ctx.accounts.rule_set,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    let mut data = ctx.accounts.rule_set.try_borrow_mut_data()?;
+    data[RuleSet::LEN..].copy_from_slice(&args.constraint);
+
+    Ok(())
+}
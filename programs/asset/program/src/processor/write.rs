@@ -78,6 +78,13 @@ pub(crate) fn process_write(
     let current = extension.extension_type();
     let expected = offset.saturating_add(extension.length() as usize);
     let boundary = extension.boundary();
+
+    require!(
+        !extension.is_immutable(),
+        AssetError::ImmutableExtension,
+        "[{:?}] extension is immutable",
+        current
+    );
     // the length of the account cannot be larger than the current extension length,
     // otherwise we would be writting data to an extension that does not exist
     if expected < asset_data.len() {
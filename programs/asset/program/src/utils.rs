@@ -1,11 +1,28 @@
 use nifty_asset_types::{
-    constraints::Target,
-    state::{Delegate, DelegateRole},
+    constraints::{Assertable, Assertion, Context as ConstraintContext, Target},
+    extensions::{
+        Action, Authorities, Creators, DelegateExpiry, Extension, ExtensionType, Guards,
+        PrimarySale, PrimarySaleMut, RoyaltyPayment, TransferHook,
+    },
+    podded::{pod::Nullable, ZeroCopy},
+    state::{Asset, Delegate, DelegateRole, Discriminator, RuleSet, State, WALLET_SEED},
+};
+use nitrate::program::{
+    cpi::{CAccountInfo, CAccountMeta, CInstruction},
+    AccountInfo,
+};
+use solana_program::{
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{instructions, Sysvar},
 };
-use nitrate::program::AccountInfo;
-use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey, system_program};
 
-use crate::{error::AssetError, require};
+use crate::{err, error::AssetError, require};
 
 #[inline(always)]
 pub fn close_program_account(account: &AccountInfo, recipient: &AccountInfo) -> ProgramResult {
@@ -26,47 +43,410 @@ pub fn close_program_account(account: &AccountInfo, recipient: &AccountInfo) ->
     Ok(())
 }
 
-#[allow(clippy::manual_try_fold)]
+/// Checks whether a delegate has expired.
+///
+/// A delegate can only expire if the `DelegateExpiry` extension is present and
+/// has an entry for the delegate address.
+#[inline(always)]
+pub fn is_expired(
+    delegate: &Delegate,
+    expiry: Option<&DelegateExpiry>,
+) -> Result<bool, ProgramError> {
+    if let Some(expiry) = expiry {
+        if expiry.get(&delegate.address).is_some() {
+            return Ok(expiry.is_expired(&delegate.address, Clock::get()?.unix_timestamp));
+        }
+    }
+
+    Ok(false)
+}
+
+/// Asserts that the `target` is an active delegate for the specified `role`.
+///
+/// The `target` is searched among all `delegates` and the assertion succeeds if any
+/// of the matching delegates has the `role` active and has not expired.
 #[inline(always)]
-pub fn assert_delegate(
-    delegates: &[Option<&Delegate>],
+pub fn assert_delegate<'a>(
+    delegates: impl IntoIterator<Item = &'a Delegate>,
+    expiry: Option<&DelegateExpiry>,
     target: &Pubkey,
     role: DelegateRole,
 ) -> ProgramResult {
-    delegates.iter().fold(
-        Err(AssetError::DelegateNotFound.into()),
-        |result, delegate| {
-            if let Some(delegate) = delegate {
-                require!(
-                    *delegate.address == *target,
-                    AssetError::InvalidDelegate,
-                    "invalid delegate"
-                );
-
-                require!(
-                    delegate.is_active(role),
-                    AssetError::DelegateRoleNotActive,
-                    "missing \"{:?}\" role",
-                    role
-                );
-
-                Ok(())
-            } else {
-                result
+    let mut error = AssetError::DelegateNotFound;
+
+    for delegate in delegates.into_iter().filter(|delegate| delegate.is_some()) {
+        if *delegate.address != *target {
+            if error == AssetError::DelegateNotFound {
+                error = AssetError::InvalidDelegate;
             }
-        },
-    )
+        } else if !delegate.is_active(role) {
+            error = AssetError::DelegateRoleNotActive;
+        } else if is_expired(delegate, expiry)? {
+            error = AssetError::DelegateExpired;
+        } else {
+            return Ok(());
+        }
+    }
+
+    match error {
+        AssetError::InvalidDelegate => err!(error, "invalid delegate"),
+        AssetError::DelegateRoleNotActive => err!(error, "missing \"{:?}\" role", role),
+        AssetError::DelegateExpired => err!(error, "delegate expired"),
+        _ => err!(error),
+    }
+}
+
+/// Asserts that the `signer` is the authority of an extension.
+///
+/// An extension is controlled by the authority of the asset unless its header references
+/// an entry on the `Authorities` extension table.
+#[inline(always)]
+pub fn assert_extension_authority(
+    data: &[u8],
+    extension: &Extension,
+    signer: &Pubkey,
+) -> ProgramResult {
+    let is_authority = match extension.authority() {
+        0 => Asset::load(data).authority == *signer,
+        index => Asset::get::<Authorities>(data)
+            .and_then(|authorities| authorities.get(index).map(|authority| authority == signer))
+            .unwrap_or_default(),
+    };
+
+    require!(is_authority, AssetError::InvalidAuthority, "authority");
+
+    Ok(())
+}
+
+/// Asserts that the group of an asset is not locked.
+///
+/// The group account must be provided when an asset is part of a group, since locking
+/// the group asset locks all of its members.
+#[inline(always)]
+pub fn assert_group_unlocked(
+    program_id: &Pubkey,
+    group: &Pubkey,
+    group_account: Option<&AccountInfo>,
+) -> ProgramResult {
+    let group_account = group_account.ok_or_else(|| {
+        msg!("[ERROR] Missing group account \"{}\"", group);
+        ProgramError::NotEnoughAccountKeys
+    })?;
+
+    require!(
+        group_account.key() == group,
+        AssetError::InvalidGroup,
+        "group account does not match the asset group"
+    );
+
+    require!(
+        group_account.owner() == program_id,
+        AssetError::InvalidGroup,
+        "group account is not owned by the program"
+    );
+
+    let data = group_account.try_borrow_data()?;
+
+    require!(
+        data.len() >= Asset::LEN && data[0] == u8::from(Discriminator::Asset),
+        AssetError::InvalidGroup,
+        "group account is not initialized"
+    );
+
+    require!(
+        Asset::load(&data).state != State::Locked,
+        AssetError::LockedAsset,
+        "group \"{}\" is locked",
+        group
+    );
+
+    require!(
+        !is_group_frozen(&data),
+        AssetError::LockedAsset,
+        "group \"{}\" is frozen",
+        group
+    );
+
+    Ok(())
+}
+
+/// Indicates whether a group asset was frozen by its authority.
+#[inline(always)]
+pub fn is_group_frozen(data: &[u8]) -> bool {
+    Asset::get_extension(ExtensionType::Grouping, data)
+        .map(|(extension, _)| extension.is_frozen())
+        .unwrap_or_default()
+}
+
+/// Sets or clears the frozen flag of a group asset.
+///
+/// The flag is stored on the header of the `Grouping` extension, so it does not affect
+/// the state of the group asset.
+#[inline(always)]
+pub fn set_group_frozen(data: &mut [u8], frozen: bool) -> ProgramResult {
+    let (_, offset) = Asset::get_extension(ExtensionType::Grouping, data).ok_or_else(|| {
+        msg!("[ERROR] Missing required [Grouping] extension");
+        AssetError::ExtensionNotFound
+    })?;
+
+    let extension = Extension::load_mut(&mut data[offset - Extension::LEN..offset]);
+
+    if frozen {
+        extension.set_flags(extension.flags() | Extension::FROZEN);
+    } else {
+        extension.set_flags(extension.flags() & !Extension::FROZEN);
+    }
+
+    Ok(())
+}
+
+/// Returns the optional account at `index` of the remaining accounts.
+///
+/// Optional remaining accounts follow the same convention as optional instruction
+/// accounts: a missing account is represented by the program id.
+#[inline(always)]
+pub fn get_optional_account(accounts: &[AccountInfo], index: usize) -> Option<&AccountInfo> {
+    accounts
+        .get(index)
+        .filter(|account| *account.key() != crate::ID)
+}
+
+/// Returns the position of the `signer` in the ownership chain of an asset.
+///
+/// An asset owned by another asset (nested asset) is controlled by the owner of its
+/// top-level parent. The parent chain is read from `parents` in order, starting with the
+/// parent of the asset, and a locked parent locks all of its children.
+///
+/// A depth of `0` means that the signer owns the asset directly, while `None` means that
+/// the signer is not an owner. Program-owned accounts (e.g., an asset signing with its own
+/// keypair) never act as owners.
+#[inline(always)]
+pub fn get_owner_depth(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    signer: &AccountInfo,
+    parents: &[AccountInfo],
+) -> Result<Option<usize>, ProgramError> {
+    if signer.owner() == program_id {
+        return Ok(None);
+    }
+
+    let mut owner = *owner;
+
+    for (depth, parent) in parents.iter().enumerate() {
+        if owner == *signer.key() {
+            return Ok(Some(depth));
+        }
+
+        if parent.key() != &owner || parent.owner() != program_id {
+            return Ok(None);
+        }
+
+        let data = parent.try_borrow_data()?;
+
+        if data.len() < Asset::LEN || data[0] != u8::from(Discriminator::Asset) {
+            return Ok(None);
+        }
+
+        let parent = Asset::load(&data);
+
+        require!(
+            parent.state != State::Locked,
+            AssetError::LockedAsset,
+            "parent asset \"{}\" is locked",
+            owner
+        );
+
+        owner = parent.owner;
+    }
+
+    Ok((owner == *signer.key()).then_some(parents.len()))
+}
+
+/// Asserts that an asset is not nested into itself.
+///
+/// An asset cannot be transferred to its own address or to one of its children, since the
+/// assets would own each other and could never be transferred again. Only direct children
+/// of the asset are checked.
+#[inline(always)]
+pub fn assert_not_self_nested(
+    program_id: &Pubkey,
+    asset: &Pubkey,
+    recipient: &AccountInfo,
+) -> ProgramResult {
+    require!(
+        recipient.key() != asset,
+        ProgramError::InvalidArgument,
+        "asset cannot own itself"
+    );
+
+    if recipient.owner() == program_id {
+        let data = recipient.try_borrow_data()?;
+
+        require!(
+            data.len() < Asset::LEN
+                || data[0] != u8::from(Discriminator::Asset)
+                || Asset::load(&data).owner != *asset,
+            ProgramError::InvalidArgument,
+            "asset cannot be nested into one of its children"
+        );
+    }
+
+    Ok(())
+}
+
+/// Invokes the transfer hook program of an asset.
+///
+/// The `accounts` (asset, signer and recipient) are passed to the hook program as read-only,
+/// non-signer accounts to avoid escalating their privileges; `extra_accounts` are passed with
+/// their original privileges. A failure of the hook program causes the transfer to fail.
+#[inline(always)]
+pub fn invoke_transfer_hook(
+    program: &AccountInfo,
+    accounts: &[&AccountInfo],
+    extra_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let instruction_accounts: Vec<CAccountMeta> = accounts
+        .iter()
+        .map(|account| CAccountMeta {
+            pubkey: account.key(),
+            is_writable: false,
+            is_signer: false,
+        })
+        .chain(extra_accounts.iter().map(CAccountMeta::from))
+        .collect();
+
+    let instruction_data = TransferHook::EXECUTE_DISCRIMINATOR;
+
+    let instruction = CInstruction {
+        program_id: program.key(),
+        accounts: instruction_accounts.as_ptr(),
+        accounts_len: instruction_accounts.len() as u64,
+        data: instruction_data.as_ptr(),
+        data_len: instruction_data.len() as u64,
+    };
+
+    // account infos (including the hook program) and seeds
+    let account_infos: Vec<CAccountInfo> = accounts
+        .iter()
+        .map(|account| CAccountInfo::from(*account))
+        .chain(extra_accounts.iter().map(CAccountInfo::from))
+        .chain(std::iter::once(CAccountInfo::from(program)))
+        .collect();
+    let seeds: &[&[&[u8]]] = &[];
+
+    #[cfg(target_os = "solana")]
+    unsafe {
+        let result = solana_program::syscalls::sol_invoke_signed_c(
+            &instruction as *const CInstruction as *const u8,
+            account_infos.as_ptr() as *const u8,
+            account_infos.len() as u64,
+            seeds.as_ptr() as *const u8,
+            seeds.len() as u64,
+        );
+
+        if result != solana_program::entrypoint::SUCCESS {
+            return Err(ProgramError::from(result));
+        }
+    }
+
+    // keep clippy happy
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box(&(&instruction, &account_infos, &seeds));
+
+    Ok(())
+}
+
+/// Invokes a program signing as the wallet of an asset.
+///
+/// The `accounts` are passed to the program with their original privileges, except for
+/// the wallet account, which is passed as a signer using the seeds of the wallet PDA. The
+/// signer privilege is only granted to the wallet of `asset`, since the seeds cannot sign
+/// for any other account.
+#[inline(always)]
+pub fn invoke_as_wallet(
+    program: &AccountInfo,
+    asset: &Pubkey,
+    wallet: &Pubkey,
+    bump: u8,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let instruction_accounts: Vec<CAccountMeta> = accounts
+        .iter()
+        .map(|account| CAccountMeta {
+            pubkey: account.key(),
+            is_writable: account.is_writable(),
+            // only the wallet is promoted to a signer; other accounts keep the signer
+            // privilege of the outer instruction
+            is_signer: account.is_signer() || account.key() == wallet,
+        })
+        .collect();
+
+    let instruction = CInstruction {
+        program_id: program.key(),
+        accounts: instruction_accounts.as_ptr(),
+        accounts_len: instruction_accounts.len() as u64,
+        data: data.as_ptr(),
+        data_len: data.len() as u64,
+    };
+
+    // account infos (including the invoked program) and seeds
+    let account_infos: Vec<CAccountInfo> = accounts
+        .iter()
+        .map(CAccountInfo::from)
+        .chain(std::iter::once(CAccountInfo::from(program)))
+        .collect();
+    let bump = [bump];
+    let seeds: &[&[&[u8]]] = &[&[WALLET_SEED, asset.as_ref(), &bump]];
+
+    #[cfg(target_os = "solana")]
+    unsafe {
+        let result = solana_program::syscalls::sol_invoke_signed_c(
+            &instruction as *const CInstruction as *const u8,
+            account_infos.as_ptr() as *const u8,
+            account_infos.len() as u64,
+            seeds.as_ptr() as *const u8,
+            seeds.len() as u64,
+        );
+
+        if result != solana_program::entrypoint::SUCCESS {
+            return Err(ProgramError::from(result));
+        }
+    }
+
+    // keep clippy happy
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box(&(&instruction, &account_infos, &seeds));
+
+    Ok(())
 }
 
 #[macro_export]
 macro_rules! process_royalties {
-    ( $ctx:expr, $data:expr) => {{
+    ( $ctx:expr, $caller:expr, $rule_sets:expr, $payments:expr, $is_primary_sale:expr, $asset_data:expr, $data:expr) => {{
+        process_royalties!(
+            $ctx.accounts.asset,
+            $ctx.accounts.signer,
+            $ctx.accounts.recipient,
+            $caller,
+            $rule_sets,
+            $payments,
+            $is_primary_sale,
+            $asset_data,
+            $data
+        )
+    }};
+    // The royalties are read from `$data`, which is either the data of the asset or its
+    // group; `$asset_data` is the (already borrowed) data of the asset.
+    ( $asset:expr, $signer:expr, $recipient:expr, $caller:expr, $rule_sets:expr, $payments:expr, $is_primary_sale:expr, $asset_data:expr, $data:expr) => {{
         // Check if royalties extension is present.
         if let Some(royalties) = Asset::get::<Royalties>($data) {
             // Check if the recipient is allowed to receive the asset.
 
             // Wallet-to-wallet transfers between system program accounts are exempt from the
-            // royalty check so we need to exclude them.
+            // royalties constraint so we need to exclude them. The exemption does not apply to
+            // the payment mode, since a payment does not depend on the recipient.
             //
             // To determine if the transaction is a wallet-to-wallet transfer, we check:
             //
@@ -75,25 +455,75 @@ macro_rules! process_royalties {
             //
             //   2. Are both the sender and the recipient system program accounts?
             let is_wallet_to_wallet = !(get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT)
-                && ($ctx.accounts.signer.owner() == &solana_program::system_program::ID)
-                && ($ctx.accounts.recipient.owner() == &solana_program::system_program::ID);
-
-            if !is_wallet_to_wallet {
-                #[cfg(feature = "logging")]
-                solana_program::msg!("Checking royalties constraint");
-
-                // We pass in the `ConstraintContext` and validate the royalties constraint.
-                let result = royalties.constraint.assertable.assert(&ConstraintContext {
-                    asset: &$crate::utils::Account($ctx.accounts.asset),
-                    authority: &$crate::utils::Account($ctx.accounts.signer),
-                    recipient: Some(&$crate::utils::Account($ctx.accounts.recipient)),
-                })?;
-
-                require!(
-                    result == Assertion::Pass,
-                    AssetError::AssertionFailure,
-                    "constraint failed"
-                );
+                && ($signer.owner() == &solana_program::system_program::ID)
+                && ($recipient.owner() == &solana_program::system_program::ID);
+
+            let mode = royalties.mode().map_err(|error| {
+                solana_program::msg!("[ERROR] {}", error);
+                AssetError::ExtensionDataInvalid
+            })?;
+
+            // Royalties are not enforced on the primary sale of the asset when the primary
+            // rate is zero.
+            let is_exempt = $is_primary_sale && royalties.rate(true) == 0;
+
+            if !is_exempt {
+                match mode {
+                    nifty_asset_types::extensions::RoyaltiesMode::Constraint
+                        if !is_wallet_to_wallet =>
+                    {
+                        #[cfg(feature = "logging")]
+                        solana_program::msg!("Checking royalties constraint");
+
+                        let rule_sets = $rule_sets
+                            .iter()
+                            .map(|rule_set| rule_set as &dyn nifty_asset_types::constraints::Target)
+                            .collect::<Vec<_>>();
+
+                        // We pass in the `ConstraintContext` and validate the royalties constraint.
+                        let result =
+                            royalties.constraint.assertable.assert(&ConstraintContext {
+                                asset: &$crate::utils::BorrowedAccount {
+                                    account: $asset,
+                                    data: $asset_data,
+                                },
+                                authority: &$crate::utils::Account($signer),
+                                recipient: Some(&$crate::utils::Account($recipient)),
+                                caller: $caller.as_ref().map(|caller| {
+                                    caller as &dyn nifty_asset_types::constraints::Target
+                                }),
+                                rule_sets: &rule_sets,
+                            })?;
+
+                        require!(
+                            result == Assertion::Pass,
+                            AssetError::AssertionFailure,
+                            "constraint failed"
+                        );
+                    }
+                    nifty_asset_types::extensions::RoyaltiesMode::Payment => {
+                        #[cfg(feature = "logging")]
+                        solana_program::msg!("Checking royalties payment");
+
+                        // Creators are read from the same account as the royalties, falling
+                        // back to the asset.
+                        let creators = Asset::get::<nifty_asset_types::extensions::Creators>($data)
+                            .or_else(|| {
+                                Asset::get::<nifty_asset_types::extensions::Creators>($asset_data)
+                            });
+                        // The payment is consumed, so it cannot be used by another transfer.
+                        let paid = $payments.consume(creators.as_ref(), *royalties.amount);
+
+                        require!(
+                            paid >= *royalties.amount,
+                            AssetError::AssertionFailure,
+                            "royalties payment of {} lamports is below the minimum of {}",
+                            paid,
+                            royalties.amount
+                        );
+                    }
+                    _ => (),
+                }
             }
 
             // royalties checked
@@ -105,6 +535,56 @@ macro_rules! process_royalties {
     }};
 }
 
+/// Asserts the constraint guarding an action on an asset.
+///
+/// The constraint is read from the `Guards` extension of the asset `data`. When the asset
+/// does not have a guard for the action, the assertion always succeeds.
+pub fn assert_guard(
+    action: Action,
+    data: &[u8],
+    asset: &AccountInfo,
+    signer: &AccountInfo,
+    recipient: Option<&AccountInfo>,
+    caller: Option<&Caller>,
+    rule_sets: &[Account],
+) -> ProgramResult {
+    let guards = Asset::get::<Guards>(data);
+
+    if let Some(constraint) = guards.as_ref().and_then(|guards| guards.get(action)) {
+        #[cfg(feature = "logging")]
+        msg!("Checking {:?} guard", action);
+
+        let recipient = recipient.map(Account);
+        let rule_sets = rule_sets
+            .iter()
+            .map(|rule_set| rule_set as &dyn Target)
+            .collect::<Vec<_>>();
+
+        let result = constraint.assert(&ConstraintContext {
+            asset: &BorrowedAccount {
+                account: asset,
+                data,
+            },
+            authority: &Account(signer),
+            recipient: recipient.as_ref().map(|recipient| recipient as &dyn Target),
+            caller: caller.map(|caller| caller as &dyn Target),
+            rule_sets: &rule_sets,
+        })?;
+
+        require!(
+            result == Assertion::Pass,
+            AssetError::AssertionFailure,
+            "{:?} guard failed",
+            action
+        );
+    }
+
+    Ok(())
+}
+
+/// Target of an account whose data is not borrowed by the instruction.
+///
+/// Accounts with borrowed data (e.g., the asset) must use a [`BorrowedAccount`] instead.
 pub struct Account<'a>(pub &'a AccountInfo);
 
 impl Target for Account<'_> {
@@ -122,4 +602,442 @@ impl Target for Account<'_> {
     fn owner(&self) -> &Pubkey {
         self.0.owner()
     }
+
+    #[inline(always)]
+    fn data(&self) -> &[u8] {
+        // SAFETY: the data is only read while evaluating a constraint and the account
+        // data is not mutably borrowed by the instruction.
+        unsafe { self.0.unchecked_borrow_data() }
+    }
+}
+
+/// Target of an account whose data is already borrowed by the instruction.
+///
+/// Constraints read the borrowed `data` instead of borrowing the account data again, which
+/// would alias a mutable borrow held by the instruction.
+pub struct BorrowedAccount<'a> {
+    /// The account.
+    pub account: &'a AccountInfo,
+
+    /// The borrowed data of the account.
+    pub data: &'a [u8],
+}
+
+impl Target for BorrowedAccount<'_> {
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    #[inline(always)]
+    fn key(&self) -> &Pubkey {
+        self.account.key()
+    }
+
+    #[inline(always)]
+    fn owner(&self) -> &Pubkey {
+        self.account.owner()
+    }
+
+    #[inline(always)]
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+}
+
+/// Target representing the program of the top-level instruction.
+///
+/// The instructions sysvar only provides the address of the program, so the owner and
+/// data of the program are only available when the program account is provided to the
+/// instruction; otherwise, the caller is treated as an empty (system) account.
+pub struct Caller<'a> {
+    /// Address of the program.
+    pub program: Pubkey,
+
+    /// The program account (if provided).
+    pub account: Option<&'a AccountInfo>,
+}
+
+impl Target for Caller<'_> {
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.account
+            .map(|account| account.data_is_empty())
+            .unwrap_or(true)
+    }
+
+    #[inline(always)]
+    fn key(&self) -> &Pubkey {
+        &self.program
+    }
+
+    #[inline(always)]
+    fn owner(&self) -> &Pubkey {
+        // an empty account is owned by the system program
+        self.account
+            .map(|account| account.owner())
+            .unwrap_or(&system_program::ID)
+    }
+
+    #[inline(always)]
+    fn data(&self) -> &[u8] {
+        // SAFETY: the data is only read while evaluating a constraint.
+        self.account
+            .map(|account| unsafe { account.unchecked_borrow_data() })
+            .unwrap_or(&[])
+    }
+}
+
+/// Optional accounts appended to the accounts of an instruction.
+///
+/// Trailing accounts are identified by their address or data, so they can be provided
+/// (in any order) without changing the accounts expected by an instruction.
+#[derive(Default)]
+pub struct TrailingAccounts<'a> {
+    /// The instructions sysvar, used to determine the calling program.
+    pub sysvar_instructions: Option<&'a AccountInfo>,
+
+    /// The rule set accounts referenced by `External` constraints.
+    pub rule_sets: Vec<&'a AccountInfo>,
+}
+
+/// Splits the trailing accounts from the end of the remaining accounts.
+///
+/// Returns the remaining accounts without the trailing accounts.
+pub fn split_trailing_accounts<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo],
+) -> (&'a [AccountInfo], TrailingAccounts<'a>) {
+    let mut trailing = TrailingAccounts::default();
+    let mut end = accounts.len();
+
+    while let Some(account) = end.checked_sub(1).map(|index| &accounts[index]) {
+        if account.key() == &instructions::ID && trailing.sysvar_instructions.is_none() {
+            trailing.sysvar_instructions = Some(account);
+        } else if is_rule_set(program_id, account) {
+            trailing.rule_sets.push(account);
+        } else {
+            break;
+        }
+
+        end -= 1;
+    }
+
+    (&accounts[..end], trailing)
+}
+
+/// Indicates whether the account is a `RuleSet` account of the program.
+#[inline(always)]
+fn is_rule_set(program_id: &Pubkey, account: &AccountInfo) -> bool {
+    account.owner() == program_id
+        && account.try_borrow_data().is_ok_and(|data| {
+            data.first()
+                .is_some_and(|discriminator| *discriminator == u8::from(Discriminator::RuleSet))
+        })
+}
+
+/// Returns the program of the top-level instruction currently executing.
+///
+/// The program is read from the instructions sysvar: when the current instruction is
+/// invoked through a CPI, this is the program that initiated the invocation; otherwise it
+/// is the asset program itself. The program account is looked up on `accounts`, so its
+/// owner is available to constraints. Returns `None` when the sysvar account is not
+/// provided.
+pub fn get_caller<'a>(
+    sysvar_instructions: Option<&AccountInfo>,
+    accounts: &'a [AccountInfo],
+) -> Result<Option<Caller<'a>>, ProgramError> {
+    /// Length of a serialized account meta (flags and pubkey).
+    const ACCOUNT_META_LEN: usize = 1 + std::mem::size_of::<Pubkey>();
+
+    let sysvar_instructions = if let Some(sysvar_instructions) = sysvar_instructions {
+        sysvar_instructions
+    } else {
+        return Ok(None);
+    };
+
+    require!(
+        sysvar_instructions.key() == &instructions::ID,
+        ProgramError::UnsupportedSysvar,
+        "sysvar_instructions"
+    );
+
+    let data = sysvar_instructions.try_borrow_data()?;
+
+    let read_u16 = |offset: usize| {
+        data.get(offset..offset + std::mem::size_of::<u16>())
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+            .ok_or(ProgramError::InvalidAccountData)
+    };
+
+    // the index of the current instruction is stored on the last two bytes
+    let current = read_u16(
+        data.len()
+            .checked_sub(std::mem::size_of::<u16>())
+            .ok_or(ProgramError::InvalidAccountData)?,
+    )?;
+    // the offsets of each instruction follow the number of instructions
+    let offset = read_u16(std::mem::size_of::<u16>() * (current + 1))?;
+    // the program id follows the list of accounts of the instruction
+    let offset = offset + std::mem::size_of::<u16>() + read_u16(offset)? * ACCOUNT_META_LEN;
+
+    let program = data
+        .get(offset..offset + std::mem::size_of::<Pubkey>())
+        .and_then(|program| Pubkey::try_from(program).ok())
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(Some(Caller {
+        program,
+        account: accounts.iter().find(|account| account.key() == &program),
+    }))
+}
+
+/// Indicates whether the asset is in its primary sale.
+///
+/// Only assets with a `PrimarySale` extension have a primary sale, which lasts until the
+/// extension is flagged by [`update_primary_sale`].
+#[inline(always)]
+pub fn is_primary_sale(data: &[u8]) -> bool {
+    Asset::get::<PrimarySale>(data).is_some_and(|primary_sale| !primary_sale.happened())
+}
+
+/// Flags the primary sale of an asset as happened when transferring it to the `recipient`.
+///
+/// Transfers to the `authority` or to one of the creators of the asset are not sales, so
+/// they do not end the primary sale. Creators are read from the asset, falling back to
+/// its `group` (if any).
+pub fn update_primary_sale(
+    authority: &Pubkey,
+    extensions: &mut [u8],
+    group: Option<&AccountInfo>,
+    recipient: &Pubkey,
+) -> ProgramResult {
+    let in_primary_sale = Extension::get::<PrimarySale>(extensions)
+        .is_some_and(|primary_sale| !primary_sale.happened());
+
+    if !in_primary_sale || recipient == authority {
+        return Ok(());
+    }
+
+    let group_data = group.map(|group| group.try_borrow_data()).transpose()?;
+
+    let is_creator = Extension::get::<Creators>(extensions)
+        .or_else(|| group_data.as_ref().and_then(|d| Asset::get::<Creators>(d)))
+        .is_some_and(|creators| {
+            creators
+                .values
+                .iter()
+                .any(|creator| creator.address == *recipient)
+        });
+
+    if !is_creator {
+        if let Some(mut primary_sale) = Extension::get_mut::<PrimarySaleMut>(extensions) {
+            primary_sale.set_happened();
+        }
+    }
+
+    Ok(())
+}
+
+/// Royalty payments available to the transfer of an asset.
+///
+/// Payments are consumed when royalties are checked, so each payment can only be used by
+/// one transfer.
+#[derive(Default)]
+pub struct RoyaltyPayments {
+    /// Lamports paid to the creators by `PayRoyalties` instructions, recorded on the
+    /// `RoyaltyPayment` extension of the asset.
+    pub paid: u64,
+
+    /// Lamports transferred by system program `Transfer` instructions from one of the
+    /// payers, by recipient.
+    pub transfers: Vec<(Pubkey, u64)>,
+}
+
+impl RoyaltyPayments {
+    /// Sets the amount recorded on the `RoyaltyPayment` extension of an asset.
+    pub fn set_paid(&mut self, data: &[u8]) {
+        self.paid = Asset::get::<RoyaltyPayment>(data)
+            .map(|payment| *payment.amount)
+            .unwrap_or_default();
+    }
+
+    /// Consumes up to `amount` lamports paid to the `creators` of an asset.
+    ///
+    /// The amount recorded by `PayRoyalties` (if any) is consumed first, followed by the
+    /// lamports transferred to any of the creators. Returns the amount consumed.
+    pub fn consume(&mut self, creators: Option<&Creators>, amount: u64) -> u64 {
+        let mut consumed = self.paid.min(amount);
+        self.paid -= consumed;
+
+        if let Some(creators) = creators {
+            for (_, lamports) in self.transfers.iter_mut().filter(|(recipient, _)| {
+                creators
+                    .values
+                    .iter()
+                    .any(|creator| creator.address == *recipient)
+            }) {
+                let value = (*lamports).min(amount - consumed);
+                *lamports -= value;
+                consumed += value;
+            }
+        }
+
+        consumed
+    }
+}
+
+/// Returns the royalty payments in the same transaction.
+///
+/// The payments are the system program `Transfer` instructions funded by one of the
+/// `payers` (e.g., the signer or recipient of the transfer) to a different account. The
+/// amount recorded by `PayRoyalties` is set for each asset with `RoyaltyPayments::set_paid`.
+///
+/// Only transfers between the previous instruction of the program and the current
+/// instruction are included, so a transfer cannot pay for the royalties of a transfer in an
+/// earlier instruction; transfers are also not included when the current instruction is
+/// invoked through a CPI, since the calling program could invoke several transfers in the
+/// same instruction. Only top-level instructions are visible, so transfers made through a
+/// CPI (or SPL Token transfers) are not included. Transfers are not searched when the sysvar
+/// account is not provided.
+pub fn get_royalty_payments(
+    program_id: &Pubkey,
+    sysvar_instructions: Option<&AccountInfo>,
+    payers: &[&Pubkey],
+) -> Result<RoyaltyPayments, ProgramError> {
+    /// Discriminator of the system program `Transfer` instruction.
+    const SYSTEM_TRANSFER_DISCRIMINATOR: u32 = 2;
+    /// Length of a serialized account meta (flags and pubkey).
+    const ACCOUNT_META_LEN: usize = 1 + std::mem::size_of::<Pubkey>();
+    /// Index of the source account on the system program `Transfer` instruction.
+    const SOURCE_INDEX: usize = 0;
+    /// Index of the recipient account on the system program `Transfer` instruction.
+    const RECIPIENT_INDEX: usize = 1;
+
+    let mut payments = RoyaltyPayments::default();
+
+    let sysvar_instructions = match sysvar_instructions {
+        Some(sysvar_instructions) if get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT => {
+            sysvar_instructions
+        }
+        _ => return Ok(payments),
+    };
+
+    require!(
+        sysvar_instructions.key() == &instructions::ID,
+        ProgramError::UnsupportedSysvar,
+        "sysvar_instructions"
+    );
+
+    let data = sysvar_instructions.try_borrow_data()?;
+
+    let read_u16 = |offset: usize| {
+        data.get(offset..offset + std::mem::size_of::<u16>())
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+            .ok_or(ProgramError::InvalidAccountData)
+    };
+
+    let read_pubkey = |offset: usize| {
+        data.get(offset..offset + std::mem::size_of::<Pubkey>())
+            .ok_or(ProgramError::InvalidAccountData)
+    };
+
+    let read_u64 = |bytes: &[u8], offset: usize| {
+        bytes
+            .get(offset..offset + std::mem::size_of::<u64>())
+            .and_then(|value| value.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidAccountData)
+    };
+
+    // the index of the current instruction is stored on the last two bytes
+    let current = read_u16(
+        data.len()
+            .checked_sub(std::mem::size_of::<u16>())
+            .ok_or(ProgramError::InvalidAccountData)?,
+    )?;
+
+    for index in 0..current {
+        // the offsets of each instruction follow the number of instructions
+        let offset = read_u16(std::mem::size_of::<u16>() * (index + 1))?;
+        let accounts = read_u16(offset)?;
+        // the pubkey of an account follows its flags
+        let account_offset =
+            |index: usize| offset + std::mem::size_of::<u16>() + index * ACCOUNT_META_LEN + 1;
+        // the program id follows the list of accounts of the instruction
+        let program_offset = offset + std::mem::size_of::<u16>() + accounts * ACCOUNT_META_LEN;
+        let program = read_pubkey(program_offset)?;
+
+        // transfers preceding an instruction of the program could have paid for it
+        if program == program_id.as_ref() {
+            payments.transfers.clear();
+            continue;
+        }
+
+        if accounts <= RECIPIENT_INDEX || program != system_program::ID.as_ref() {
+            continue;
+        }
+
+        // the instruction data follows the program id (prefixed by its length)
+        let data_offset = program_offset + std::mem::size_of::<Pubkey>();
+        let instruction_data = data
+            .get(data_offset + std::mem::size_of::<u16>()..)
+            .and_then(|bytes| bytes.get(..read_u16(data_offset).ok()?))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        let discriminator = instruction_data
+            .get(..std::mem::size_of::<u32>())
+            .and_then(|discriminator| discriminator.try_into().ok())
+            .map(u32::from_le_bytes);
+
+        if discriminator == Some(SYSTEM_TRANSFER_DISCRIMINATOR) {
+            let source = read_pubkey(account_offset(SOURCE_INDEX))?;
+            let recipient = read_pubkey(account_offset(RECIPIENT_INDEX))?;
+
+            // only transfers funded by one of the payers to a different account count
+            // as a payment
+            if source != recipient && payers.iter().any(|payer| payer.as_ref() == source) {
+                let lamports = read_u64(instruction_data, std::mem::size_of::<u32>())?;
+                let recipient =
+                    Pubkey::try_from(recipient).map_err(|_| ProgramError::InvalidAccountData)?;
+
+                payments.transfers.push((recipient, lamports));
+            }
+        }
+    }
+
+    Ok(payments)
+}
+
+/// Returns the rule set accounts referenced by `External` constraints.
+///
+/// Each account must be an initialized `RuleSet` account owned by the program. The
+/// constraints look up the rule sets by their address, so any number of rule sets can be
+/// provided.
+pub fn get_rule_sets<'a>(
+    program_id: &Pubkey,
+    rule_sets: &[&'a AccountInfo],
+) -> Result<Vec<Account<'a>>, ProgramError> {
+    rule_sets
+        .iter()
+        .map(|rule_set| {
+            require!(
+                rule_set.owner() == program_id,
+                ProgramError::IllegalOwner,
+                "rule_set \"{}\"",
+                rule_set.key()
+            );
+
+            let data = rule_set.try_borrow_data()?;
+
+            require!(
+                data.len() >= RuleSet::LEN && data[0] == u8::from(Discriminator::RuleSet),
+                AssetError::Uninitialized,
+                "rule_set \"{}\"",
+                rule_set.key()
+            );
+
+            Ok(Account(rule_set))
+        })
+        .collect()
 }
@@ -0,0 +1,190 @@
+use std::{mem::size_of, ops::Deref};
+
+use crate::constraints::{
+    Assertable, Assertion, AssertionResult, Constraint, ConstraintBuilder, Context, FromBytes,
+    Operator, OperatorType,
+};
+
+use super::DEFAULT_CAPACITY;
+
+/// A list of constraints where at least `n` of them must succeed for the assertion to pass.
+pub struct AtLeast<'a> {
+    /// Minimum number of constraints that must succeed.
+    pub n: &'a u64,
+
+    pub constraints: Vec<Constraint<'a>>,
+}
+
+impl<'a> FromBytes<'a> for AtLeast<'a> {
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (n, bytes) = bytes.split_at(size_of::<u64>());
+        let mut constraints = Vec::with_capacity(DEFAULT_CAPACITY);
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            let constraint = Constraint::from_bytes(&bytes[offset..]);
+            offset += constraint.size();
+            constraints.push(constraint);
+        }
+
+        Self {
+            n: bytemuck::from_bytes(n),
+            constraints,
+        }
+    }
+}
+
+impl Assertable for AtLeast<'_> {
+    fn assert(&self, context: &Context) -> AssertionResult {
+        let mut passed = 0;
+
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            if passed >= *self.n {
+                break;
+            }
+            // not enough constraints left to reach the threshold
+            if passed + ((self.constraints.len() - index) as u64) < *self.n {
+                return Ok(Assertion::Failure);
+            }
+
+            if constraint.assert(context)? == Assertion::Pass {
+                passed += 1;
+            }
+        }
+
+        Ok(if passed >= *self.n {
+            Assertion::Pass
+        } else {
+            Assertion::Failure
+        })
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(DEFAULT_CAPACITY);
+        bytes.extend_from_slice(&self.n.to_le_bytes());
+        for constraint in &self.constraints {
+            bytes.extend_from_slice(constraint.as_bytes().as_ref());
+        }
+        bytes
+    }
+}
+
+/// Builder for an `AtLeast` constraint.
+pub struct AtLeastBuilder(Vec<u8>);
+
+impl Default for AtLeastBuilder {
+    fn default() -> Self {
+        Self(vec![0u8; size_of::<Operator>() + size_of::<u64>()])
+    }
+}
+
+impl AtLeastBuilder {
+    /// Set the minimum number of constraints that must succeed.
+    pub fn set_threshold(&mut self, n: u64) {
+        let offset = size_of::<Operator>();
+        self.0[offset..offset + size_of::<u64>()].copy_from_slice(&n.to_le_bytes());
+    }
+
+    /// Add a new constraint.
+    pub fn add(&mut self, constraint: &mut dyn ConstraintBuilder) {
+        self.0.extend_from_slice(constraint.build().as_ref());
+    }
+}
+
+impl ConstraintBuilder for AtLeastBuilder {
+    fn build(&mut self) -> Vec<u8> {
+        if self.0.is_empty() {
+            self.0.resize(size_of::<Operator>() + size_of::<u64>(), 0);
+        }
+
+        let length = self.0.len() - size_of::<Operator>();
+
+        // manual byte wrangling because bytemuck doesn't work with newly
+        // allocated Vec in BPF.
+        self.0[0..4].copy_from_slice(&u32::to_le_bytes(OperatorType::AtLeast as u32));
+        self.0[4..8].copy_from_slice(&u32::to_le_bytes(length as u32));
+
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for AtLeastBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraints::{
+        Account, Assertable, Assertion, AtLeastBuilder, Constraint, ConstraintBuilder, Context,
+        FromBytes, OperatorType, PubkeyMatchBuilder, Target,
+    };
+    use solana_program::{pubkey::Pubkey, system_program};
+
+    struct Wallet(Pubkey);
+
+    impl Target for Wallet {
+        fn key(&self) -> &Pubkey {
+            &self.0
+        }
+
+        fn owner(&self) -> &Pubkey {
+            &system_program::ID
+        }
+
+        fn is_empty(&self) -> bool {
+            true
+        }
+
+        fn data(&self) -> &[u8] {
+            &[]
+        }
+    }
+
+    #[test]
+    pub fn test_build() {
+        let asset = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let mut builder = AtLeastBuilder::default();
+        builder.set_threshold(2);
+
+        for (account, key) in [
+            (Account::Asset, asset),
+            (Account::Authority, Pubkey::new_unique()),
+            (Account::Authority, authority),
+        ] {
+            let mut constraint = PubkeyMatchBuilder::default();
+            constraint.set(account, &[key]);
+            builder.add(&mut constraint);
+        }
+
+        let bytes = builder.build();
+
+        let constraint = Constraint::from_bytes(&bytes);
+        assert_eq!(constraint.operator.operator_type(), OperatorType::AtLeast);
+
+        // two of the three constraints pass
+        let context = Context {
+            asset: &Wallet(asset),
+            authority: &Wallet(authority),
+            recipient: None,
+            caller: None,
+            rule_sets: &[],
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Pass);
+
+        // only one of the three constraints pass
+        let context = Context {
+            asset: &Wallet(asset),
+            authority: &Wallet(Pubkey::new_unique()),
+            recipient: None,
+            caller: None,
+            rule_sets: &[],
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Failure);
+    }
+}
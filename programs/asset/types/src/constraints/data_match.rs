@@ -0,0 +1,257 @@
+use std::{mem::size_of, ops::Deref};
+
+use podded::ZeroCopy;
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    constraints::{
+        Account, Assertable, Assertion, AssertionResult, ConstraintBuilder, Context, FromBytes,
+        Operator, OperatorType,
+    },
+    get_account,
+};
+
+/// Constraint that passes when the data of an account matches the expected bytes.
+///
+/// The `bytes` are compared against the account data starting at `offset`. This allows, for
+/// example, to check the discriminator of an account or the mint of a token account; in
+/// combination with a `Not`, it can check that a token account balance is not zero.
+///
+/// The expected bytes are padded to a multiple of 8 bytes so that any constraint following
+/// this one remains aligned.
+pub struct DataMatch<'a> {
+    /// The evaluation's field target.
+    pub account: &'a Account,
+
+    /// Offset on the account data where the comparison starts.
+    pub offset: &'a u32,
+
+    /// Number of expected bytes.
+    pub length: &'a u32,
+
+    /// The expected bytes (including any padding).
+    pub data: &'a [u8],
+}
+
+impl DataMatch<'_> {
+    /// Returns the expected bytes.
+    ///
+    /// Fails when the length is larger than the available data.
+    pub fn bytes(&self) -> Result<&[u8], ProgramError> {
+        self.data
+            .get(..*self.length as usize)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
+
+impl<'a> FromBytes<'a> for DataMatch<'a> {
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (account, rest) = bytes.split_at(size_of::<Account>());
+        let (offset, rest) = rest.split_at(size_of::<u32>());
+        let (length, data) = rest.split_at(size_of::<u32>());
+
+        Self {
+            account: Account::load(account),
+            offset: bytemuck::from_bytes(offset),
+            length: bytemuck::from_bytes(length),
+            data,
+        }
+    }
+}
+
+impl Assertable for DataMatch<'_> {
+    fn assert(&self, context: &Context) -> AssertionResult {
+        let bytes = self.bytes()?;
+        let account = get_account!(self.account, context);
+
+        let start = *self.offset as usize;
+        let matches = start
+            .checked_add(bytes.len())
+            .and_then(|end| account.data().get(start..end))
+            .map(|data| data == bytes)
+            .unwrap_or(false);
+
+        Ok(if matches {
+            Assertion::Pass
+        } else {
+            Assertion::Failure
+        })
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(size_of::<Account>() + size_of::<u64>() + self.data.len());
+        bytes.extend_from_slice(bytemuck::bytes_of(self.account));
+        bytes.extend_from_slice(&self.offset.to_le_bytes());
+        bytes.extend_from_slice(&self.length.to_le_bytes());
+        bytes.extend_from_slice(self.data);
+        bytes
+    }
+}
+
+/// Builder for a `DataMatch` constraint.
+#[derive(Default)]
+pub struct DataMatchBuilder(Vec<u8>);
+
+impl DataMatchBuilder {
+    /// Sets the account, offset and expected bytes.
+    pub fn set(&mut self, account: Account, offset: u32, bytes: &[u8]) {
+        // clear any previous value
+        self.0.resize(std::mem::size_of::<Operator>(), 0);
+
+        // add the account to the data buffer.
+        self.0.extend_from_slice(account.into_bytes().as_ref());
+
+        // add the offset and bytes to the data buffer.
+        self.0.extend_from_slice(&offset.to_le_bytes());
+        self.0
+            .extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        self.0.extend_from_slice(bytes);
+
+        // pad the bytes to keep the alignment
+        self.0
+            .resize(self.0.len() + padded_len(bytes.len()) - bytes.len(), 0);
+    }
+}
+
+/// Returns the length of `len` bytes padded to a multiple of 8.
+#[inline(always)]
+fn padded_len(len: usize) -> usize {
+    (len + 7) & !7
+}
+
+impl ConstraintBuilder for DataMatchBuilder {
+    fn build(&mut self) -> Vec<u8> {
+        if self.0.is_empty() {
+            self.0.resize(std::mem::size_of::<Operator>(), 0);
+        }
+
+        let length = self.0.len() - std::mem::size_of::<Operator>();
+
+        // manual byte wrangling because bytemuck doesn't work with newly
+        // allocated Vec in BPF.
+        self.0[0..4].copy_from_slice(&u32::to_le_bytes(OperatorType::DataMatch as u32));
+        self.0[4..8].copy_from_slice(&u32::to_le_bytes(length as u32));
+
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for DataMatchBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraints::{
+        Account, AndBuilder, Assertable, Assertion, Constraint, ConstraintBuilder, Context,
+        DataMatchBuilder, FromBytes, Operator, OperatorType, PubkeyMatchBuilder, Target,
+    };
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+
+    struct Data(Vec<u8>);
+
+    impl Target for Data {
+        fn key(&self) -> &Pubkey {
+            &system_program::ID
+        }
+
+        fn owner(&self) -> &Pubkey {
+            &system_program::ID
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    #[test]
+    pub fn test_build() {
+        let mut builder = DataMatchBuilder::default();
+        builder.set(Account::Recipient, 2, &[3, 4]);
+        let bytes = builder.build();
+
+        let constraint = Constraint::from_bytes(&bytes);
+        assert_eq!(constraint.operator.operator_type(), OperatorType::DataMatch);
+        assert_eq!(
+            constraint.operator.size(),
+            (std::mem::size_of::<Account>() + std::mem::size_of::<u64>() + 8) as u32
+        );
+
+        let asset = Data(vec![]);
+
+        for (data, expected) in [
+            (vec![1, 2, 3, 4, 5], Assertion::Pass),
+            (vec![1, 2, 3, 5, 5], Assertion::Failure),
+            // not enough data
+            (vec![1, 2, 3], Assertion::Failure),
+        ] {
+            let context = Context {
+                asset: &asset,
+                authority: &asset,
+                recipient: Some(&Data(data)),
+                caller: None,
+                rule_sets: &[],
+            };
+            assert_eq!(constraint.assert(&context).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    pub fn test_invalid_length() {
+        let mut builder = DataMatchBuilder::default();
+        builder.set(Account::Recipient, 0, &[1, 2]);
+        let mut bytes = builder.build();
+
+        // length larger than the expected bytes
+        let offset = std::mem::size_of::<Operator>()
+            + std::mem::size_of::<Account>()
+            + std::mem::size_of::<u32>();
+        bytes[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let constraint = Constraint::from_bytes(&bytes);
+        let context = Context {
+            asset: &Data(vec![]),
+            authority: &Data(vec![]),
+            recipient: Some(&Data(vec![1, 2])),
+            caller: None,
+            rule_sets: &[],
+        };
+        assert_eq!(
+            constraint.assert(&context).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    pub fn test_alignment() {
+        let mut data_match = DataMatchBuilder::default();
+        data_match.set(Account::Recipient, 0, &[1, 2, 3]);
+        let mut pubkey_match = PubkeyMatchBuilder::default();
+        pubkey_match.set(Account::Asset, &[system_program::ID]);
+
+        let mut builder = AndBuilder::default();
+        builder.add(&mut data_match);
+        builder.add(&mut pubkey_match);
+        let bytes = builder.build();
+
+        // the constraint following the data match must be readable
+        let constraint = Constraint::from_bytes(&bytes);
+        let context = Context {
+            asset: &Data(vec![]),
+            authority: &Data(vec![]),
+            recipient: Some(&Data(vec![1, 2, 3])),
+            caller: None,
+            rule_sets: &[],
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Pass);
+    }
+}
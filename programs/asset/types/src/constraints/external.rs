@@ -0,0 +1,188 @@
+use std::{mem::size_of, ops::Deref};
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constraints::{
+        Assertable, AssertionResult, ConstraintBuilder, Context, FromBytes, Operator, OperatorType,
+    },
+    state::{Discriminator, RuleSet},
+};
+
+/// Constraint that delegates the evaluation to a `RuleSet` account.
+///
+/// The rule set account is looked up by its address on the rule sets of the context; its
+/// constraint is evaluated using the same context, but without rule sets, so rule sets
+/// cannot be nested.
+pub struct External<'a> {
+    /// The address of the rule set account.
+    pub address: &'a Pubkey,
+}
+
+impl<'a> FromBytes<'a> for External<'a> {
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let address = bytemuck::from_bytes(&bytes[..size_of::<Pubkey>()]);
+        Self { address }
+    }
+}
+
+impl Assertable for External<'_> {
+    fn assert(&self, context: &Context) -> AssertionResult {
+        let rule_set = context
+            .rule_sets
+            .iter()
+            .find(|rule_set| rule_set.key() == self.address)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let data = rule_set.data();
+
+        if data.len() < RuleSet::LEN || Discriminator::from(data[0]) != Discriminator::RuleSet {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        RuleSet::constraint(data).assert(&Context {
+            rule_sets: &[],
+            ..*context
+        })
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        self.address.as_ref().to_vec()
+    }
+}
+
+/// Builder for an `External` constraint.
+#[derive(Default)]
+pub struct ExternalBuilder(Vec<u8>);
+
+impl ExternalBuilder {
+    /// Sets the address of the rule set account.
+    pub fn set(&mut self, address: &Pubkey) {
+        // clear any previous value
+        self.0.resize(size_of::<Operator>(), 0);
+
+        self.0.extend_from_slice(address.as_ref());
+    }
+}
+
+impl ConstraintBuilder for ExternalBuilder {
+    fn build(&mut self) -> Vec<u8> {
+        if self.0.is_empty() {
+            self.0.resize(size_of::<Operator>(), 0);
+        }
+
+        let length = self.0.len() - size_of::<Operator>();
+
+        // manual byte wrangling because bytemuck doesn't work with newly
+        // allocated Vec in BPF.
+        self.0[0..4].copy_from_slice(&u32::to_le_bytes(OperatorType::External as u32));
+        self.0[4..8].copy_from_slice(&u32::to_le_bytes(length as u32));
+
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for ExternalBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constraints::{
+            Account, Assertable, Assertion, Constraint, ConstraintBuilder, Context,
+            ExternalBuilder, FromBytes, OperatorType, PubkeyMatchBuilder, Target,
+        },
+        state::{Discriminator, RuleSet},
+    };
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+
+    struct Wallet(Pubkey, Vec<u8>);
+
+    impl Target for Wallet {
+        fn key(&self) -> &Pubkey {
+            &self.0
+        }
+
+        fn owner(&self) -> &Pubkey {
+            &system_program::ID
+        }
+
+        fn is_empty(&self) -> bool {
+            self.1.is_empty()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.1
+        }
+    }
+
+    #[test]
+    pub fn test_build() {
+        let authority = Pubkey::new_unique();
+
+        // rule set account data: header + constraint
+        let mut pubkey_match = PubkeyMatchBuilder::default();
+        pubkey_match.set(Account::Authority, &[authority]);
+
+        let mut data = vec![0u8; RuleSet::LEN];
+        data[0] = Discriminator::RuleSet.into();
+        data.extend_from_slice(&pubkey_match.build());
+        let rule_set = Wallet(Pubkey::new_unique(), data);
+
+        let mut builder = ExternalBuilder::default();
+        builder.set(&rule_set.0);
+        let bytes = builder.build();
+
+        let constraint = Constraint::from_bytes(&bytes);
+        assert_eq!(constraint.operator.operator_type(), OperatorType::External);
+
+        let asset = Wallet(Pubkey::new_unique(), vec![]);
+        let context = Context {
+            asset: &asset,
+            authority: &Wallet(authority, vec![]),
+            recipient: None,
+            caller: None,
+            rule_sets: &[&rule_set],
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Pass);
+
+        let context = Context {
+            authority: &Wallet(Pubkey::new_unique(), vec![]),
+            ..context
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Failure);
+
+        // the rule set is looked up by its address
+        let other = Wallet(Pubkey::new_unique(), vec![]);
+        let context = Context {
+            authority: &Wallet(authority, vec![]),
+            rule_sets: &[&other, &rule_set],
+            ..context
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Pass);
+
+        // missing rule set account
+        let context = Context {
+            rule_sets: &[&other],
+            ..context
+        };
+        assert_eq!(
+            constraint.assert(&context).unwrap_err(),
+            ProgramError::NotEnoughAccountKeys
+        );
+
+        let context = Context {
+            rule_sets: &[],
+            ..context
+        };
+        assert_eq!(
+            constraint.assert(&context).unwrap_err(),
+            ProgramError::NotEnoughAccountKeys
+        );
+    }
+}
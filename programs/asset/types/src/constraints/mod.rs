@@ -1,16 +1,26 @@
 mod and;
+mod at_least;
+mod data_match;
 mod empty;
+mod external;
 mod not;
 mod or;
 mod owned_by;
 mod pubkey_match;
+mod time_window;
+mod value_match;
 
 pub use and::*;
+pub use at_least::*;
+pub use data_match::*;
 pub use empty::*;
+pub use external::*;
 pub use not::*;
 pub use or::*;
 pub use owned_by::*;
 pub use pubkey_match::*;
+pub use time_window::*;
+pub use value_match::*;
 
 use bytemuck::{Pod, Zeroable};
 use podded::ZeroCopy;
@@ -24,6 +34,7 @@ const DEFAULT_CAPACITY: usize = 2;
 pub type AssertionResult = Result<Assertion, ProgramError>;
 
 /// The context of a constraint evaluation.
+#[derive(Clone, Copy)]
 pub struct Context<'a> {
     /// The asset participating in the action.
     pub asset: &'a dyn Target,
@@ -33,6 +44,16 @@ pub struct Context<'a> {
 
     /// The recipient account when the action is a transfer.
     pub recipient: Option<&'a dyn Target>,
+
+    /// The program of the top-level instruction invoking the action.
+    ///
+    /// When the action is invoked directly, this is the asset program itself.
+    pub caller: Option<&'a dyn Target>,
+
+    /// The rule set accounts referenced by `External` constraints.
+    ///
+    /// The accounts must be `RuleSet` accounts owned by the asset program.
+    pub rule_sets: &'a [&'a dyn Target],
 }
 
 /// Defines the target of a constraint.
@@ -50,6 +71,9 @@ pub trait Target {
     ///
     /// Note that even when the data is not empty, it can still be zeroed out.
     fn is_empty(&self) -> bool;
+
+    /// The data of the target.
+    fn data(&self) -> &[u8];
 }
 
 #[repr(u64)]
@@ -59,6 +83,7 @@ pub enum Account {
     Asset,
     Authority,
     Recipient,
+    Caller,
 }
 
 impl Account {
@@ -73,6 +98,7 @@ impl From<&str> for Account {
             "asset" => Account::Asset,
             "authority" => Account::Authority,
             "recipient" => Account::Recipient,
+            "caller" => Account::Caller,
             _ => panic!("invalid target value: {value}"),
         }
     }
@@ -84,6 +110,7 @@ impl fmt::Display for Account {
             Account::Asset => "asset",
             Account::Authority => "authority",
             Account::Recipient => "recipient",
+            Account::Caller => "caller",
         };
         write!(f, "{}", account_str)
     }
@@ -105,6 +132,9 @@ macro_rules! get_account {
             $crate::constraints::Account::Recipient => $context
                 .recipient
                 .ok_or(solana_program::program_error::ProgramError::NotEnoughAccountKeys)?,
+            $crate::constraints::Account::Caller => $context
+                .caller
+                .ok_or(solana_program::program_error::ProgramError::NotEnoughAccountKeys)?,
         }
     };
 }
@@ -134,6 +164,11 @@ pub enum OperatorType {
     OwnedBy,
     PubkeyMatch,
     Empty,
+    TimeWindow,
+    AtLeast,
+    DataMatch,
+    ValueMatch,
+    External,
 }
 
 impl From<u32> for OperatorType {
@@ -145,6 +180,11 @@ impl From<u32> for OperatorType {
             3 => OperatorType::OwnedBy,
             4 => OperatorType::PubkeyMatch,
             5 => OperatorType::Empty,
+            6 => OperatorType::TimeWindow,
+            7 => OperatorType::AtLeast,
+            8 => OperatorType::DataMatch,
+            9 => OperatorType::ValueMatch,
+            10 => OperatorType::External,
             _ => panic!("invalid operator type: {value}"),
         }
     }
@@ -159,6 +199,11 @@ impl From<OperatorType> for u32 {
             OperatorType::OwnedBy => 3,
             OperatorType::PubkeyMatch => 4,
             OperatorType::Empty => 5,
+            OperatorType::TimeWindow => 6,
+            OperatorType::AtLeast => 7,
+            OperatorType::DataMatch => 8,
+            OperatorType::ValueMatch => 9,
+            OperatorType::External => 10,
         }
     }
 }
@@ -246,7 +291,12 @@ impl<'a> FromBytes<'a> for Constraint<'a> {
             Or,
             OwnedBy,
             PubkeyMatch,
-            Empty
+            Empty,
+            TimeWindow,
+            AtLeast,
+            DataMatch,
+            ValueMatch,
+            External
         );
 
         Self {
@@ -0,0 +1,139 @@
+use std::{mem::size_of, ops::Deref};
+
+use solana_program::{clock::Clock, sysvar::Sysvar};
+
+use crate::constraints::{
+    Assertable, Assertion, AssertionResult, ConstraintBuilder, Context, FromBytes, Operator,
+    OperatorType,
+};
+
+/// Constraint that passes when the current time is within a window.
+///
+/// The window is defined by `start` (inclusive) and `end` (exclusive) unix timestamps; a
+/// value of `0` leaves that side of the window open. This can be used to forbid transfers
+/// until a reveal date (`start` only) or to allow them only during a trading window.
+pub struct TimeWindow<'a> {
+    /// Unix timestamp when the window opens.
+    pub start: &'a i64,
+
+    /// Unix timestamp when the window closes.
+    pub end: &'a i64,
+}
+
+impl TimeWindow<'_> {
+    /// Indicates whether the `timestamp` is within the window.
+    pub fn contains(&self, timestamp: i64) -> bool {
+        (*self.start == 0 || timestamp >= *self.start) && (*self.end == 0 || timestamp < *self.end)
+    }
+}
+
+impl<'a> FromBytes<'a> for TimeWindow<'a> {
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (start, end) = bytes.split_at(size_of::<i64>());
+        Self {
+            start: bytemuck::from_bytes(start),
+            end: bytemuck::from_bytes(end),
+        }
+    }
+}
+
+impl Assertable for TimeWindow<'_> {
+    fn assert(&self, _context: &Context) -> AssertionResult {
+        Ok(if self.contains(Clock::get()?.unix_timestamp) {
+            Assertion::Pass
+        } else {
+            Assertion::Failure
+        })
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(size_of::<i64>() * 2);
+        bytes.extend_from_slice(&self.start.to_le_bytes());
+        bytes.extend_from_slice(&self.end.to_le_bytes());
+        bytes
+    }
+}
+
+/// Builder for a `TimeWindow` constraint.
+#[derive(Default)]
+pub struct TimeWindowBuilder(Vec<u8>);
+
+impl TimeWindowBuilder {
+    /// Sets the start and end of the window.
+    ///
+    /// Use `0` to leave either side of the window open.
+    pub fn set(&mut self, start: i64, end: i64) {
+        // clear any previous value
+        self.0.resize(std::mem::size_of::<Operator>(), 0);
+
+        // add the window to the data buffer.
+        self.0.extend_from_slice(&start.to_le_bytes());
+        self.0.extend_from_slice(&end.to_le_bytes());
+    }
+}
+
+impl ConstraintBuilder for TimeWindowBuilder {
+    fn build(&mut self) -> Vec<u8> {
+        if self.0.is_empty() {
+            self.set(0, 0);
+        }
+
+        let length = self.0.len() - std::mem::size_of::<Operator>();
+
+        // manual byte wrangling because bytemuck doesn't work with newly
+        // allocated Vec in BPF.
+        self.0[0..4].copy_from_slice(&u32::to_le_bytes(OperatorType::TimeWindow as u32));
+        self.0[4..8].copy_from_slice(&u32::to_le_bytes(length as u32));
+
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for TimeWindowBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraints::{
+        Constraint, ConstraintBuilder, FromBytes, OperatorType, TimeWindow, TimeWindowBuilder,
+    };
+
+    #[test]
+    pub fn test_build() {
+        let mut builder = TimeWindowBuilder::default();
+        builder.set(1_000, 2_000);
+        let bytes = builder.build();
+
+        let constraint = Constraint::from_bytes(&bytes);
+        assert_eq!(
+            constraint.operator.operator_type(),
+            OperatorType::TimeWindow
+        );
+        assert_eq!(
+            constraint.operator.size(),
+            (std::mem::size_of::<i64>() * 2) as u32
+        );
+
+        let window = TimeWindow::from_bytes(&bytes[8..]);
+        assert!(!window.contains(999));
+        assert!(window.contains(1_000));
+        assert!(window.contains(1_999));
+        assert!(!window.contains(2_000));
+    }
+
+    #[test]
+    pub fn test_open_window() {
+        let mut builder = TimeWindowBuilder::default();
+        builder.set(1_000, 0);
+        let bytes = builder.build();
+
+        let window = TimeWindow::from_bytes(&bytes[8..]);
+        assert!(!window.contains(999));
+        assert!(window.contains(i64::MAX));
+    }
+}
@@ -0,0 +1,523 @@
+use std::{mem::size_of, ops::Deref, str::FromStr};
+
+use podded::{
+    types::{U8PrefixStr, U8PrefixStrMut},
+    ZeroCopy,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constraints::{
+        Account, Assertable, Assertion, AssertionResult, ConstraintBuilder, Context, FromBytes,
+        Operator, OperatorType,
+    },
+    error::Error,
+    extensions::{Attributes, Boolean, Number, Properties, Text, Type, TypedValue},
+    get_account,
+    state::Asset,
+};
+
+/// Constraint that passes when a value stored on the asset matches the expected one.
+///
+/// The value is read by `name` from either the `Attributes` or `Properties` extension of the
+/// asset and it is compared against:
+///   * `MatchMode::Value` - a typed value (e.g., property `staked` is `false`).
+///   * `MatchMode::Account` - the key of an account, where the value on the asset is a base58
+///     encoded pubkey (e.g., recipient is the pubkey stored under `beneficiary`).
+///
+/// The constraint fails when the asset does not have the value or its type does not match.
+pub struct ValueMatch<'a> {
+    /// The account to compare when the mode is `MatchMode::Account`.
+    pub account: &'a Account,
+
+    /// The extension holding the value (see `ValueSource`).
+    source: u8,
+
+    /// Indicates what the value is compared against (see `MatchMode`).
+    mode: u8,
+
+    /// Name of the value on the asset.
+    pub name: U8PrefixStr<'a>,
+
+    /// The expected (typed) value when the mode is `MatchMode::Value`.
+    ///
+    /// The value is encoded as a property value and it is followed by padding.
+    pub value: &'a [u8],
+}
+
+impl ValueMatch<'_> {
+    /// Returns the extension holding the value.
+    pub fn source(&self) -> Result<ValueSource, Error> {
+        self.source.try_into()
+    }
+
+    /// Returns what the value is compared against.
+    pub fn mode(&self) -> Result<MatchMode, Error> {
+        self.mode.try_into()
+    }
+
+    /// Returns the expected value when the mode is `MatchMode::Value`.
+    ///
+    /// Fails when the value is not a valid property value.
+    fn literal(&self) -> Result<Literal<'_>, ProgramError> {
+        let (value_type, value) = self
+            .value
+            .split_first()
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(
+            match Type::try_from(*value_type).map_err(|_| ProgramError::InvalidAccountData)? {
+                Type::Text => {
+                    // the text is prefixed by its length
+                    let length = *value.first().ok_or(ProgramError::InvalidAccountData)? as usize;
+                    let text = value
+                        .get(1..1 + length)
+                        .ok_or(ProgramError::InvalidAccountData)?;
+
+                    Literal::Text(
+                        std::str::from_utf8(text).map_err(|_| ProgramError::InvalidAccountData)?,
+                    )
+                }
+                Type::Number => Literal::Number(u64::from_le_bytes(
+                    value
+                        .get(..size_of::<u64>())
+                        .and_then(|bytes| bytes.try_into().ok())
+                        .ok_or(ProgramError::InvalidAccountData)?,
+                )),
+                Type::Boolean => {
+                    Literal::Boolean(*value.first().ok_or(ProgramError::InvalidAccountData)? != 0)
+                }
+            },
+        )
+    }
+}
+
+impl<'a> FromBytes<'a> for ValueMatch<'a> {
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (account, rest) = bytes.split_at(size_of::<Account>());
+        let name = U8PrefixStr::from_bytes(&rest[2..]);
+        let value = &rest[2 + name.size()..];
+
+        Self {
+            account: Account::load(account),
+            source: rest[0],
+            mode: rest[1],
+            name,
+            value,
+        }
+    }
+}
+
+impl Assertable for ValueMatch<'_> {
+    fn assert(&self, context: &Context) -> AssertionResult {
+        let expected = match self.mode().map_err(|_| ProgramError::InvalidAccountData)? {
+            MatchMode::Value => Expected::Value(self.literal()?),
+            MatchMode::Account => Expected::Key(get_account!(self.account, context).key()),
+        };
+
+        let data = context.asset.data();
+        let name = self.name.as_str();
+
+        let matches = match self
+            .source()
+            .map_err(|_| ProgramError::InvalidAccountData)?
+        {
+            ValueSource::Attributes => Asset::get::<Attributes>(data)
+                .and_then(|attributes| {
+                    attributes
+                        .get(name)
+                        .map(|value| expected.matches(Literal::Text(value)))
+                })
+                .unwrap_or(false),
+            ValueSource::Properties => Asset::get::<Properties>(data)
+                .and_then(|properties| {
+                    let property = properties.iter().find(|p| p.name.as_str() == name)?;
+                    // the type of the value is checked before accessing it
+                    let value = match property.value.value_type() {
+                        Type::Text => Literal::Text(properties.get::<Text>(name)?),
+                        Type::Number => Literal::Number(**properties.get::<Number>(name)?),
+                        Type::Boolean => Literal::Boolean(**properties.get::<Boolean>(name)?),
+                    };
+                    Some(expected.matches(value))
+                })
+                .unwrap_or(false),
+        };
+
+        Ok(if matches {
+            Assertion::Pass
+        } else {
+            Assertion::Failure
+        })
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(size_of::<Account>() + 2 + self.name.size() + self.value.len());
+        bytes.extend_from_slice(bytemuck::bytes_of(self.account));
+        bytes.push(self.source);
+        bytes.push(self.mode);
+        bytes.push(self.name.as_str().len() as u8);
+        bytes.extend_from_slice(self.name.as_str().as_bytes());
+        bytes.extend_from_slice(self.value);
+        bytes
+    }
+}
+
+/// Extension holding the value of a `ValueMatch` constraint.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueSource {
+    Attributes,
+    Properties,
+}
+
+impl From<ValueSource> for u8 {
+    fn from(value: ValueSource) -> Self {
+        match value {
+            ValueSource::Attributes => 0,
+            ValueSource::Properties => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for ValueSource {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ValueSource::Attributes),
+            1 => Ok(ValueSource::Properties),
+            _ => Err(Error::InvalidValueSource(value)),
+        }
+    }
+}
+
+/// Indicates what the value of a `ValueMatch` constraint is compared against.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatchMode {
+    Value,
+    Account,
+}
+
+impl From<MatchMode> for u8 {
+    fn from(value: MatchMode) -> Self {
+        match value {
+            MatchMode::Value => 0,
+            MatchMode::Account => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for MatchMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MatchMode::Value),
+            1 => Ok(MatchMode::Account),
+            _ => Err(Error::InvalidMatchMode(value)),
+        }
+    }
+}
+
+/// A value read from the asset or from the constraint data.
+#[derive(PartialEq)]
+enum Literal<'a> {
+    Text(&'a str),
+    Number(u64),
+    Boolean(bool),
+}
+
+/// The expected outcome of a `ValueMatch` constraint.
+enum Expected<'a> {
+    Value(Literal<'a>),
+    Key(&'a Pubkey),
+}
+
+impl Expected<'_> {
+    fn matches(&self, value: Literal) -> bool {
+        match self {
+            Expected::Value(expected) => *expected == value,
+            Expected::Key(key) => match value {
+                Literal::Text(text) => Pubkey::from_str(text)
+                    .map(|pubkey| pubkey == **key)
+                    .unwrap_or(false),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Builder for a `ValueMatch` constraint.
+#[derive(Default)]
+pub struct ValueMatchBuilder(Vec<u8>);
+
+impl ValueMatchBuilder {
+    /// Sets the name of the value and the typed value that it must match.
+    pub fn set_value<T: TypedValue>(&mut self, source: ValueSource, name: &str, value: T) {
+        self.set_name(Account::Asset, source, MatchMode::Value, name);
+
+        // add the value type and value to the data buffer.
+        self.0.push(T::TYPE.into());
+        value.write_to(&mut self.0);
+
+        self.pad();
+    }
+
+    /// Sets the name of the value and the account whose key it must match.
+    pub fn set_account(&mut self, source: ValueSource, name: &str, account: Account) {
+        self.set_name(account, source, MatchMode::Account, name);
+        self.pad();
+    }
+
+    fn set_name(&mut self, account: Account, source: ValueSource, mode: MatchMode, name: &str) {
+        // clear any previous value
+        self.0.resize(size_of::<Operator>(), 0);
+
+        // add the account, source and mode to the data buffer.
+        self.0.extend_from_slice(account.into_bytes().as_ref());
+        self.0.push(source.into());
+        self.0.push(mode.into());
+
+        // add the length of the name + prefix to the data buffer.
+        let cursor = self.0.len();
+        self.0.append(&mut vec![0u8; name.len() + 1]);
+        let mut name_str = U8PrefixStrMut::new(&mut self.0[cursor..]);
+        name_str.copy_from_str(name);
+    }
+
+    /// Pads the data buffer to a multiple of 8 bytes to keep the alignment.
+    fn pad(&mut self) {
+        self.0.resize((self.0.len() + 7) & !7, 0);
+    }
+}
+
+impl ConstraintBuilder for ValueMatchBuilder {
+    fn build(&mut self) -> Vec<u8> {
+        if self.0.is_empty() {
+            self.set_value(ValueSource::Properties, "", false);
+        }
+
+        let length = self.0.len() - size_of::<Operator>();
+
+        // manual byte wrangling because bytemuck doesn't work with newly
+        // allocated Vec in BPF.
+        self.0[0..4].copy_from_slice(&u32::to_le_bytes(OperatorType::ValueMatch as u32));
+        self.0[4..8].copy_from_slice(&u32::to_le_bytes(length as u32));
+
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for ValueMatchBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constraints::{
+            Account, Assertable, Assertion, Constraint, ConstraintBuilder, Context, FromBytes,
+            Operator, OperatorType, Target, ValueMatch, ValueMatchBuilder, ValueSource,
+        },
+        extensions::{
+            AttributesBuilder, Extension, ExtensionBuilder, ExtensionType, PropertiesBuilder,
+        },
+        state::Asset,
+    };
+    use solana_program::{pubkey::Pubkey, system_program};
+    use std::mem::size_of;
+
+    struct Data(Pubkey, Vec<u8>);
+
+    impl Target for Data {
+        fn key(&self) -> &Pubkey {
+            &self.0
+        }
+
+        fn owner(&self) -> &Pubkey {
+            &system_program::ID
+        }
+
+        fn is_empty(&self) -> bool {
+            self.1.is_empty()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.1
+        }
+    }
+
+    /// Creates the data of an asset with the given extensions.
+    fn asset_data(extensions: &[(ExtensionType, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0u8; Asset::LEN];
+
+        for (extension_type, extension) in extensions {
+            let start = data.len() + Extension::LEN;
+            let boundary = (start + extension.len() + 7) & !7;
+            let header = Extension::new(*extension_type, extension.len() as u32, boundary as u32);
+
+            data.extend_from_slice(bytemuck::bytes_of(&header));
+            data.extend_from_slice(extension);
+            data.resize(boundary, 0);
+        }
+
+        data
+    }
+
+    #[test]
+    pub fn test_build() {
+        let mut builder = ValueMatchBuilder::default();
+        builder.set_value(ValueSource::Properties, "staked", false);
+        let bytes = builder.build();
+
+        let constraint = Constraint::from_bytes(&bytes);
+        assert_eq!(
+            constraint.operator.operator_type(),
+            OperatorType::ValueMatch
+        );
+        assert_eq!(constraint.operator.size() % 8, 0);
+        assert_eq!(constraint.as_bytes(), bytes);
+
+        let wallet = Data(Pubkey::new_unique(), vec![]);
+
+        for (staked, expected) in [(false, Assertion::Pass), (true, Assertion::Failure)] {
+            let mut properties = PropertiesBuilder::default();
+            properties.add("staked", staked);
+            properties.add("level", 1u64);
+
+            let asset = Data(
+                Pubkey::new_unique(),
+                asset_data(&[(ExtensionType::Properties, properties.data())]),
+            );
+            let context = Context {
+                asset: &asset,
+                authority: &wallet,
+                recipient: None,
+                caller: None,
+                rule_sets: &[],
+            };
+            assert_eq!(constraint.assert(&context).unwrap(), expected);
+        }
+
+        // a value of a different type does not match
+        let mut properties = PropertiesBuilder::default();
+        properties.add("staked", 0u64);
+
+        let asset = Data(
+            Pubkey::new_unique(),
+            asset_data(&[(ExtensionType::Properties, properties.data())]),
+        );
+        let context = Context {
+            asset: &asset,
+            authority: &wallet,
+            recipient: None,
+            caller: None,
+            rule_sets: &[],
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Failure);
+    }
+
+    #[test]
+    pub fn test_account() {
+        let beneficiary = Pubkey::new_unique();
+
+        let mut builder = ValueMatchBuilder::default();
+        builder.set_account(ValueSource::Attributes, "beneficiary", Account::Recipient);
+        let bytes = builder.build();
+
+        let constraint = Constraint::from_bytes(&bytes);
+
+        let mut attributes = AttributesBuilder::default();
+        attributes.add("beneficiary", &beneficiary.to_string());
+
+        let asset = Data(
+            Pubkey::new_unique(),
+            asset_data(&[(ExtensionType::Attributes, attributes.data())]),
+        );
+
+        for (recipient, expected) in [
+            (beneficiary, Assertion::Pass),
+            (Pubkey::new_unique(), Assertion::Failure),
+        ] {
+            let context = Context {
+                asset: &asset,
+                authority: &asset,
+                recipient: Some(&Data(recipient, vec![])),
+                caller: None,
+                rule_sets: &[],
+            };
+            assert_eq!(constraint.assert(&context).unwrap(), expected);
+        }
+
+        // the asset does not have the value
+        let asset = Data(Pubkey::new_unique(), asset_data(&[]));
+        let context = Context {
+            asset: &asset,
+            authority: &asset,
+            recipient: Some(&Data(beneficiary, vec![])),
+            caller: None,
+            rule_sets: &[],
+        };
+        assert_eq!(constraint.assert(&context).unwrap(), Assertion::Failure);
+    }
+
+    #[test]
+    pub fn test_malformed() {
+        let mut builder = ValueMatchBuilder::default();
+        builder.set_value(ValueSource::Properties, "staked", false);
+        let bytes = builder.build();
+
+        // the data of the constraint follows the operator
+        let data = &bytes[size_of::<Operator>()..];
+        // offset of the source, followed by the mode and name
+        let source = size_of::<Account>();
+
+        let wallet = Data(Pubkey::new_unique(), vec![]);
+        let asset = Data(Pubkey::new_unique(), asset_data(&[]));
+        let context = Context {
+            asset: &asset,
+            authority: &wallet,
+            recipient: None,
+            caller: None,
+            rule_sets: &[],
+        };
+
+        // invalid source
+        let mut invalid = data.to_vec();
+        invalid[source] = 2;
+        assert!(ValueMatch::from_bytes(&invalid).assert(&context).is_err());
+
+        // invalid mode
+        let mut invalid = data.to_vec();
+        invalid[source + 1] = 2;
+        assert!(ValueMatch::from_bytes(&invalid).assert(&context).is_err());
+
+        // missing value
+        let name = source + 2 + 1 + "staked".len();
+        assert!(ValueMatch::from_bytes(&data[..name])
+            .assert(&context)
+            .is_err());
+
+        // invalid value type
+        let mut invalid = data.to_vec();
+        invalid[name] = 3;
+        assert!(ValueMatch::from_bytes(&invalid).assert(&context).is_err());
+
+        // truncated number
+        let mut builder = ValueMatchBuilder::default();
+        builder.set_value(ValueSource::Properties, "level", 1u64);
+        let bytes = builder.build();
+        let name = size_of::<Operator>() + source + 2 + 1 + "level".len();
+        assert!(
+            ValueMatch::from_bytes(&bytes[size_of::<Operator>()..name + 4])
+                .assert(&context)
+                .is_err()
+        );
+    }
+}
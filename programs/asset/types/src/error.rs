@@ -32,4 +32,64 @@ pub enum Error {
     /// 6 - Cannot modify proxy extension derivation data
     #[error("Cannot modify proxy extension derivation data")]
     CannotModifyDerivationData,
+
+    /// 7 - Cannot modify edition extension
+    #[error("Cannot modify edition extension")]
+    CannotModifyEdition,
+
+    /// 8 - Cannot modify the supply of a master edition
+    #[error("Cannot modify the supply of a master edition")]
+    InvalidEditionSupply,
+
+    /// 9 - Invalid maximum edition supply
+    #[error("Maximum edition supply is invalid (expected at least {0}, got {1})")]
+    InvalidMaximumEditionSupply(u64, u64),
+
+    /// 10 - Cannot modify delegate expiry extension
+    #[error("Cannot modify delegate expiry extension")]
+    CannotModifyDelegateExpiry,
+
+    /// 11 - Cannot modify delegates extension
+    #[error("Cannot modify delegates extension")]
+    CannotModifyDelegates,
+
+    /// 12 - Cannot modify authorities extension
+    #[error("Cannot modify authorities extension")]
+    CannotModifyAuthorities,
+
+    /// 13 - Invalid guard action
+    #[error("Invalid guard action: {0}")]
+    InvalidGuardAction(u64),
+
+    /// 14 - Invalid royalties mode
+    #[error("Invalid royalties mode: {0}")]
+    InvalidRoyaltiesMode(u64),
+
+    /// 15 - Cannot modify rental extension
+    #[error("Cannot modify rental extension")]
+    CannotModifyRental,
+
+    /// 16 - Cannot modify pending authority extension
+    #[error("Cannot modify pending authority extension")]
+    CannotModifyPendingAuthority,
+
+    /// 17 - Cannot reset the primary sale
+    #[error("Cannot reset the primary sale")]
+    CannotResetPrimarySale,
+
+    /// 18 - Cannot modify royalty payment extension
+    #[error("Cannot modify royalty payment extension")]
+    CannotModifyRoyaltyPayment,
+
+    /// 19 - Invalid value type
+    #[error("Invalid value type: {0}")]
+    InvalidValueType(u8),
+
+    /// 20 - Invalid value source
+    #[error("Invalid value source: {0}")]
+    InvalidValueSource(u8),
+
+    /// 21 - Invalid match mode
+    #[error("Invalid match mode: {0}")]
+    InvalidMatchMode(u8),
 }
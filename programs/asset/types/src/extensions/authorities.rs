@@ -0,0 +1,148 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to store the table of extension authorities.
+///
+/// Each extension header has an authority index that refers to an entry on this table,
+/// starting at `1`; an index of `0` means that the extension is controlled by the authority
+/// of the asset. The table is managed by the `SetExtensionAuthority` instruction.
+pub struct Authorities<'a> {
+    /// List of authorities.
+    pub values: &'a [Pubkey],
+}
+
+impl Authorities<'_> {
+    /// Returns the authority with the given index.
+    ///
+    /// Note that the index starts at `1`.
+    pub fn get(&self, index: u16) -> Option<&Pubkey> {
+        (index as usize)
+            .checked_sub(1)
+            .and_then(|index| self.values.get(index))
+    }
+
+    /// Returns the index of the given authority.
+    pub fn index_of(&self, authority: &Pubkey) -> Option<u16> {
+        self.values
+            .iter()
+            .position(|value| value == authority)
+            .map(|position| position as u16 + 1)
+    }
+}
+
+impl<'a> ExtensionData<'a> for Authorities<'a> {
+    const TYPE: ExtensionType = ExtensionType::Authorities;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let authorities = bytemuck::cast_slice(bytes);
+        Self {
+            values: authorities,
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of_val(self.values)
+    }
+}
+
+impl Debug for Authorities<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Authorities")
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+/// Mutable version of the `Authorities` extension.
+pub struct AuthoritiesMut<'a> {
+    pub authorities: &'a mut [Pubkey],
+}
+
+impl<'a> ExtensionDataMut<'a> for AuthoritiesMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::Authorities;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let authorities = bytemuck::cast_slice_mut(bytes);
+        Self { authorities }
+    }
+}
+
+impl Lifecycle for AuthoritiesMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // the table is managed by the program when extension authorities are set
+        Err(Error::CannotModifyAuthorities)
+    }
+
+    fn on_update(&mut self, _other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Err(Error::CannotModifyAuthorities)
+    }
+}
+
+/// Builder for an `Authorities` extension.
+#[derive(Default)]
+pub struct AuthoritiesBuilder(Vec<u8>);
+
+impl AuthoritiesBuilder {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        let mut s = Self(buffer);
+        s.0.clear();
+        s
+    }
+
+    /// Add a new authority to the extension.
+    pub fn add(&mut self, authority: &Pubkey) -> &mut Self {
+        self.0.extend_from_slice(authority.as_ref());
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, Authorities<'a>> for AuthoritiesBuilder {
+    fn build(&'a self) -> Authorities<'a> {
+        Authorities::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for AuthoritiesBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, sysvar};
+
+    use crate::extensions::{AuthoritiesBuilder, ExtensionBuilder};
+
+    #[test]
+    fn test_add() {
+        let mut builder = AuthoritiesBuilder::default();
+        builder.add(&sysvar::ID).add(&sysvar::rent::ID);
+        let authorities = builder.build();
+
+        assert_eq!(authorities.values.len(), 2);
+
+        // indices start at 1
+        assert_eq!(authorities.get(0), None);
+        assert_eq!(authorities.get(1), Some(&sysvar::ID));
+        assert_eq!(authorities.get(2), Some(&sysvar::rent::ID));
+        assert_eq!(authorities.get(3), None);
+
+        assert_eq!(authorities.index_of(&sysvar::rent::ID), Some(2));
+        assert_eq!(authorities.index_of(&Pubkey::default()), None);
+    }
+}
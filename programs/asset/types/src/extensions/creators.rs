@@ -144,6 +144,13 @@ pub struct Creator {
     pub share: u8,
 }
 
+impl Creator {
+    /// Returns the creator's share of a royalty amount.
+    pub fn royalty(&self, amount: u64) -> u64 {
+        ((amount as u128 * self.share as u128) / TOTAL_SHARE as u128) as u64
+    }
+}
+
 impl Debug for Creator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Creator")
@@ -223,7 +230,7 @@ impl Deref for CreatorsBuilder {
 mod tests {
     use crate::extensions::{CreatorsBuilder, ExtensionBuilder};
     use podded::pod::PodBool;
-    use solana_program::pubkey;
+    use solana_program::{pubkey, pubkey::Pubkey};
 
     #[test]
     fn test_add() {
@@ -243,4 +250,17 @@ mod tests {
         assert!(<PodBool as Into<bool>>::into(list.values[0].verified));
         assert_eq!(list.values[0].share, 100);
     }
+
+    #[test]
+    fn test_royalty() {
+        let mut builder = CreatorsBuilder::default();
+        builder.add(&Pubkey::new_unique(), true, 70);
+        builder.add(&Pubkey::new_unique(), false, 30);
+        let list = builder.build();
+
+        assert_eq!(list.values[0].royalty(1_000), 700);
+        assert_eq!(list.values[1].royalty(1_000), 300);
+        // no overflow on large amounts
+        assert_eq!(list.values[1].royalty(u64::MAX), 5_534_023_222_112_865_484);
+    }
 }
@@ -0,0 +1,208 @@
+use bytemuck::{Pod, Zeroable};
+use podded::ZeroCopy;
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to define the expiry of asset delegates.
+///
+/// The extension is managed by the `Approve` instruction when a delegate is approved
+/// with an expiry timestamp. Each entry only applies to its `delegate` address; once
+/// the timestamp is reached, the delegate is no longer valid. An empty entry (default
+/// address) represents a free slot that can be reused by another delegate.
+pub struct DelegateExpiry<'a> {
+    /// List of delegate expiries.
+    pub values: &'a [Expiry],
+}
+
+impl DelegateExpiry<'_> {
+    /// Returns the expiry of the given delegate.
+    pub fn get(&self, delegate: &Pubkey) -> Option<&Expiry> {
+        self.values
+            .iter()
+            .find(|expiry| expiry.is_some() && expiry.delegate == *delegate)
+    }
+
+    /// Indicates whether the delegate has expired at the given timestamp.
+    pub fn is_expired(&self, delegate: &Pubkey, timestamp: i64) -> bool {
+        self.get(delegate)
+            .map(|expiry| timestamp >= expiry.timestamp)
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> ExtensionData<'a> for DelegateExpiry<'a> {
+    const TYPE: ExtensionType = ExtensionType::DelegateExpiry;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let values = bytemuck::cast_slice(bytes);
+        Self { values }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of_val(self.values)
+    }
+}
+
+impl Debug for DelegateExpiry<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DelegateExpiry")
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+/// Mutable version of the `DelegateExpiry` extension.
+pub struct DelegateExpiryMut<'a> {
+    pub values: &'a mut [Expiry],
+}
+
+impl DelegateExpiryMut<'_> {
+    /// Returns the expiry of the given delegate.
+    pub fn get(&mut self, delegate: &Pubkey) -> Option<&mut Expiry> {
+        self.values
+            .iter_mut()
+            .find(|expiry| expiry.is_some() && expiry.delegate == *delegate)
+    }
+
+    /// Returns the first empty expiry slot.
+    pub fn get_empty(&mut self) -> Option<&mut Expiry> {
+        self.values.iter_mut().find(|expiry| !expiry.is_some())
+    }
+
+    /// Removes the expiry of the given delegate (if any).
+    pub fn remove(&mut self, delegate: &Pubkey) {
+        if let Some(expiry) = self.get(delegate) {
+            *expiry = Expiry::default();
+        }
+    }
+
+    /// Removes all expiries from the list.
+    pub fn clear(&mut self) {
+        self.values
+            .iter_mut()
+            .for_each(|expiry| *expiry = Expiry::default());
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for DelegateExpiryMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::DelegateExpiry;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let values = bytemuck::cast_slice_mut(bytes);
+        Self { values }
+    }
+}
+
+impl Lifecycle for DelegateExpiryMut<'_> {
+    /// Validates that the list of expiries is empty.
+    ///
+    /// The extension can be created with pre-allocated slots, but expiries can only be
+    /// set by the owner through the `Approve` instruction.
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        if self.values.iter().any(|expiry| expiry.is_some()) {
+            Err(Error::CannotModifyDelegateExpiry)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn on_update(&mut self, _other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Err(Error::CannotModifyDelegateExpiry)
+    }
+}
+
+/// Expiry of a delegate.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
+pub struct Expiry {
+    /// The delegate address.
+    pub delegate: Pubkey,
+
+    /// Unix timestamp (in seconds) when the delegate expires.
+    pub timestamp: i64,
+}
+
+impl Expiry {
+    /// Indicates whether the entry is in use.
+    pub fn is_some(&self) -> bool {
+        self.delegate != Pubkey::default()
+    }
+}
+
+impl ZeroCopy<'_, Expiry> for Expiry {}
+
+/// Builder for a `DelegateExpiry` extension.
+#[derive(Default)]
+pub struct DelegateExpiryBuilder(Vec<u8>);
+
+impl DelegateExpiryBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        let mut s = Self(buffer);
+        s.0.clear();
+        s
+    }
+
+    /// Add a delegate and its expiry timestamp.
+    ///
+    /// Using a `None` delegate adds an empty expiry slot.
+    pub fn add(&mut self, delegate: Option<&Pubkey>, timestamp: i64) -> &mut Self {
+        self.0
+            .extend_from_slice(delegate.copied().unwrap_or_default().as_ref());
+        self.0.extend_from_slice(&i64::to_le_bytes(timestamp));
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, DelegateExpiry<'a>> for DelegateExpiryBuilder {
+    fn build(&'a self) -> DelegateExpiry<'a> {
+        DelegateExpiry::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for DelegateExpiryBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, sysvar};
+
+    use crate::extensions::{DelegateExpiryBuilder, ExtensionBuilder};
+
+    #[test]
+    fn test_is_expired() {
+        let mut builder = DelegateExpiryBuilder::default();
+        builder.add(Some(&sysvar::ID), 1_000);
+        builder.add(Some(&Pubkey::new_from_array([1; 32])), 2_000);
+        builder.add(None, 0);
+        let expiry = builder.build();
+
+        assert_eq!(expiry.values.len(), 3);
+        assert_eq!(expiry.get(&sysvar::ID).unwrap().timestamp, 1_000);
+
+        assert!(!expiry.is_expired(&sysvar::ID, 999));
+        assert!(expiry.is_expired(&sysvar::ID, 1_000));
+        assert!(expiry.is_expired(&sysvar::ID, 1_001));
+
+        // each delegate has its own expiry
+        assert!(!expiry.is_expired(&Pubkey::new_from_array([1; 32]), 1_001));
+        assert!(expiry.is_expired(&Pubkey::new_from_array([1; 32]), 2_000));
+
+        // delegates without an entry (and empty slots) do not expire
+        assert!(!expiry.is_expired(&Pubkey::default(), 1_001));
+        assert!(expiry.get(&Pubkey::default()).is_none());
+    }
+}
@@ -0,0 +1,203 @@
+use podded::{pod::Nullable, ZeroCopy};
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{
+    error::Error,
+    state::{Delegate, NullablePubkey},
+};
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to add a list of delegates.
+///
+/// Assets with this extension can have multiple delegates at the same time, each
+/// one with its own roles. Delegates are added and removed by the `Approve` and
+/// `Revoke` instructions; an empty entry (`None` address) represents a free slot
+/// that can be reused by a new delegate.
+pub struct Delegates<'a> {
+    /// List of delegates.
+    pub values: &'a [Delegate],
+}
+
+impl Delegates<'_> {
+    /// Returns the delegate with the given address.
+    pub fn get(&self, address: &Pubkey) -> Option<&Delegate> {
+        self.values
+            .iter()
+            .find(|delegate| delegate.is_some() && *delegate.address == *address)
+    }
+
+    /// Indicates whether there are any delegates set.
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(|delegate| delegate.is_none())
+    }
+}
+
+impl<'a> ExtensionData<'a> for Delegates<'a> {
+    const TYPE: ExtensionType = ExtensionType::Delegates;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let delegates = bytemuck::cast_slice(bytes);
+        Self { values: delegates }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of_val(self.values)
+    }
+}
+
+impl Debug for Delegates<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Delegates")
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+/// Mutable version of the `Delegates` extension.
+pub struct DelegatesMut<'a> {
+    pub delegates: &'a mut [Delegate],
+}
+
+impl DelegatesMut<'_> {
+    /// Returns the delegate with the given address.
+    pub fn get(&mut self, address: &Pubkey) -> Option<&mut Delegate> {
+        self.delegates
+            .iter_mut()
+            .find(|delegate| delegate.is_some() && *delegate.address == *address)
+    }
+
+    /// Returns the first empty delegate slot.
+    pub fn get_empty(&mut self) -> Option<&mut Delegate> {
+        self.delegates
+            .iter_mut()
+            .find(|delegate| delegate.is_none())
+    }
+
+    /// Removes all delegates from the list.
+    pub fn clear(&mut self) {
+        self.delegates
+            .iter_mut()
+            .for_each(|delegate| *delegate = Delegate::default());
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for DelegatesMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::Delegates;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let delegates = bytemuck::cast_slice_mut(bytes);
+        Self { delegates }
+    }
+}
+
+impl Lifecycle for DelegatesMut<'_> {
+    /// Validates that the list of delegates is empty.
+    ///
+    /// The extension can be created with pre-allocated slots, but delegates can only
+    /// be added by the owner through the `Approve` instruction.
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        if self.delegates.iter().any(|delegate| delegate.is_some()) {
+            Err(Error::CannotModifyDelegates)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn on_update(&mut self, _other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Err(Error::CannotModifyDelegates)
+    }
+}
+
+/// Builder for a `Delegates` extension.
+#[derive(Default)]
+pub struct DelegatesBuilder {
+    /// The current number of delegates.
+    count: u8,
+
+    /// The extension data.
+    data: Vec<u8>,
+}
+
+impl DelegatesBuilder {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            count: 0,
+            data: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        let mut s = Self {
+            count: 0,
+            data: buffer,
+        };
+        s.data.clear();
+        s
+    }
+
+    /// Add a new delegate to the extension.
+    ///
+    /// Using a `None` address adds an empty delegate slot.
+    pub fn add(&mut self, address: Option<&Pubkey>, roles: u8) -> &mut Self {
+        // extends the data buffer
+        self.data
+            .append(&mut vec![0u8; std::mem::size_of::<Delegate>()]);
+        let offset = self.count as usize * std::mem::size_of::<Delegate>();
+
+        let delegate = Delegate::load_mut(&mut self.data[offset..]);
+        delegate.address = NullablePubkey::new(address.copied().unwrap_or_default());
+        delegate.roles = roles;
+
+        self.count += 1;
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, Delegates<'a>> for DelegatesBuilder {
+    fn build(&'a self) -> Delegates<'a> {
+        Delegates::from_bytes(&self.data)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.data)
+    }
+}
+
+impl Deref for DelegatesBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::sysvar;
+
+    use crate::{
+        extensions::{DelegatesBuilder, ExtensionBuilder},
+        state::{Delegate, DelegateRole},
+    };
+
+    #[test]
+    fn test_add() {
+        let mut builder = DelegatesBuilder::default();
+        builder.add(Some(&sysvar::ID), DelegateRole::Lock.mask());
+        builder.add(None, 0);
+        let delegates = builder.build();
+
+        assert_eq!(delegates.values.len(), 2);
+        assert!(!delegates.is_empty());
+
+        let delegate = delegates.get(&sysvar::ID).unwrap();
+        assert!(delegate.is_active(DelegateRole::Lock));
+        assert!(!delegate.is_active(DelegateRole::Transfer));
+
+        // empty slots are not returned
+        assert!(delegates.get(&Delegate::default().address).is_none());
+    }
+}
@@ -0,0 +1,144 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to identify a numbered print of a master asset.
+///
+/// Print assets are created by the `Print` instruction, which attaches this extension
+/// to the new asset. The extension cannot be created or modified directly, so it can
+/// be trusted to reference the master asset that originated the print.
+pub struct Edition<'a> {
+    /// The master asset of the print.
+    pub master: &'a Pubkey,
+
+    /// The edition number of the print.
+    pub number: &'a u64,
+}
+
+impl<'a> ExtensionData<'a> for Edition<'a> {
+    const TYPE: ExtensionType = ExtensionType::Edition;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (master, number) = bytes.split_at(std::mem::size_of::<Pubkey>());
+
+        Self {
+            master: bytemuck::from_bytes(master),
+            number: bytemuck::from_bytes(number),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<Pubkey>() + std::mem::size_of::<u64>()
+    }
+}
+
+impl Debug for Edition<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Edition")
+            .field("master", &self.master)
+            .field("number", &self.number)
+            .finish()
+    }
+}
+
+pub struct EditionMut<'a> {
+    pub master: &'a mut Pubkey,
+
+    pub number: &'a mut u64,
+}
+
+impl<'a> ExtensionDataMut<'a> for EditionMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::Edition;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let (master, number) = bytes.split_at_mut(std::mem::size_of::<Pubkey>());
+
+        Self {
+            master: bytemuck::from_bytes_mut(master),
+            number: bytemuck::from_bytes_mut(number),
+        }
+    }
+}
+
+impl Lifecycle for EditionMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // editions can only be created by printing from a master asset
+        Err(Error::CannotModifyEdition)
+    }
+
+    fn on_update(&mut self, other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        if *self.master != *other.master || *self.number != *other.number {
+            return Err(Error::CannotModifyEdition);
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder for an `Edition` extension.
+pub struct EditionBuilder(Vec<u8>);
+
+impl Default for EditionBuilder {
+    fn default() -> Self {
+        Self(vec![
+            0;
+            std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u64>()
+        ])
+    }
+}
+
+impl EditionBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the master asset and edition number of the print.
+    pub fn set(&mut self, master: &Pubkey, number: u64) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+
+        self.0.extend_from_slice(master.as_ref());
+        self.0.extend_from_slice(&u64::to_le_bytes(number));
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, Edition<'a>> for EditionBuilder {
+    fn build(&'a self) -> Edition<'a> {
+        Edition::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for EditionBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::sysvar;
+
+    use crate::extensions::{EditionBuilder, ExtensionBuilder};
+
+    #[test]
+    fn test_set() {
+        let mut builder = EditionBuilder::default();
+        builder.set(&sysvar::ID, 7);
+        let edition = builder.build();
+
+        assert_eq!(edition.master, &sysvar::ID);
+        assert_eq!(*edition.number, 7);
+    }
+}
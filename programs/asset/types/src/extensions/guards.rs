@@ -0,0 +1,271 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{
+    constraints::{Constraint, ConstraintBuilder, FromBytes},
+    error::Error,
+};
+
+use super::{
+    ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle, DEFAULT_CAPACITY,
+};
+
+/// Extension to guard the actions on an asset with constraints.
+///
+/// Each guard maps an action (instruction) to a `Constraint` that is evaluated every time the
+/// action is executed; the action fails if the constraint is not satisfied. Unlike `Royalties`,
+/// guards are evaluated unconditionally, including on wallet-to-wallet transfers.
+///
+/// The `Caller` account is only available when the instructions sysvar is provided to the
+/// instruction. Removing an extension is guarded by the `Update` action.
+pub struct Guards<'a> {
+    guards: Vec<Guard<'a>>,
+}
+
+impl<'a> Guards<'a> {
+    /// Returns the constraint of an action.
+    ///
+    /// If the action is not guarded, returns `None`.
+    pub fn get(&self, action: Action) -> Option<&Constraint<'a>> {
+        self.guards
+            .iter()
+            .find(|guard| guard.action() == Ok(action))
+            .map(|guard| &guard.constraint)
+    }
+}
+
+impl<'a> Deref for Guards<'a> {
+    type Target = Vec<Guard<'a>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guards
+    }
+}
+
+impl<'a> ExtensionData<'a> for Guards<'a> {
+    const TYPE: ExtensionType = ExtensionType::Guards;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let mut cursor = 0;
+        let mut guards = Vec::with_capacity(DEFAULT_CAPACITY);
+
+        while cursor < bytes.len() {
+            let guard = Guard::from_bytes(&bytes[cursor..]);
+            cursor += guard.size();
+            guards.push(guard);
+        }
+
+        Self { guards }
+    }
+
+    fn length(&self) -> usize {
+        self.guards.iter().map(|guard| guard.size()).sum()
+    }
+}
+
+impl Debug for Guards<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Guards")
+            .field(
+                "actions",
+                &self.guards.iter().map(|g| g.action()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// A constraint guarding an action.
+pub struct Guard<'a> {
+    /// The guarded action (stored as a `u64` to keep the constraint aligned).
+    action: &'a u64,
+
+    /// The constraint evaluated on the action.
+    pub constraint: Constraint<'a>,
+}
+
+impl<'a> Guard<'a> {
+    /// Returns the guarded action.
+    ///
+    /// The action is validated when the extension is created or updated.
+    pub fn action(&self) -> Result<Action, Error> {
+        (*self.action).try_into()
+    }
+
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<u64>() + self.constraint.size()
+    }
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (action, constraint) = bytes.split_at(std::mem::size_of::<u64>());
+
+        Self {
+            action: bytemuck::from_bytes(action),
+            constraint: Constraint::from_bytes(constraint),
+        }
+    }
+}
+
+/// Actions that can be guarded by a constraint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Burn,
+    Lock,
+    Unlock,
+    Approve,
+    Update,
+    Transfer,
+}
+
+impl TryFrom<u64> for Action {
+    type Error = Error;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Action::Burn),
+            1 => Ok(Action::Lock),
+            2 => Ok(Action::Unlock),
+            3 => Ok(Action::Approve),
+            4 => Ok(Action::Update),
+            5 => Ok(Action::Transfer),
+            _ => Err(Error::InvalidGuardAction(value)),
+        }
+    }
+}
+
+impl From<Action> for u64 {
+    fn from(value: Action) -> Self {
+        match value {
+            Action::Burn => 0,
+            Action::Lock => 1,
+            Action::Unlock => 2,
+            Action::Approve => 3,
+            Action::Update => 4,
+            Action::Transfer => 5,
+        }
+    }
+}
+
+pub struct GuardsMut<'a> {
+    pub data: &'a mut [u8],
+}
+
+impl<'a> ExtensionDataMut<'a> for GuardsMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::Guards;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        Self { data: bytes }
+    }
+}
+
+impl GuardsMut<'_> {
+    /// Validates the actions of all guards.
+    fn validate(&self) -> Result<(), Error> {
+        Guards::from_bytes(self.data)
+            .iter()
+            .try_for_each(|guard| guard.action().map(|_| ()))
+    }
+}
+
+impl Lifecycle for GuardsMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        self.validate()
+    }
+
+    fn on_update(&mut self, other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        other.validate()
+    }
+}
+
+/// Builder for a `Guards` extension.
+#[derive(Default)]
+pub struct GuardsBuilder(Vec<u8>);
+
+impl GuardsBuilder {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        let mut s = Self(buffer);
+        s.0.clear();
+        s
+    }
+
+    /// Add a new guard to the extension.
+    pub fn add(&mut self, action: Action, constraint: &mut dyn ConstraintBuilder) -> &mut Self {
+        self.0.extend_from_slice(&u64::from(action).to_le_bytes());
+        self.0.extend_from_slice(&constraint.build());
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, Guards<'a>> for GuardsBuilder {
+    fn build(&'a self) -> Guards<'a> {
+        Guards::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for GuardsBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        constraints::{Account, EmptyBuilder, OperatorType, PubkeyMatchBuilder},
+        error::Error,
+        extensions::{
+            Action, ExtensionBuilder, ExtensionData, ExtensionDataMut, GuardsBuilder, GuardsMut,
+            Lifecycle,
+        },
+    };
+
+    #[test]
+    fn test_add() {
+        let mut pubkey_match = PubkeyMatchBuilder::default();
+        pubkey_match.set(Account::Authority, &[Pubkey::default()]);
+
+        let mut builder = GuardsBuilder::default();
+        builder.add(Action::Burn, &mut pubkey_match);
+        builder.add(Action::Transfer, &mut EmptyBuilder::default());
+        let guards = builder.build();
+
+        assert_eq!(guards.len(), 2);
+        assert_eq!(guards.length(), builder.len());
+        assert_eq!(
+            guards.get(Action::Burn).unwrap().operator.operator_type(),
+            OperatorType::PubkeyMatch
+        );
+        assert_eq!(
+            guards
+                .get(Action::Transfer)
+                .unwrap()
+                .operator
+                .operator_type(),
+            OperatorType::Empty
+        );
+        assert!(guards.get(Action::Lock).is_none());
+    }
+
+    #[test]
+    fn test_invalid_action() {
+        let mut builder = GuardsBuilder::default();
+        builder.add(Action::Burn, &mut EmptyBuilder::default());
+        let mut data = builder.data();
+        data[0] = 42;
+
+        let mut guards = GuardsMut::from_bytes_mut(&mut data);
+        assert_eq!(guards.on_create(None), Err(Error::InvalidGuardAction(42)));
+    }
+}
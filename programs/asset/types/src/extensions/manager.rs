@@ -9,9 +9,13 @@ use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Li
 /// Extension to define the delegate of a managed asset.
 ///
 /// Assets with a `Managed` standard can be controlled by the delegate
-/// specified in this extension.
+/// specified in this extension. The delegate can also recall the asset
+/// to an address of its choice with the `Recall` instruction.
 ///
-/// This extension can only be used in `Managed` asset accounts.
+/// This extension can only be used in `Managed` and `Soulbound` asset
+/// accounts; it is required for `Managed` assets. The manager of a
+/// `Soulbound` asset cannot have delegate roles, so it can only recall
+/// the asset.
 pub struct Manager<'a> {
     /// The delegate address.
     pub delegate: &'a Delegate,
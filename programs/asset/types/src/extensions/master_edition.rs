@@ -0,0 +1,198 @@
+use podded::pod::PodOption;
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{
+    ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle, NullableU64,
+};
+
+/// Extension to define a master asset that can be printed.
+///
+/// Assets with this extension can be used to create numbered prints (editions) using
+/// the `Print` instruction. The `supply` is incremented every time a print is created
+/// and it cannot exceed the `max_supply`, when one is set.
+pub struct MasterEdition<'a> {
+    /// The number of prints created from the master.
+    pub supply: &'a u64,
+
+    /// The maximum number of prints that can be created.
+    ///
+    /// When the supply is unlimited, this value is `0`.
+    pub max_supply: &'a PodOption<NullableU64>,
+}
+
+impl<'a> ExtensionData<'a> for MasterEdition<'a> {
+    const TYPE: ExtensionType = ExtensionType::MasterEdition;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (supply, max_supply) = bytes.split_at(std::mem::size_of::<u64>());
+
+        Self {
+            supply: bytemuck::from_bytes(supply),
+            max_supply: bytemuck::from_bytes(max_supply),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<u64>() + std::mem::size_of::<u64>()
+    }
+}
+
+impl Debug for MasterEdition<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MasterEdition")
+            .field("supply", &self.supply)
+            .field("max_supply", &self.max_supply.value())
+            .finish()
+    }
+}
+
+pub struct MasterEditionMut<'a> {
+    pub supply: &'a mut u64,
+
+    pub max_supply: &'a mut PodOption<NullableU64>,
+}
+
+impl<'a> ExtensionDataMut<'a> for MasterEditionMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::MasterEdition;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let (supply, max_supply) = bytes.split_at_mut(std::mem::size_of::<u64>());
+
+        Self {
+            supply: bytemuck::from_bytes_mut(supply),
+            max_supply: bytemuck::from_bytes_mut(max_supply),
+        }
+    }
+}
+
+impl Lifecycle for MasterEditionMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        if *self.supply > 0 {
+            Err(Error::InvalidEditionSupply)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn on_update(&mut self, other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // supply cannot be updated
+        *other.supply = *self.supply;
+
+        if let Some(max_supply) = other.max_supply.value() {
+            // it cannot update the max supply to be lower than the current supply
+            if **max_supply < *other.supply {
+                return Err(Error::InvalidMaximumEditionSupply(
+                    *other.supply,
+                    **max_supply,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder for a `MasterEdition` extension.
+pub struct MasterEditionBuilder(Vec<u8>);
+
+impl Default for MasterEditionBuilder {
+    fn default() -> Self {
+        Self(vec![0; std::mem::size_of::<u64>() * 2])
+    }
+}
+
+impl MasterEditionBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the maximum supply of the master edition.
+    pub fn set(&mut self, max_supply: Option<u64>) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+
+        self.0.extend_from_slice(&u64::to_le_bytes(0));
+        self.0
+            .extend_from_slice(&u64::to_le_bytes(max_supply.unwrap_or(0)));
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, MasterEdition<'a>> for MasterEditionBuilder {
+    fn build(&'a self) -> MasterEdition<'a> {
+        MasterEdition::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for MasterEditionBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extensions::{
+        ExtensionBuilder, ExtensionDataMut, Lifecycle, MasterEditionBuilder, MasterEditionMut,
+    };
+
+    #[test]
+    fn test_set_max_supply() {
+        // max_supply set
+        let mut builder = MasterEditionBuilder::default();
+        builder.set(Some(100));
+        let master = builder.build();
+
+        assert_eq!(*master.supply, 0);
+        assert!(master.max_supply.value().is_some());
+
+        let max_supply = master.max_supply.value().unwrap();
+        assert_eq!(**max_supply, 100);
+
+        // "default" max supply
+
+        let builder = MasterEditionBuilder::default();
+        let master = builder.build();
+
+        assert_eq!(*master.supply, 0);
+        assert!(master.max_supply.value().is_none());
+    }
+
+    #[test]
+    fn test_update_max_supply() {
+        let mut current = MasterEditionBuilder::default();
+        current.set(Some(100));
+        let mut current = current.data();
+
+        let mut master = MasterEditionMut::from_bytes_mut(&mut current);
+        *master.supply = 10;
+
+        // cannot lower the max supply below the current supply
+        let mut updated = MasterEditionBuilder::default();
+        updated.set(Some(5));
+        let mut updated = updated.data();
+
+        assert!(master
+            .on_update(&mut MasterEditionMut::from_bytes_mut(&mut updated), None)
+            .is_err());
+
+        // the supply is preserved on update
+        let mut updated = MasterEditionBuilder::default();
+        updated.set(Some(50));
+        let mut updated = updated.data();
+
+        let mut other = MasterEditionMut::from_bytes_mut(&mut updated);
+        assert!(master.on_update(&mut other, None).is_ok());
+        assert_eq!(*other.supply, 10);
+    }
+}
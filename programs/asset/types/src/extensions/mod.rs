@@ -14,28 +14,52 @@
 //! presence/absence of the extension.
 
 mod attributes;
+mod authorities;
 mod blob;
 mod bucket;
 mod creators;
+mod delegate_expiry;
+mod delegates;
+mod edition;
 mod grouping;
+mod guards;
 mod links;
 mod manager;
+mod master_edition;
 mod metadata;
+mod pending_authority;
+mod primary_sale;
 mod properties;
 mod proxy;
+mod rental;
 mod royalties;
+mod royalty_payment;
+mod transfer_hook;
+mod update_delegate;
 
 pub use attributes::*;
+pub use authorities::*;
 pub use blob::*;
 pub use bucket::*;
 pub use creators::*;
+pub use delegate_expiry::*;
+pub use delegates::*;
+pub use edition::*;
 pub use grouping::*;
+pub use guards::*;
 pub use links::*;
 pub use manager::*;
+pub use master_edition::*;
 pub use metadata::*;
+pub use pending_authority::*;
+pub use primary_sale::*;
 pub use properties::*;
 pub use proxy::*;
+pub use rental::*;
 pub use royalties::*;
+pub use royalty_payment::*;
+pub use transfer_hook::*;
+pub use update_delegate::*;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
@@ -59,13 +83,20 @@ pub struct Extension {
     ///   0. type
     ///   1. length
     ///   2. boundary
-    ///   3. unused
+    ///   3. authority (lower 16 bits) and flags (upper 16 bits)
     data: [u32; 4],
 }
 
 impl Extension {
     pub const LEN: usize = std::mem::size_of::<Self>();
 
+    /// Flag to indicate that the extension data cannot be modified.
+    pub const IMMUTABLE: u16 = 0b1;
+
+    /// Flag to indicate that a group was frozen by its authority (`Grouping` extension
+    /// only), which locks all of its members.
+    pub const FROZEN: u16 = 0b10;
+
     pub fn new(extension_type: ExtensionType, length: u32, boundary: u32) -> Self {
         Self {
             data: [extension_type.into(), length, boundary, 0],
@@ -117,6 +148,43 @@ impl Extension {
         self.data[2] = boundary;
     }
 
+    /// Returns the authority index of the extension.
+    ///
+    /// The index refers to an entry on the `Authorities` extension table (starting at `1`).
+    /// When the index is `0`, the extension is controlled by the authority of the asset.
+    pub fn authority(&self) -> u16 {
+        (self.data[3] & 0xffff) as u16
+    }
+
+    pub fn set_authority(&mut self, authority: u16) {
+        self.data[3] = (self.data[3] & 0xffff_0000) | authority as u32;
+    }
+
+    /// Returns the flags of the extension.
+    pub fn flags(&self) -> u16 {
+        (self.data[3] >> 16) as u16
+    }
+
+    pub fn set_flags(&mut self, flags: u16) {
+        self.data[3] = (self.data[3] & 0xffff) | ((flags as u32) << 16);
+    }
+
+    /// Indicates whether the extension is immutable or not.
+    ///
+    /// Once an extension is immutable, its data cannot be updated and the extension
+    /// cannot be removed from the asset.
+    pub fn is_immutable(&self) -> bool {
+        self.flags() & Self::IMMUTABLE != 0
+    }
+
+    /// Indicates whether the extension is frozen or not.
+    ///
+    /// The flag is set by the group authority on the `Grouping` extension and it is
+    /// independent of the state of the group asset.
+    pub fn is_frozen(&self) -> bool {
+        self.flags() & Self::FROZEN != 0
+    }
+
     /// Returns the extension data of a given type.
     ///
     /// This function expects a slice of bytes of extension data only and it will return the first
@@ -211,6 +279,18 @@ pub enum ExtensionType {
     Proxy,
     Properties,
     Bucket,
+    Edition,
+    MasterEdition,
+    DelegateExpiry,
+    Delegates,
+    UpdateDelegate,
+    Authorities,
+    TransferHook,
+    Guards,
+    Rental,
+    PendingAuthority,
+    PrimarySale,
+    RoyaltyPayment,
 }
 
 impl TryFrom<u32> for ExtensionType {
@@ -230,6 +310,18 @@ impl TryFrom<u32> for ExtensionType {
             9 => Ok(ExtensionType::Proxy),
             10 => Ok(ExtensionType::Properties),
             11 => Ok(ExtensionType::Bucket),
+            12 => Ok(ExtensionType::Edition),
+            13 => Ok(ExtensionType::MasterEdition),
+            14 => Ok(ExtensionType::DelegateExpiry),
+            15 => Ok(ExtensionType::Delegates),
+            16 => Ok(ExtensionType::UpdateDelegate),
+            17 => Ok(ExtensionType::Authorities),
+            18 => Ok(ExtensionType::TransferHook),
+            19 => Ok(ExtensionType::Guards),
+            20 => Ok(ExtensionType::Rental),
+            21 => Ok(ExtensionType::PendingAuthority),
+            22 => Ok(ExtensionType::PrimarySale),
+            23 => Ok(ExtensionType::RoyaltyPayment),
             _ => Err(Error::InvalidExtensionType(value)),
         }
     }
@@ -250,6 +342,18 @@ impl From<ExtensionType> for u32 {
             ExtensionType::Proxy => 9,
             ExtensionType::Properties => 10,
             ExtensionType::Bucket => 11,
+            ExtensionType::Edition => 12,
+            ExtensionType::MasterEdition => 13,
+            ExtensionType::DelegateExpiry => 14,
+            ExtensionType::Delegates => 15,
+            ExtensionType::UpdateDelegate => 16,
+            ExtensionType::Authorities => 17,
+            ExtensionType::TransferHook => 18,
+            ExtensionType::Guards => 19,
+            ExtensionType::Rental => 20,
+            ExtensionType::PendingAuthority => 21,
+            ExtensionType::PrimarySale => 22,
+            ExtensionType::RoyaltyPayment => 23,
         }
     }
 }
@@ -339,5 +443,17 @@ validate_extension_type!(
     (Manager, ManagerMut),
     (Proxy, ProxyMut),
     (Properties, PropertiesMut),
-    (Bucket, BucketMut)
+    (Bucket, BucketMut),
+    (Edition, EditionMut),
+    (MasterEdition, MasterEditionMut),
+    (DelegateExpiry, DelegateExpiryMut),
+    (Delegates, DelegatesMut),
+    (UpdateDelegate, UpdateDelegateMut),
+    (Authorities, AuthoritiesMut),
+    (TransferHook, TransferHookMut),
+    (Guards, GuardsMut),
+    (Rental, RentalMut),
+    (PendingAuthority, PendingAuthorityMut),
+    (PrimarySale, PrimarySaleMut),
+    (RoyaltyPayment, RoyaltyPaymentMut)
 );
@@ -0,0 +1,149 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to store the authority proposed on a two-step handover.
+///
+/// The extension is managed by the `ProposeHandover` instruction, which stores the proposed
+/// authority until it signs an `AcceptHandover` instruction. The authority of the asset can
+/// cancel the proposal with the `CancelHandover` instruction.
+pub struct PendingAuthority<'a> {
+    /// The proposed authority of the asset.
+    pub authority: &'a Pubkey,
+}
+
+impl PendingAuthority<'_> {
+    /// Indicates whether there is a pending handover.
+    ///
+    /// An accepted or cancelled handover has its authority cleared.
+    pub fn is_active(&self) -> bool {
+        *self.authority != Pubkey::default()
+    }
+}
+
+impl<'a> ExtensionData<'a> for PendingAuthority<'a> {
+    const TYPE: ExtensionType = ExtensionType::PendingAuthority;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            authority: bytemuck::from_bytes(&bytes[..std::mem::size_of::<Pubkey>()]),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<Pubkey>()
+    }
+}
+
+impl Debug for PendingAuthority<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PendingAuthority")
+            .field("authority", &self.authority)
+            .finish()
+    }
+}
+
+pub struct PendingAuthorityMut<'a> {
+    pub authority: &'a mut Pubkey,
+}
+
+impl PendingAuthorityMut<'_> {
+    /// Clears the pending handover.
+    pub fn clear(&mut self) {
+        *self.authority = Pubkey::default();
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for PendingAuthorityMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::PendingAuthority;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        Self {
+            authority: bytemuck::from_bytes_mut(&mut bytes[..std::mem::size_of::<Pubkey>()]),
+        }
+    }
+}
+
+impl Lifecycle for PendingAuthorityMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // the pending authority is set by the authority when proposing a handover
+        Err(Error::CannotModifyPendingAuthority)
+    }
+
+    fn on_update(&mut self, _other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Err(Error::CannotModifyPendingAuthority)
+    }
+}
+
+/// Builder for a `PendingAuthority` extension.
+pub struct PendingAuthorityBuilder(Vec<u8>);
+
+impl Default for PendingAuthorityBuilder {
+    fn default() -> Self {
+        Self(vec![0; std::mem::size_of::<Pubkey>()])
+    }
+}
+
+impl PendingAuthorityBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the proposed authority.
+    pub fn set(&mut self, authority: &Pubkey) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+        self.0.extend_from_slice(authority.as_ref());
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, PendingAuthority<'a>> for PendingAuthorityBuilder {
+    fn build(&'a self) -> PendingAuthority<'a> {
+        PendingAuthority::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for PendingAuthorityBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::sysvar;
+
+    use crate::extensions::{
+        ExtensionBuilder, ExtensionDataMut, Lifecycle, PendingAuthorityBuilder, PendingAuthorityMut,
+    };
+
+    #[test]
+    fn test_pending_authority() {
+        let mut builder = PendingAuthorityBuilder::default();
+        builder.set(&sysvar::ID);
+        let pending = builder.build();
+
+        assert_eq!(pending.authority, &sysvar::ID);
+        assert!(pending.is_active());
+
+        let mut data = builder.data();
+        let mut pending = PendingAuthorityMut::from_bytes_mut(&mut data);
+        assert!(pending.on_create(None).is_err());
+
+        pending.clear();
+
+        let builder = PendingAuthorityBuilder::with_buffer(data);
+        assert!(!builder.build().is_active());
+    }
+}
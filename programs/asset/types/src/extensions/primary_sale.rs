@@ -0,0 +1,161 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to track the primary sale of an asset.
+///
+/// Assets with this extension use the primary rate of their royalties (either their own
+/// or the ones of their group) until the primary sale happens. The primary sale happens
+/// on the first transfer to an account that is neither the authority nor a creator of the
+/// asset. Assets without the extension are considered to be past their primary sale.
+pub struct PrimarySale<'a> {
+    /// Indicates whether the primary sale happened (stored as a `u64` to keep the
+    /// extension aligned).
+    happened: &'a u64,
+}
+
+impl PrimarySale<'_> {
+    /// Indicates whether the primary sale happened.
+    pub fn happened(&self) -> bool {
+        *self.happened != 0
+    }
+}
+
+impl<'a> ExtensionData<'a> for PrimarySale<'a> {
+    const TYPE: ExtensionType = ExtensionType::PrimarySale;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            happened: bytemuck::from_bytes(&bytes[..std::mem::size_of::<u64>()]),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<u64>()
+    }
+}
+
+impl Debug for PrimarySale<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrimarySale")
+            .field("happened", &self.happened())
+            .finish()
+    }
+}
+
+pub struct PrimarySaleMut<'a> {
+    happened: &'a mut u64,
+}
+
+impl PrimarySaleMut<'_> {
+    /// Indicates whether the primary sale happened.
+    pub fn happened(&self) -> bool {
+        *self.happened != 0
+    }
+
+    /// Flags the primary sale as happened.
+    pub fn set_happened(&mut self) {
+        *self.happened = 1;
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for PrimarySaleMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::PrimarySale;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        Self {
+            happened: bytemuck::from_bytes_mut(&mut bytes[..std::mem::size_of::<u64>()]),
+        }
+    }
+}
+
+impl Lifecycle for PrimarySaleMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_update(&mut self, other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // the primary sale flag cannot be reset by an update
+        if self.happened() && !other.happened() {
+            Err(Error::CannotResetPrimarySale)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Builder for a `PrimarySale` extension.
+pub struct PrimarySaleBuilder(Vec<u8>);
+
+impl Default for PrimarySaleBuilder {
+    fn default() -> Self {
+        // the primary sale has not happened yet
+        Self(vec![0; std::mem::size_of::<u64>()])
+    }
+}
+
+impl PrimarySaleBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set whether the primary sale happened.
+    pub fn set(&mut self, happened: bool) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+        self.0.extend_from_slice(&u64::from(happened).to_le_bytes());
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, PrimarySale<'a>> for PrimarySaleBuilder {
+    fn build(&'a self) -> PrimarySale<'a> {
+        PrimarySale::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for PrimarySaleBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extensions::{
+        ExtensionBuilder, ExtensionDataMut, Lifecycle, PrimarySaleBuilder, PrimarySaleMut,
+    };
+
+    #[test]
+    fn test_primary_sale() {
+        let mut builder = PrimarySaleBuilder::default();
+        assert!(!builder.build().happened());
+
+        let mut data = builder.data();
+        let mut primary_sale = PrimarySaleMut::from_bytes_mut(&mut data);
+        assert!(primary_sale.on_create(None).is_ok());
+
+        primary_sale.set_happened();
+
+        let mut builder = PrimarySaleBuilder::with_buffer(data);
+        assert!(builder.build().happened());
+
+        // the primary sale cannot be reset once it happened
+        let mut data = builder.data();
+        let mut reset = PrimarySaleBuilder::default().data();
+
+        let mut primary_sale = PrimarySaleMut::from_bytes_mut(&mut data);
+        let mut other = PrimarySaleMut::from_bytes_mut(&mut reset);
+        assert!(primary_sale.on_update(&mut other, None).is_err());
+    }
+}
@@ -2,6 +2,8 @@ use bytemuck::{Pod, Zeroable};
 use podded::types::{U8PrefixStr, U8PrefixStrMut};
 use std::{fmt::Debug, ops::Deref};
 
+use crate::error::Error;
+
 use super::{
     ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle, DEFAULT_CAPACITY,
 };
@@ -140,7 +142,8 @@ impl<'a> Property<'a> {
 
         let (_, value) = bytes.split_at(name.size());
 
-        let value = match value[0].into() {
+        // properties with an invalid value type cannot be read
+        let value = match Type::try_from(value[0]).unwrap_or_else(|error| panic!("{error}")) {
             Type::Text => Box::new(Text::from_bytes(value)) as Box<dyn Value>,
             Type::Number => Box::new(Number::from_bytes(value)) as Box<dyn Value>,
             Type::Boolean => Box::new(Boolean::from_bytes(value)) as Box<dyn Value>,
@@ -162,6 +165,9 @@ impl Debug for Property<'_> {
 /// Trait representing a value in a property.
 pub trait Value: Debug {
     fn size(&self) -> usize;
+
+    /// Type of the value.
+    fn value_type(&self) -> Type;
 }
 
 /// Type of the value in a property.
@@ -189,13 +195,15 @@ impl From<Type> for u8 {
     }
 }
 
-impl From<u8> for Type {
-    fn from(value: u8) -> Self {
+impl TryFrom<u8> for Type {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 => Type::Text,
-            1 => Type::Number,
-            2 => Type::Boolean,
-            _ => panic!("invalid value type: {}", value),
+            0 => Ok(Type::Text),
+            1 => Ok(Type::Number),
+            2 => Ok(Type::Boolean),
+            _ => Err(Error::InvalidValueType(value)),
         }
     }
 }
@@ -219,6 +227,10 @@ impl Value for Text<'_> {
     fn size(&self) -> usize {
         std::mem::size_of::<Type>() + self.value.size()
     }
+
+    fn value_type(&self) -> Type {
+        Type::Text
+    }
 }
 
 impl Debug for Text<'_> {
@@ -253,6 +265,10 @@ impl Value for Number<'_> {
     fn size(&self) -> usize {
         std::mem::size_of::<Type>() + std::mem::size_of_val(self.value)
     }
+
+    fn value_type(&self) -> Type {
+        Type::Number
+    }
 }
 
 impl Debug for Number<'_> {
@@ -286,6 +302,10 @@ impl Value for Boolean<'_> {
     fn size(&self) -> usize {
         std::mem::size_of::<Type>() + std::mem::size_of_val(self.value)
     }
+
+    fn value_type(&self) -> Type {
+        Type::Boolean
+    }
 }
 
 impl Debug for Boolean<'_> {
@@ -0,0 +1,174 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to lend an asset to a renter until a timestamp.
+///
+/// The extension is managed by the `Lend` instruction, which transfers the asset to the
+/// renter and locks it, so the renter holds the asset but cannot transfer or burn it. Once
+/// the rental expires, anyone can return the asset to the lender with the `Reclaim`
+/// instruction.
+pub struct Rental<'a> {
+    /// The lender address (owner of the asset before the rental).
+    pub lender: &'a Pubkey,
+
+    /// Unix timestamp (in seconds) when the rental expires.
+    pub expiry: &'a i64,
+}
+
+impl Rental<'_> {
+    /// Indicates whether the asset is currently rented.
+    ///
+    /// A reclaimed rental has its lender cleared.
+    pub fn is_active(&self) -> bool {
+        *self.lender != Pubkey::default()
+    }
+
+    /// Indicates whether the rental has expired at the given timestamp.
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp >= *self.expiry
+    }
+}
+
+impl<'a> ExtensionData<'a> for Rental<'a> {
+    const TYPE: ExtensionType = ExtensionType::Rental;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (lender, expiry) = bytes.split_at(std::mem::size_of::<Pubkey>());
+
+        Self {
+            lender: bytemuck::from_bytes(lender),
+            expiry: bytemuck::from_bytes(expiry),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<Pubkey>() + std::mem::size_of::<i64>()
+    }
+}
+
+impl Debug for Rental<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Rental")
+            .field("lender", &self.lender)
+            .field("expiry", &self.expiry)
+            .finish()
+    }
+}
+
+pub struct RentalMut<'a> {
+    pub lender: &'a mut Pubkey,
+
+    pub expiry: &'a mut i64,
+}
+
+impl RentalMut<'_> {
+    /// Clears the rental.
+    pub fn clear(&mut self) {
+        *self.lender = Pubkey::default();
+        *self.expiry = 0;
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for RentalMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::Rental;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let (lender, expiry) = bytes.split_at_mut(std::mem::size_of::<Pubkey>());
+
+        Self {
+            lender: bytemuck::from_bytes_mut(lender),
+            expiry: bytemuck::from_bytes_mut(expiry),
+        }
+    }
+}
+
+impl Lifecycle for RentalMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // the rental is set by the owner when lending the asset
+        Err(Error::CannotModifyRental)
+    }
+
+    fn on_update(&mut self, _other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Err(Error::CannotModifyRental)
+    }
+}
+
+/// Builder for a `Rental` extension.
+pub struct RentalBuilder(Vec<u8>);
+
+impl Default for RentalBuilder {
+    fn default() -> Self {
+        Self(vec![
+            0;
+            std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<i64>()
+        ])
+    }
+}
+
+impl RentalBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the lender and the expiry timestamp of the rental.
+    pub fn set(&mut self, lender: &Pubkey, expiry: i64) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+
+        self.0.extend_from_slice(lender.as_ref());
+        self.0.extend_from_slice(&i64::to_le_bytes(expiry));
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, Rental<'a>> for RentalBuilder {
+    fn build(&'a self) -> Rental<'a> {
+        Rental::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for RentalBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, sysvar};
+
+    use crate::extensions::{ExtensionBuilder, ExtensionDataMut, RentalBuilder, RentalMut};
+
+    #[test]
+    fn test_rental() {
+        let mut builder = RentalBuilder::default();
+        builder.set(&sysvar::ID, 1_000);
+        let rental = builder.build();
+
+        assert_eq!(rental.lender, &sysvar::ID);
+        assert!(rental.is_active());
+        assert!(!rental.is_expired(999));
+        assert!(rental.is_expired(1_000));
+
+        let mut data = builder.data();
+        RentalMut::from_bytes_mut(&mut data).clear();
+
+        let builder = RentalBuilder::with_buffer(data);
+        let rental = builder.build();
+
+        assert_eq!(rental.lender, &Pubkey::default());
+        assert!(!rental.is_active());
+    }
+}
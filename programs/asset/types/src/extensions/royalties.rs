@@ -5,9 +5,55 @@ use crate::error::Error;
 
 use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
 
+/// Default value of the mode and minimum amount for backwards compatibility.
+const ZERO: u64 = 0;
+
 pub struct Royalties<'a> {
     pub basis_points: &'a u64,
     pub constraint: Constraint<'a>,
+
+    /// Enforcement mode (stored as a `u64` to keep the amount aligned).
+    mode: &'a u64,
+
+    /// Minimum amount (in lamports) paid to the creators on a transfer.
+    ///
+    /// The amount is only used by the `Payment` mode.
+    pub amount: &'a u64,
+
+    /// Basis points of the primary sale (not present on royalties created without a
+    /// primary rate).
+    ///
+    /// The `basis_points` value is the rate of secondary sales.
+    pub primary_basis_points: Option<&'a u64>,
+
+    /// Length of the extension data.
+    length: usize,
+}
+
+impl Royalties<'_> {
+    /// Returns the royalty amount of a sale.
+    pub fn amount(&self, price: u64, is_primary_sale: bool) -> u64 {
+        ((price as u128 * self.rate(is_primary_sale) as u128) / 10000) as u64
+    }
+
+    /// Returns the basis points of a sale.
+    ///
+    /// This is the primary rate (if any) on the primary sale of an asset, and the secondary
+    /// rate (`basis_points`) otherwise. The primary sale is tracked by the `PrimarySale`
+    /// extension of each asset.
+    pub fn rate(&self, is_primary_sale: bool) -> u64 {
+        match self.primary_basis_points {
+            Some(primary_basis_points) if is_primary_sale => *primary_basis_points,
+            _ => *self.basis_points,
+        }
+    }
+
+    /// Returns the enforcement mode of the royalties.
+    ///
+    /// The mode is validated when the extension is created or updated.
+    pub fn mode(&self) -> Result<RoyaltiesMode, Error> {
+        (*self.mode).try_into()
+    }
 }
 
 impl<'a> ExtensionData<'a> for Royalties<'a> {
@@ -19,20 +65,92 @@ impl<'a> ExtensionData<'a> for Royalties<'a> {
         let basis_points = bytemuck::from_bytes(basis_points);
         let constraint = Constraint::from_bytes(constraint);
 
+        let mut length = std::mem::size_of::<u64>() + constraint.size();
+
+        let has_mode = length + MODE_LEN <= bytes.len();
+
+        let (mode, amount) = if !has_mode {
+            // backwards compatibility for royalties extension: if there are not enough
+            // bytes to read the mode, we assume the constraint mode
+            (&ZERO, &ZERO)
+        } else {
+            let (mode, amount) =
+                bytes[length..length + MODE_LEN].split_at(std::mem::size_of::<u64>());
+            length += MODE_LEN;
+            (bytemuck::from_bytes(mode), bytemuck::from_bytes(amount))
+        };
+
+        // the primary rate follows the mode, so it is only read when the mode is present
+        let primary_basis_points = if !has_mode || length + PRIMARY_LEN > bytes.len() {
+            None
+        } else {
+            let primary_basis_points = bytemuck::from_bytes(&bytes[length..length + PRIMARY_LEN]);
+            length += PRIMARY_LEN;
+            Some(primary_basis_points)
+        };
+
         Self {
             basis_points,
             constraint,
+            mode,
+            amount,
+            primary_basis_points,
+            length,
         }
     }
 
     fn length(&self) -> usize {
-        std::mem::size_of::<u64>() + self.constraint.size()
+        self.length
+    }
+}
+
+/// Length of the mode and minimum amount.
+const MODE_LEN: usize = std::mem::size_of::<u64>() * 2;
+
+/// Length of the primary rate.
+const PRIMARY_LEN: usize = std::mem::size_of::<u64>();
+
+/// Modes of enforcing royalties on transfers.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RoyaltiesMode {
+    /// The recipient of a transfer must satisfy the constraint.
+    #[default]
+    Constraint,
+
+    /// The transaction must pay at least the minimum amount to the creators before the
+    /// transfer; the constraint is not evaluated.
+    Payment,
+}
+
+impl TryFrom<u64> for RoyaltiesMode {
+    type Error = Error;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RoyaltiesMode::Constraint),
+            1 => Ok(RoyaltiesMode::Payment),
+            _ => Err(Error::InvalidRoyaltiesMode(value)),
+        }
+    }
+}
+
+impl From<RoyaltiesMode> for u64 {
+    fn from(value: RoyaltiesMode) -> Self {
+        match value {
+            RoyaltiesMode::Constraint => 0,
+            RoyaltiesMode::Payment => 1,
+        }
     }
 }
 
 pub struct RoyaltiesMut<'a> {
     pub basis_points: &'a mut u64,
     pub constraint: Constraint<'a>,
+    /// Enforcement mode (not present on royalties created before the mode was added).
+    pub mode: Option<&'a mut u64>,
+    /// Basis points of the primary sale (not present on royalties created before the
+    /// primary rate was added).
+    pub primary_basis_points: Option<&'a mut u64>,
 }
 
 impl<'a> ExtensionDataMut<'a> for RoyaltiesMut<'a> {
@@ -42,12 +160,55 @@ impl<'a> ExtensionDataMut<'a> for RoyaltiesMut<'a> {
         let (basis_points, constraint) = bytes.split_at_mut(std::mem::size_of::<u64>());
 
         let basis_points = bytemuck::from_bytes_mut(basis_points);
+
+        let size = Constraint::from_bytes(constraint).size();
+        let (constraint, remaining) = constraint.split_at_mut(size);
         let constraint = Constraint::from_bytes(constraint);
 
+        let (mode, remaining) = if remaining.len() < MODE_LEN {
+            (None, &mut [][..])
+        } else {
+            let (mode, remaining) = remaining.split_at_mut(MODE_LEN);
+            (
+                Some(bytemuck::from_bytes_mut(
+                    &mut mode[..std::mem::size_of::<u64>()],
+                )),
+                remaining,
+            )
+        };
+
+        let primary_basis_points = if remaining.len() < PRIMARY_LEN {
+            None
+        } else {
+            Some(bytemuck::from_bytes_mut(&mut remaining[..PRIMARY_LEN]))
+        };
+
         Self {
             basis_points,
             constraint,
+            mode,
+            primary_basis_points,
+        }
+    }
+}
+
+impl RoyaltiesMut<'_> {
+    /// Validates the basis points and the mode.
+    fn validate(&self) -> Result<(), Error> {
+        if *self.basis_points > 10000
+            || self
+                .primary_basis_points
+                .as_ref()
+                .is_some_and(|basis_points| **basis_points > 10000)
+        {
+            return Err(Error::InvalidRoyaltyBasisPoints);
         }
+
+        if let Some(mode) = &self.mode {
+            RoyaltiesMode::try_from(**mode)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -56,11 +217,7 @@ impl Lifecycle for RoyaltiesMut<'_> {
         &mut self,
         _authority: Option<&solana_program::pubkey::Pubkey>,
     ) -> Result<(), Error> {
-        if *self.basis_points > 10000 {
-            return Err(Error::InvalidRoyaltyBasisPoints);
-        }
-
-        Ok(())
+        self.validate()
     }
 
     fn on_update(
@@ -68,11 +225,7 @@ impl Lifecycle for RoyaltiesMut<'_> {
         other: &mut Self,
         _authority: Option<&solana_program::pubkey::Pubkey>,
     ) -> Result<(), Error> {
-        if *other.basis_points > 10000 {
-            return Err(Error::InvalidRoyaltyBasisPoints);
-        }
-
-        Ok(())
+        other.validate()
     }
 }
 
@@ -97,6 +250,25 @@ impl RoyaltiesBuilder {
 
         self
     }
+
+    /// Sets the enforcement mode and the minimum amount paid to the creators.
+    ///
+    /// This must be called after `set`, since setting the royalties clears the mode.
+    pub fn set_mode(&mut self, mode: RoyaltiesMode, amount: u64) -> &mut Self {
+        self.0.extend_from_slice(&u64::from(mode).to_le_bytes());
+        self.0.extend_from_slice(&amount.to_le_bytes());
+
+        self
+    }
+
+    /// Sets the basis points of the primary sale.
+    ///
+    /// This must be called after `set_mode`, since the primary rate follows the mode.
+    pub fn set_primary(&mut self, basis_points: u64) -> &mut Self {
+        self.0.extend_from_slice(&basis_points.to_le_bytes());
+
+        self
+    }
 }
 
 impl<'a> ExtensionBuilder<'a, Royalties<'a>> for RoyaltiesBuilder {
@@ -116,3 +288,81 @@ impl Deref for RoyaltiesBuilder {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constraints::EmptyBuilder,
+        extensions::{
+            ExtensionBuilder, ExtensionData, ExtensionDataMut, Royalties, RoyaltiesBuilder,
+            RoyaltiesMode, RoyaltiesMut,
+        },
+    };
+
+    #[test]
+    fn test_mode() {
+        let mut builder = RoyaltiesBuilder::default();
+        builder.set(500, &mut EmptyBuilder::default());
+        let royalties = builder.build();
+
+        // royalties without a mode default to the constraint mode
+        assert_eq!(royalties.mode(), Ok(RoyaltiesMode::Constraint));
+        assert_eq!(*royalties.amount, 0);
+
+        let mut builder = RoyaltiesBuilder::default();
+        builder
+            .set(500, &mut EmptyBuilder::default())
+            .set_mode(RoyaltiesMode::Payment, 1_000);
+        let royalties = builder.build();
+
+        assert_eq!(royalties.mode(), Ok(RoyaltiesMode::Payment));
+        assert_eq!(*royalties.amount, 1_000);
+    }
+
+    #[test]
+    fn test_primary() {
+        let mut builder = RoyaltiesBuilder::default();
+        builder
+            .set(500, &mut EmptyBuilder::default())
+            .set_mode(RoyaltiesMode::Constraint, 0);
+        let data = builder.data();
+        let royalties = Royalties::from_bytes(&data);
+
+        // royalties without a primary rate use the secondary rate on a primary sale
+        assert!(royalties.primary_basis_points.is_none());
+        assert_eq!(royalties.amount(10_000, true), 500);
+        assert_eq!(royalties.length(), data.len());
+
+        let mut builder = RoyaltiesBuilder::default();
+        builder
+            .set(500, &mut EmptyBuilder::default())
+            .set_mode(RoyaltiesMode::Constraint, 0)
+            .set_primary(1_000);
+        let mut data = builder.data();
+
+        {
+            let royalties = Royalties::from_bytes(&data);
+            assert_eq!(royalties.amount(10_000, true), 1_000);
+            assert_eq!(royalties.amount(10_000, false), 500);
+            assert_eq!(royalties.length(), data.len());
+        }
+
+        *RoyaltiesMut::from_bytes_mut(&mut data)
+            .primary_basis_points
+            .unwrap() = 0;
+
+        let royalties = Royalties::from_bytes(&data);
+        assert_eq!(royalties.rate(true), 0);
+        assert_eq!(royalties.rate(false), 500);
+    }
+
+    #[test]
+    fn test_length() {
+        let mut builder = RoyaltiesBuilder::default();
+        builder.set(500, &mut EmptyBuilder::default());
+        let data = builder.data();
+
+        // royalties without a mode only use the basis points and the constraint
+        assert_eq!(Royalties::from_bytes(&data).length(), data.len());
+    }
+}
@@ -0,0 +1,146 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to record the royalties paid for the next transfer of an asset.
+///
+/// The extension is managed by the `PayRoyalties` instruction, which adds the amount paid
+/// to the creators. The next transfer of the asset consumes the amount, so a payment can
+/// only be used once.
+pub struct RoyaltyPayment<'a> {
+    /// Lamports paid to the creators for the next transfer.
+    pub amount: &'a u64,
+}
+
+impl<'a> ExtensionData<'a> for RoyaltyPayment<'a> {
+    const TYPE: ExtensionType = ExtensionType::RoyaltyPayment;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            amount: bytemuck::from_bytes(&bytes[..std::mem::size_of::<u64>()]),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<u64>()
+    }
+}
+
+impl Debug for RoyaltyPayment<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoyaltyPayment")
+            .field("amount", &self.amount)
+            .finish()
+    }
+}
+
+pub struct RoyaltyPaymentMut<'a> {
+    pub amount: &'a mut u64,
+}
+
+impl RoyaltyPaymentMut<'_> {
+    /// Adds a payment to the amount paid.
+    pub fn add(&mut self, amount: u64) {
+        *self.amount = self.amount.saturating_add(amount);
+    }
+
+    /// Clears the amount paid.
+    pub fn clear(&mut self) {
+        *self.amount = 0;
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for RoyaltyPaymentMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::RoyaltyPayment;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        Self {
+            amount: bytemuck::from_bytes_mut(&mut bytes[..std::mem::size_of::<u64>()]),
+        }
+    }
+}
+
+impl Lifecycle for RoyaltyPaymentMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        // the amount is set by paying the royalties
+        Err(Error::CannotModifyRoyaltyPayment)
+    }
+
+    fn on_update(&mut self, _other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        Err(Error::CannotModifyRoyaltyPayment)
+    }
+}
+
+/// Builder for a `RoyaltyPayment` extension.
+pub struct RoyaltyPaymentBuilder(Vec<u8>);
+
+impl Default for RoyaltyPaymentBuilder {
+    fn default() -> Self {
+        Self(vec![0; std::mem::size_of::<u64>()])
+    }
+}
+
+impl RoyaltyPaymentBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the amount paid.
+    pub fn set(&mut self, amount: u64) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+        self.0.extend_from_slice(&amount.to_le_bytes());
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, RoyaltyPayment<'a>> for RoyaltyPaymentBuilder {
+    fn build(&'a self) -> RoyaltyPayment<'a> {
+        RoyaltyPayment::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for RoyaltyPaymentBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extensions::{
+        ExtensionBuilder, ExtensionDataMut, Lifecycle, RoyaltyPaymentBuilder, RoyaltyPaymentMut,
+    };
+
+    #[test]
+    fn test_royalty_payment() {
+        let mut builder = RoyaltyPaymentBuilder::default();
+        builder.set(1_000);
+        assert_eq!(*builder.build().amount, 1_000);
+
+        let mut data = builder.data();
+        let mut payment = RoyaltyPaymentMut::from_bytes_mut(&mut data);
+        assert!(payment.on_create(None).is_err());
+
+        payment.add(500);
+        assert_eq!(*payment.amount, 1_500);
+
+        payment.add(u64::MAX);
+        assert_eq!(*payment.amount, u64::MAX);
+
+        payment.clear();
+
+        let builder = RoyaltyPaymentBuilder::with_buffer(data);
+        assert_eq!(*builder.build().amount, 0);
+    }
+}
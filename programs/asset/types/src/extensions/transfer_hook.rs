@@ -0,0 +1,119 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to define a program invoked on every transfer of an asset.
+///
+/// When an asset has a transfer hook, the `Transfer` instruction invokes the hook program
+/// with the asset, signer and recipient accounts (as read-only, non-signer accounts), followed
+/// by any extra accounts provided to the instruction. The transfer fails if the hook fails.
+///
+/// The instruction data sent to the hook program is the `EXECUTE_DISCRIMINATOR`.
+pub struct TransferHook<'a> {
+    /// The address of the hook program.
+    pub program: &'a Pubkey,
+}
+
+impl TransferHook<'_> {
+    /// Discriminator of the instruction invoked on the hook program.
+    ///
+    /// This is the first 8 bytes of the SHA-256 hash of `"nifty-asset:transfer-hook:execute"`.
+    pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [169, 144, 108, 47, 149, 140, 242, 51];
+}
+
+impl<'a> ExtensionData<'a> for TransferHook<'a> {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            program: bytemuck::from_bytes(&bytes[..std::mem::size_of::<Pubkey>()]),
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<Pubkey>()
+    }
+}
+
+impl Debug for TransferHook<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransferHook")
+            .field("program", &self.program)
+            .finish()
+    }
+}
+
+pub struct TransferHookMut<'a> {
+    pub program: &'a mut Pubkey,
+}
+
+impl<'a> ExtensionDataMut<'a> for TransferHookMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        Self {
+            program: bytemuck::from_bytes_mut(&mut bytes[..std::mem::size_of::<Pubkey>()]),
+        }
+    }
+}
+
+impl Lifecycle for TransferHookMut<'_> {}
+
+/// Builder for a `TransferHook` extension.
+pub struct TransferHookBuilder(Vec<u8>);
+
+impl Default for TransferHookBuilder {
+    fn default() -> Self {
+        Self(vec![0; std::mem::size_of::<Pubkey>()])
+    }
+}
+
+impl TransferHookBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the address of the hook program.
+    pub fn set(&mut self, program: &Pubkey) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+        self.0.extend_from_slice(program.as_ref());
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, TransferHook<'a>> for TransferHookBuilder {
+    fn build(&'a self) -> TransferHook<'a> {
+        TransferHook::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for TransferHookBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::sysvar;
+
+    use crate::extensions::{ExtensionBuilder, ExtensionData, TransferHookBuilder};
+
+    #[test]
+    fn test_set() {
+        let mut builder = TransferHookBuilder::default();
+        builder.set(&sysvar::ID);
+        let hook = builder.build();
+
+        assert_eq!(hook.program, &sysvar::ID);
+        assert_eq!(hook.length(), 32);
+    }
+}
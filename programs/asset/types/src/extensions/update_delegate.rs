@@ -0,0 +1,183 @@
+use solana_program::pubkey::Pubkey;
+use std::{fmt::Debug, ops::Deref};
+
+use crate::error::Error;
+
+use super::{ExtensionBuilder, ExtensionData, ExtensionDataMut, ExtensionType, Lifecycle};
+
+/// Extension to define a delegate authorised to update extensions of an asset.
+///
+/// The delegate is granted by the authority of the asset and can only create or update
+/// the extension types listed on `extension_types`. The delegate cannot update the name,
+/// mutable flag nor the `UpdateDelegate` extension itself.
+pub struct UpdateDelegate<'a> {
+    /// The address of the delegate.
+    pub delegate: &'a Pubkey,
+
+    /// List of extension types that the delegate can update.
+    pub extension_types: &'a [u8],
+}
+
+impl UpdateDelegate<'_> {
+    /// Indicates whether the delegate can update the given extension type.
+    pub fn contains(&self, extension_type: ExtensionType) -> bool {
+        extension_type != ExtensionType::UpdateDelegate
+            && self
+                .extension_types
+                .iter()
+                .any(|t| *t as u32 == u32::from(extension_type))
+    }
+}
+
+impl<'a> ExtensionData<'a> for UpdateDelegate<'a> {
+    const TYPE: ExtensionType = ExtensionType::UpdateDelegate;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        let (delegate, extension_types) = bytes.split_at(std::mem::size_of::<Pubkey>());
+
+        Self {
+            delegate: bytemuck::from_bytes(delegate),
+            extension_types,
+        }
+    }
+
+    fn length(&self) -> usize {
+        std::mem::size_of::<Pubkey>() + self.extension_types.len()
+    }
+}
+
+impl Debug for UpdateDelegate<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateDelegate")
+            .field("delegate", &self.delegate)
+            .field(
+                "extension_types",
+                &self
+                    .extension_types
+                    .iter()
+                    .map(|t| ExtensionType::try_from(*t as u32))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+pub struct UpdateDelegateMut<'a> {
+    pub delegate: &'a mut Pubkey,
+
+    pub extension_types: &'a mut [u8],
+}
+
+impl UpdateDelegateMut<'_> {
+    fn validate(&self) -> Result<(), Error> {
+        self.extension_types
+            .iter()
+            .try_for_each(|t| ExtensionType::try_from(*t as u32).map(|_| ()))
+    }
+}
+
+impl<'a> ExtensionDataMut<'a> for UpdateDelegateMut<'a> {
+    const TYPE: ExtensionType = ExtensionType::UpdateDelegate;
+
+    fn from_bytes_mut(bytes: &'a mut [u8]) -> Self {
+        let (delegate, extension_types) = bytes.split_at_mut(std::mem::size_of::<Pubkey>());
+
+        Self {
+            delegate: bytemuck::from_bytes_mut(delegate),
+            extension_types,
+        }
+    }
+}
+
+impl Lifecycle for UpdateDelegateMut<'_> {
+    fn on_create(&mut self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        self.validate()
+    }
+
+    fn on_update(&mut self, other: &mut Self, _authority: Option<&Pubkey>) -> Result<(), Error> {
+        other.validate()
+    }
+}
+
+/// Builder for an `UpdateDelegate` extension.
+pub struct UpdateDelegateBuilder(Vec<u8>);
+
+impl Default for UpdateDelegateBuilder {
+    fn default() -> Self {
+        Self(vec![0; std::mem::size_of::<Pubkey>()])
+    }
+}
+
+impl UpdateDelegateBuilder {
+    pub fn with_buffer(buffer: Vec<u8>) -> Self {
+        Self(buffer)
+    }
+
+    /// Set the delegate and the extension types it can update.
+    pub fn set(&mut self, delegate: &Pubkey, extension_types: &[ExtensionType]) -> &mut Self {
+        // setting the data replaces any existing data
+        self.0.clear();
+
+        self.0.extend_from_slice(delegate.as_ref());
+        extension_types
+            .iter()
+            .for_each(|t| self.0.push(u32::from(*t) as u8));
+
+        self
+    }
+}
+
+impl<'a> ExtensionBuilder<'a, UpdateDelegate<'a>> for UpdateDelegateBuilder {
+    fn build(&'a self) -> UpdateDelegate<'a> {
+        UpdateDelegate::from_bytes(&self.0)
+    }
+
+    fn data(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for UpdateDelegateBuilder {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::sysvar;
+
+    use crate::extensions::{
+        ExtensionBuilder, ExtensionDataMut, ExtensionType, Lifecycle, UpdateDelegateBuilder,
+        UpdateDelegateMut,
+    };
+
+    #[test]
+    fn test_set() {
+        let mut builder = UpdateDelegateBuilder::default();
+        builder.set(
+            &sysvar::ID,
+            &[ExtensionType::Attributes, ExtensionType::Properties],
+        );
+        let delegate = builder.build();
+
+        assert_eq!(delegate.delegate, &sysvar::ID);
+        assert!(delegate.contains(ExtensionType::Attributes));
+        assert!(delegate.contains(ExtensionType::Properties));
+        assert!(!delegate.contains(ExtensionType::Creators));
+        assert!(!delegate.contains(ExtensionType::Royalties));
+    }
+
+    #[test]
+    fn test_invalid_extension_type() {
+        let mut builder = UpdateDelegateBuilder::default();
+        builder.set(&sysvar::ID, &[ExtensionType::Attributes]);
+        let mut data = builder.data();
+        data.push(u8::MAX);
+
+        let mut delegate = UpdateDelegateMut::from_bytes_mut(&mut data);
+        assert!(delegate.on_create(None).is_err());
+    }
+}
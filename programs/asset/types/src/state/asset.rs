@@ -31,6 +31,9 @@ pub const MAX_NAME_LENGTH: usize = 35;
 /// Default extension count.
 pub const DEFAULT_EXTENSION_COUNT: usize = 9;
 
+/// Seed prefix of the wallet of an asset.
+pub const WALLET_SEED: &[u8] = b"wallet";
+
 /// `Asset` account (header) information.
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
@@ -77,6 +80,15 @@ impl Asset {
     /// Length of the account data.
     pub const LEN: usize = std::mem::size_of::<Asset>();
 
+    /// Returns the address (and bump) of the wallet of an asset.
+    ///
+    /// The wallet is a PDA derived from `["wallet", asset]` that can hold SOL, tokens and
+    /// other assets. The owner of the asset signs for the wallet through the `ExecuteAsWallet`
+    /// instruction.
+    pub fn find_wallet_address(program_id: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[WALLET_SEED, asset.as_ref()], program_id)
+    }
+
     /// Indicates whether the account contains an extension of a given type.
     pub fn contains(extension_type: ExtensionType, data: &[u8]) -> bool {
         let mut cursor = Asset::LEN;
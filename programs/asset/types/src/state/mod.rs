@@ -1,11 +1,13 @@
 mod asset;
 mod delegate;
+mod rule_set;
 
 use std::ops::{Deref, DerefMut};
 
 pub use asset::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 pub use delegate::*;
+pub use rule_set::*;
 
 use bytemuck::{Pod, Zeroable};
 use podded::pod::Nullable;
@@ -17,6 +19,7 @@ pub enum Discriminator {
     #[default]
     Uninitialized,
     Asset,
+    RuleSet,
 }
 
 impl From<u8> for Discriminator {
@@ -24,6 +27,7 @@ impl From<u8> for Discriminator {
         match value {
             0 => Discriminator::Uninitialized,
             1 => Discriminator::Asset,
+            2 => Discriminator::RuleSet,
             _ => panic!("invalid key value: {value}"),
         }
     }
@@ -34,6 +38,7 @@ impl From<Discriminator> for u8 {
         match value {
             Discriminator::Uninitialized => 0,
             Discriminator::Asset => 1,
+            Discriminator::RuleSet => 2,
         }
     }
 }
@@ -57,6 +62,9 @@ pub enum Standard {
     Managed,
 
     /// A unique non-transferable asset.
+    ///
+    /// Soulbound assets can have a `Manager` extension without delegate roles, which
+    /// only allows the manager to recall the asset (e.g., to revoke a credential).
     Soulbound,
 
     /// A unique proxied asset (an asset that is controlled by another program).
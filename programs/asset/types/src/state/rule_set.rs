@@ -0,0 +1,48 @@
+//! The `RuleSet` account holds a constraint that can be shared by multiple assets. Assets
+//! reference a rule set through an `External` constraint, so updating the rule set updates
+//! the rules of every asset pointing at it.
+//!
+//! # Account layout
+//!
+//! |  *fixed*  |    *variable*   |
+//! |-----------|-----------------|
+//! | RuleSet   | Constraint      |
+//! |-----------|-----------------|
+//! | 40 bytes  | variable bytes  |
+
+use bytemuck::{Pod, Zeroable};
+use podded::ZeroCopy;
+use solana_program::pubkey::Pubkey;
+
+use super::Discriminator;
+use crate::constraints::{Constraint, FromBytes};
+
+/// `RuleSet` account (header) information.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct RuleSet {
+    /// Account discriminator.
+    pub discriminator: Discriminator,
+
+    /// Padding to keep the authority aligned.
+    pub padding: [u8; 7],
+
+    /// Authority of the rule set.
+    ///
+    /// The authority is the account that can update the constraint of the rule set.
+    pub authority: Pubkey,
+}
+
+impl RuleSet {
+    /// Length of the account data (header).
+    pub const LEN: usize = std::mem::size_of::<RuleSet>();
+
+    /// Returns the constraint of the rule set.
+    ///
+    /// The `data` is expected to be the whole account data, including the header.
+    pub fn constraint(data: &[u8]) -> Constraint<'_> {
+        Constraint::from_bytes(&data[RuleSet::LEN..])
+    }
+}
+
+impl<'a> ZeroCopy<'a, RuleSet> for RuleSet {}